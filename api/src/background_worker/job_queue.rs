@@ -2,7 +2,10 @@ use std::{error::Error as StdError, fmt};
 
 use hub_core::{prelude::*, thiserror};
 use redis::{Client, RedisError};
-use sea_orm::{error::DbErr, ActiveModelTrait};
+use sea_orm::{
+    error::DbErr, sea_query::Expr, ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait,
+    PaginatorTrait, QueryFilter, TransactionTrait,
+};
 use serde::{Deserialize, Serialize};
 use serde_json::Error as SerdeJsonError;
 
@@ -32,6 +35,15 @@ pub enum JobQueueError {
     #[error("Background task error: {0}")]
     BackgroundTask(#[from] Error),
 }
+/// Jobs whose trackings have been inserted but which have not been pushed to their queue yet
+#[derive(Debug)]
+#[must_use = "tracked jobs are not processed until they are pushed"]
+pub struct TrackedJobs {
+    queue: &'static str,
+    ids: Vec<i32>,
+    payloads: Vec<String>,
+}
+
 #[derive(Clone, Debug)]
 pub struct JobQueue {
     client: Client,
@@ -78,6 +90,199 @@ impl JobQueue {
         Ok(())
     }
 
+    /// Enqueue a job unless a job of the same task with the same payload is still waiting in its queue
+    ///
+    /// Tasks that recompute the whole state of a resource only need to run once for all the changes made before they are dequeued.
+    /// # Arguments
+    /// * `self` - The job queue
+    /// * `task` - The task to enqueue
+    /// # Returns
+    /// * `Result<(), JobQueueError>` - The result of the operation
+    /// # Errors
+    /// * `JobQueueError` - The error that occurred
+    pub async fn enqueue_unless_queued<C, T>(&self, task: T) -> Result<(), JobQueueError>
+    where
+        T: Serialize + for<'de> Deserialize<'de> + Send + Sync + BackgroundTask<C>,
+        C: Clone + std::fmt::Debug + Send + Sync,
+    {
+        let payload = task.payload()?;
+
+        let queued = job_trackings::Entity::find()
+            .filter(
+                job_trackings::Column::JobType
+                    .eq(task.name())
+                    .and(job_trackings::Column::Status.eq("queued")),
+            )
+            .filter(Expr::cust_with_values(
+                "payload::jsonb = ?::jsonb",
+                [payload.to_string()],
+            ))
+            .count(self.db_pool.get())
+            .await?;
+
+        if queued > 0 {
+            return Ok(());
+        }
+
+        self.enqueue(task).await
+    }
+
+    /// Enqueue many jobs of the same task type
+    ///
+    /// The job trackings are inserted in a single transaction and the jobs are pushed to the queue with a single command.
+    /// # Arguments
+    /// * `self` - The job queue
+    /// * `tasks` - The tasks to enqueue
+    /// # Returns
+    /// * `Result<(), JobQueueError>` - The result of the operation
+    /// # Errors
+    /// * `JobQueueError` - The error that occurred
+    pub async fn enqueue_many<C, T>(&self, tasks: Vec<T>) -> Result<(), JobQueueError>
+    where
+        T: Serialize + for<'de> Deserialize<'de> + Send + Sync + BackgroundTask<C>,
+        C: Clone + std::fmt::Debug + Send + Sync,
+    {
+        let tx = self.db_pool.get().begin().await?;
+
+        let jobs = self.track_many(&tx, tasks).await?;
+
+        tx.commit().await?;
+
+        self.push(jobs).await
+    }
+
+    /// Insert the job trackings of many jobs of the same task type with the connection or transaction of the caller
+    ///
+    /// The jobs are not queued until they are passed to `push`. Tracking jobs in the transaction that saves the work they depend on and pushing them once it commits ensures a job is only queued for saved work and saved work always has a job.
+    /// # Arguments
+    /// * `self` - The job queue
+    /// * `db` - The connection or transaction the job trackings are inserted with
+    /// * `tasks` - The tasks to track
+    /// # Returns
+    /// * `Result<TrackedJobs, JobQueueError>` - The jobs to push
+    /// # Errors
+    /// * `JobQueueError` - The error that occurred
+    pub async fn track_many<C, T>(
+        &self,
+        db: &impl ConnectionTrait,
+        tasks: Vec<T>,
+    ) -> Result<TrackedJobs, JobQueueError>
+    where
+        T: Serialize + for<'de> Deserialize<'de> + Send + Sync + BackgroundTask<C>,
+        C: Clone + std::fmt::Debug + Send + Sync,
+    {
+        let mut jobs = TrackedJobs {
+            queue: T::QUEUE,
+            ids: Vec::with_capacity(tasks.len()),
+            payloads: Vec::with_capacity(tasks.len()),
+        };
+
+        for task in tasks {
+            let payload = task.payload()?;
+            let new_job_tracking = job_trackings::Entity::create(task.name(), payload, "queued")
+                .insert(db)
+                .await?;
+
+            jobs.ids.push(new_job_tracking.id);
+            jobs.payloads
+                .push(serde_json::to_string(&Job::new(new_job_tracking.id, task))?);
+        }
+
+        Ok(jobs)
+    }
+
+    /// Returns the jobs of existing job trackings so they can be pushed again
+    ///
+    /// Used for jobs whose trackings were saved but which may not have been pushed. A job pushed twice is only processed once since jobs that are being or have been processed are skipped when dequeued.
+    /// # Arguments
+    /// * `trackings` - The job trackings of tasks of the same type
+    /// # Returns
+    /// * `Result<TrackedJobs, JobQueueError>` - The jobs to push
+    /// # Errors
+    /// * `JobQueueError` - The error that occurred
+    pub fn tracked<C, T>(trackings: Vec<job_trackings::Model>) -> Result<TrackedJobs, JobQueueError>
+    where
+        T: Serialize + for<'de> Deserialize<'de> + Send + Sync + BackgroundTask<C>,
+        C: Clone + std::fmt::Debug + Send + Sync,
+    {
+        let mut jobs = TrackedJobs {
+            queue: T::QUEUE,
+            ids: Vec::with_capacity(trackings.len()),
+            payloads: Vec::with_capacity(trackings.len()),
+        };
+
+        for tracking in trackings {
+            let task: T = serde_json::from_value(tracking.payload)?;
+
+            jobs.ids.push(tracking.id);
+            jobs.payloads
+                .push(serde_json::to_string(&Job::new(tracking.id, task))?);
+        }
+
+        Ok(jobs)
+    }
+
+    /// Push tracked jobs to their queue with a single command
+    ///
+    /// If the jobs cannot be pushed their job trackings are marked as failed so they are picked up by `retry-jobs`.
+    /// # Arguments
+    /// * `self` - The job queue
+    /// * `jobs` - The jobs returned by `track_many`
+    /// # Returns
+    /// * `Result<(), JobQueueError>` - The result of the operation
+    /// # Errors
+    /// * `JobQueueError` - The error that occurred
+    pub async fn push(&self, jobs: TrackedJobs) -> Result<(), JobQueueError> {
+        if jobs.payloads.is_empty() {
+            return Ok(());
+        }
+
+        let TrackedJobs {
+            queue,
+            ids,
+            payloads,
+        } = jobs;
+
+        let pushed = async {
+            let mut conn = self.client.get_async_connection().await?;
+
+            redis::cmd("LPUSH")
+                .arg(queue)
+                .arg(payloads)
+                .query_async::<_, ()>(&mut conn)
+                .await
+        }
+        .await;
+
+        if let Err(e) = pushed {
+            job_trackings::Entity::update_many()
+                .col_expr(job_trackings::Column::Status, Expr::value("failed"))
+                .filter(job_trackings::Column::Id.is_in(ids))
+                .exec(self.db_pool.get())
+                .await?;
+
+            return Err(e.into());
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of jobs waiting in a queue
+    /// # Arguments
+    /// * `self` - The job queue
+    /// * `queue` - The name of the queue
+    /// # Returns
+    /// * `Result<i64, JobQueueError>` - The length of the queue
+    /// # Errors
+    /// * `JobQueueError` - The error that occurred
+    pub async fn depth(&self, queue: &str) -> Result<i64, JobQueueError> {
+        let mut conn = self.client.get_async_connection().await?;
+
+        let depth: i64 = redis::cmd("LLEN").arg(queue).query_async(&mut conn).await?;
+
+        Ok(depth)
+    }
+
     /// Dequeue a job
     /// # Arguments
     /// * `self` - The job queue
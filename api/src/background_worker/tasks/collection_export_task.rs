@@ -0,0 +1,318 @@
+use std::path::Path;
+
+use hub_core::{
+    anyhow::{Error, Result},
+    chrono::Utc,
+    tokio::{
+        self,
+        io::{AsyncWriteExt, BufWriter},
+    },
+    tracing::error,
+};
+use sea_orm::{prelude::*, DbBackend, FromQueryResult, Set, Statement};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{BackgroundTask, BackgroundTaskError};
+use crate::{
+    db::Connection,
+    entities::{
+        collection_exports,
+        sea_orm_active_enums::{CreationStatus, ExportFormat},
+    },
+    hub_uploads::HubUploadClient,
+};
+
+/// The number of mints read per query while writing an export.
+const EXPORT_CHUNK_SIZE: u64 = 1000;
+
+/// Reads a page of the mints of a collection after a mint ID. Polygon mints share the metadata json of their collection.
+/// Mints removed from the collection by a re-import are skipped.
+const EXPORT_ROWS_SQL: &str = "SELECT collection_mints.id,
+    collection_mints.address,
+    collection_mints.owner,
+    collection_mints.edition,
+    collection_mints.creation_status::text AS status,
+    metadata_jsons.uri AS metadata_uri,
+    COALESCE((
+        SELECT json_agg(json_build_object(
+            'trait_type', metadata_json_attributes.trait_type,
+            'value', metadata_json_attributes.value
+        ) ORDER BY metadata_json_attributes.trait_type)
+        FROM metadata_json_attributes
+        WHERE metadata_json_attributes.metadata_json_id = metadata_jsons.id
+    ), '[]'::json) AS attributes
+FROM collection_mints
+INNER JOIN collections ON collections.id = collection_mints.collection_id
+LEFT JOIN metadata_jsons ON metadata_jsons.id = CASE WHEN collections.blockchain = 'polygon'
+    THEN collection_mints.collection_id ELSE collection_mints.id END
+WHERE collection_mints.collection_id = $1
+    AND collection_mints.removed_at IS NULL
+    AND collection_mints.id > $2
+ORDER BY collection_mints.id
+LIMIT $3";
+
+/// The header row of CSV exports.
+const CSV_HEADER: &str = "id,address,owner,edition,status,metadata_uri,attributes\n";
+
+/// A mint written to a collection export.
+#[derive(Debug, Clone, FromQueryResult, Serialize)]
+struct ExportRow {
+    id: Uuid,
+    address: Option<String>,
+    owner: Option<String>,
+    edition: i64,
+    status: String,
+    metadata_uri: Option<String>,
+    attributes: Value,
+}
+
+impl ExportRow {
+    fn write_csv(&self, out: &mut String) {
+        let attributes = self.attributes.to_string();
+
+        let cells = [
+            self.id.to_string(),
+            self.address.clone().unwrap_or_default(),
+            self.owner.clone().unwrap_or_default(),
+            self.edition.to_string(),
+            self.status.clone(),
+            self.metadata_uri.clone().unwrap_or_default(),
+            attributes,
+        ];
+
+        let line = cells
+            .iter()
+            .map(|cell| escape_csv(cell))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        out.push_str(&line);
+        out.push('\n');
+    }
+}
+
+/// Quotes a CSV cell when it contains a separator, a quote or a line break.
+fn escape_csv(cell: &str) -> String {
+    if cell.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+/// Writes every mint of a collection to a CSV or JSON file and uploads it to hub uploads.
+/// The file is written to the temporary directory and removed once uploaded.
+#[derive(Clone, Serialize, Debug, Deserialize)]
+pub struct CollectionExportTask {
+    pub export_id: Uuid,
+}
+
+impl CollectionExportTask {
+    #[must_use]
+    pub fn new(export_id: Uuid) -> Self {
+        Self { export_id }
+    }
+
+    async fn export(
+        &self,
+        conn: &DatabaseConnection,
+        hub_uploads: &HubUploadClient,
+        export: &collection_exports::Model,
+    ) -> Result<(), BackgroundTaskError> {
+        let (extension, content_type) = match export.format {
+            ExportFormat::Csv => ("csv", "text/csv"),
+            ExportFormat::Json => ("json", "application/json"),
+        };
+        let file_name = format!("{}.{extension}", export.id);
+        let path = std::env::temp_dir().join(&file_name);
+
+        let res = self
+            .write_and_upload(conn, hub_uploads, export, &path, &file_name, content_type)
+            .await;
+
+        if let Err(e) = tokio::fs::remove_file(&path).await {
+            error!("failed to remove export file {}: {e}", path.display());
+        }
+
+        res
+    }
+
+    /// Writes the rows of the export to a file page by page so the export is never held in memory, then uploads the file.
+    async fn write_and_upload(
+        &self,
+        conn: &DatabaseConnection,
+        hub_uploads: &HubUploadClient,
+        export: &collection_exports::Model,
+        path: &Path,
+        file_name: &str,
+        content_type: &str,
+    ) -> Result<(), BackgroundTaskError> {
+        let mut file = BufWriter::new(tokio::fs::File::create(path).await.map_err(Error::from)?);
+
+        let header = match export.format {
+            ExportFormat::Csv => CSV_HEADER,
+            ExportFormat::Json => "[",
+        };
+        file.write_all(header.as_bytes())
+            .await
+            .map_err(Error::from)?;
+
+        let mut rows: i64 = 0;
+        let mut after = Uuid::nil();
+
+        loop {
+            let page = ExportRow::find_by_statement(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                EXPORT_ROWS_SQL,
+                [
+                    export.collection_id.into(),
+                    after.into(),
+                    EXPORT_CHUNK_SIZE.into(),
+                ],
+            ))
+            .all(conn)
+            .await?;
+
+            let Some(last) = page.last() else {
+                break;
+            };
+
+            after = last.id;
+
+            let mut out = String::new();
+
+            for row in &page {
+                match export.format {
+                    ExportFormat::Csv => row.write_csv(&mut out),
+                    ExportFormat::Json => {
+                        if rows > 0 {
+                            out.push(',');
+                        }
+
+                        out.push_str(&serde_json::to_string(row).map_err(Error::from)?);
+                    },
+                }
+
+                rows += 1;
+            }
+
+            file.write_all(out.as_bytes()).await.map_err(Error::from)?;
+        }
+
+        if export.format == ExportFormat::Json {
+            file.write_all(b"]").await.map_err(Error::from)?;
+        }
+
+        file.flush().await.map_err(Error::from)?;
+        drop(file);
+
+        let upload = hub_uploads
+            .upload_path(file_name, content_type, path)
+            .await?;
+
+        let mut export_am: collection_exports::ActiveModel = export.clone().into();
+
+        export_am.status = Set(CreationStatus::Created);
+        export_am.uri = Set(Some(upload.uri));
+        export_am.cid = Set(Some(upload.cid));
+        export_am.rows = Set(rows);
+        export_am.last_error = Set(None);
+        export_am.completed_at = Set(Some(Utc::now().into()));
+
+        export_am.update(conn).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl BackgroundTask<HubUploadClient> for CollectionExportTask {
+    const QUEUE: &'static str = "export_job_queue";
+    const NAME: &'static str = "CollectionExportTask";
+
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn queue(&self) -> &'static str {
+        Self::QUEUE
+    }
+
+    fn payload(&self) -> Result<Value> {
+        serde_json::to_value(self).map_err(Into::into)
+    }
+
+    async fn process(
+        &self,
+        db: Connection,
+        hub_uploads: HubUploadClient,
+    ) -> Result<(), BackgroundTaskError> {
+        let conn = db.get();
+
+        let export = collection_exports::Entity::find_by_id(self.export_id)
+            .one(conn)
+            .await?
+            .ok_or(BackgroundTaskError::RecordNotFound)?;
+
+        // The failure is recorded on the export so the job is not retried.
+        if let Err(e) = self.export(conn, &hub_uploads, &export).await {
+            error!("export {} failed: {e}", self.export_id);
+
+            let mut export_am: collection_exports::ActiveModel = export.into();
+
+            export_am.status = Set(CreationStatus::Failed);
+            export_am.last_error = Set(Some(e.to_string()));
+
+            export_am.update(conn).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn escape_csv_leaves_plain_cells() {
+        assert_eq!(escape_csv("plain cell"), "plain cell");
+        assert_eq!(escape_csv(""), "");
+    }
+
+    #[test]
+    fn escape_csv_quotes_separators_and_line_breaks() {
+        assert_eq!(escape_csv("a,b"), "\"a,b\"");
+        assert_eq!(escape_csv("a\nb"), "\"a\nb\"");
+        assert_eq!(escape_csv("a\r\nb"), "\"a\r\nb\"");
+    }
+
+    #[test]
+    fn escape_csv_doubles_quotes() {
+        assert_eq!(escape_csv("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn write_csv_writes_a_row() {
+        let row = ExportRow {
+            id: Uuid::from_u128(1),
+            address: Some("address".to_string()),
+            owner: None,
+            edition: 2,
+            status: "created".to_string(),
+            metadata_uri: None,
+            attributes: json!([{ "trait_type": "color", "value": "red" }]),
+        };
+        let mut out = String::new();
+
+        row.write_csv(&mut out);
+
+        assert_eq!(
+            out,
+            "00000000-0000-0000-0000-000000000001,address,,2,created,,\"[{\"\"trait_type\"\":\"\"color\"\",\"\"value\"\":\"\"red\"\"}]\"\n"
+        );
+    }
+}
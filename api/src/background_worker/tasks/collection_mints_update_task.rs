@@ -0,0 +1,464 @@
+use hub_core::{
+    anyhow::{Error, Result},
+    chrono::Utc,
+    credits::{CreditsClient, TransactionId},
+    prelude::*,
+    tokio,
+};
+use sea_orm::{
+    prelude::*, sea_query::Expr, Condition, ConnectionTrait, PaginatorTrait, QueryOrder,
+    QuerySelect, Set, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{
+    BackgroundTask, BackgroundTaskError, CollectionRarityTask, MetadataJsonUploadCaller,
+    MetadataJsonUploadContext, MetadataJsonUploadTask, MetadataJsonUploadUpdateMint,
+};
+use crate::{
+    background_worker::job_queue::JobQueue,
+    db::Connection,
+    entities::{
+        collection_mint_update_errors, collection_mint_updates, collection_mints, collections,
+        job_trackings, metadata_json_versions, metadata_jsons,
+        sea_orm_active_enums::CreationStatus, update_histories,
+    },
+    metrics::Metrics,
+    mutations::{collection::validate_json, mint::record_metadata_version},
+    objects::{MetadataJsonInput, MetadataJsonPatchInput},
+    Actions,
+};
+
+/// The number of mints loaded per page. Cancellation is checked between pages.
+const MINTS_PAGE_SIZE: u64 = 100;
+
+/// The pause between updated mints, which bounds the rate of credit deductions and mint update events of a bulk update.
+const MINT_UPDATE_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Matches the upload jobs of the pending update histories of a bulk update
+const BULK_UPDATE_UPLOADS: &str = "(job_trackings.payload #>> '{caller,UpdateMint,update_history_id}')::uuid IN (SELECT id FROM update_histories WHERE collection_mint_update_id = ? AND status = 'pending')";
+
+#[derive(Clone)]
+pub struct Context {
+    job_queue: JobQueue,
+    credits: CreditsClient<Actions>,
+    metrics: Metrics,
+}
+
+impl std::fmt::Debug for Context {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Context")
+            .field("job_queue", &self.job_queue)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Context {
+    #[must_use]
+    pub fn new(job_queue: JobQueue, credits: CreditsClient<Actions>, metrics: Metrics) -> Self {
+        Self {
+            job_queue,
+            credits,
+            metrics,
+        }
+    }
+}
+
+/// The outcome of applying the patch to a single mint.
+enum MintOutcome {
+    /// The patch changed the metadata json.
+    Updated(MetadataJsonInput),
+    /// The patch left the metadata json unchanged.
+    Unchanged,
+    /// The patch could not be applied.
+    Failed(String),
+}
+
+/// Applies a metadata json patch to every updatable mint of a collection.
+/// Each changed mint is updated like `updateMint`: a credit deduction, an update history, a new metadata json version and an upload of the patched metadata json.
+/// Mints are processed in id order. The update of a mint, the tracking of its upload job and the progress of the bulk update are saved in one transaction,
+/// so a retried task resumes after the last processed mint and pushes the uploads that were saved but not queued.
+#[derive(Clone, Serialize, Debug, Deserialize)]
+pub struct CollectionMintsUpdateTask {
+    pub update_id: Uuid,
+}
+
+impl CollectionMintsUpdateTask {
+    #[must_use]
+    pub fn new(update_id: Uuid) -> Self {
+        Self { update_id }
+    }
+
+    /// The mints of the collection that can be updated: created, uncompressed and not an edition.
+    fn updatable_mints(collection_id: Uuid) -> Condition {
+        Condition::all()
+            .add(collection_mints::Column::CollectionId.eq(collection_id))
+            .add(collection_mints::Column::CreationStatus.eq(CreationStatus::Created))
+            .add(collection_mints::Column::Edition.eq(0))
+            .add(
+                Condition::any()
+                    .add(collection_mints::Column::Compressed.is_null())
+                    .add(collection_mints::Column::Compressed.eq(false)),
+            )
+    }
+
+    async fn update_mints(
+        &self,
+        conn: &DatabaseConnection,
+        context: &Context,
+        update: collection_mint_updates::Model,
+    ) -> Result<(), BackgroundTaskError> {
+        let collection = collections::Entity::find_by_id(update.collection_id)
+            .one(conn)
+            .await?
+            .ok_or(BackgroundTaskError::RecordNotFound)?;
+
+        let patch: MetadataJsonPatchInput = serde_json::from_value(update.patch.clone())
+            .map_err(|e| Error::msg(format!("invalid patch: {e}")))?;
+        let balance = u64::try_from(update.balance).unwrap_or_default();
+
+        let rows_total = match update.rows_total {
+            Some(rows_total) => rows_total,
+            None => collection_mints::Entity::find()
+                .filter(Self::updatable_mints(collection.id))
+                .count(conn)
+                .await?
+                .try_into()
+                .unwrap_or(i64::MAX),
+        };
+
+        let mut update_am: collection_mint_updates::ActiveModel = update.into();
+
+        update_am.status = Set(CreationStatus::Pending);
+        update_am.rows_total = Set(Some(rows_total));
+        update_am.last_error = Set(None);
+        update_am.updated_at = Set(Utc::now().into());
+
+        let mut update = update_am.update(conn).await?;
+        let mut canceled = false;
+
+        Self::push_saved_uploads(conn, context, update.id).await?;
+
+        loop {
+            let mut query = collection_mints::Entity::find()
+                .filter(Self::updatable_mints(collection.id))
+                .order_by_asc(collection_mints::Column::Id)
+                .limit(MINTS_PAGE_SIZE);
+
+            if let Some(last_mint_id) = update.last_mint_id {
+                query = query.filter(collection_mints::Column::Id.gt(last_mint_id));
+            }
+
+            let mints = query.all(conn).await?;
+
+            if mints.is_empty() {
+                break;
+            }
+
+            for mint in mints {
+                update =
+                    Self::update_mint(conn, context, &patch, &collection, update, &mint, balance)
+                        .await?;
+            }
+
+            canceled = collection_mint_updates::Entity::find_by_id(update.id)
+                .one(conn)
+                .await?
+                .is_some_and(|update| update.status == CreationStatus::Canceled);
+
+            if canceled {
+                break;
+            }
+        }
+
+        if update.rows_updated > 0 {
+            context
+                .job_queue
+                .enqueue_unless_queued(CollectionRarityTask::new(collection.id))
+                .await?;
+        }
+
+        let mut update_am: collection_mint_updates::ActiveModel = update.into();
+
+        update_am.status = Set(if canceled {
+            CreationStatus::Canceled
+        } else {
+            CreationStatus::Created
+        });
+        update_am.updated_at = Set(Utc::now().into());
+        update_am.completed_at = Set(Some(Utc::now().into()));
+
+        update_am.update(conn).await?;
+
+        Ok(())
+    }
+
+    /// Pushes the upload jobs of the mints the bulk update changed that are still pending and whose jobs were never dequeued.
+    /// A job is tracked in the transaction that updates its mint, so a task stopped before pushing it leaves a tracking behind.
+    async fn push_saved_uploads(
+        conn: &DatabaseConnection,
+        context: &Context,
+        update_id: Uuid,
+    ) -> Result<(), BackgroundTaskError> {
+        let job_type = <MetadataJsonUploadTask as BackgroundTask<MetadataJsonUploadContext>>::NAME;
+
+        let trackings = job_trackings::Entity::find()
+            .filter(job_trackings::Column::JobType.eq(job_type))
+            .filter(job_trackings::Column::Status.eq("queued"))
+            .filter(Expr::cust_with_values(BULK_UPDATE_UPLOADS, [update_id]))
+            .all(conn)
+            .await?;
+
+        let jobs =
+            JobQueue::tracked::<MetadataJsonUploadContext, MetadataJsonUploadTask>(trackings)?;
+
+        context.job_queue.push(jobs).await?;
+
+        Ok(())
+    }
+
+    /// Returns the metadata json of a mint the patch is applied to.
+    /// Metadata jsons saved without their document are missing fields, so the latest version of the mint is used when there is one.
+    async fn current_metadata_json(
+        conn: &DatabaseConnection,
+        mint_id: Uuid,
+    ) -> Result<Option<MetadataJsonInput>, BackgroundTaskError> {
+        let Some(metadata_json) = metadata_jsons::Entity::find_by_id(mint_id).one(conn).await?
+        else {
+            return Ok(None);
+        };
+
+        if metadata_json.document.is_none() {
+            let latest = metadata_json_versions::Entity::find()
+                .filter(metadata_json_versions::Column::CollectionMintId.eq(mint_id))
+                .order_by_desc(metadata_json_versions::Column::Version)
+                .one(conn)
+                .await?;
+
+            if let Some(version) = latest {
+                return Ok(Some(
+                    serde_json::from_value(version.metadata_json).map_err(Error::from)?,
+                ));
+            }
+        }
+
+        MetadataJsonInput::load(mint_id, conn)
+            .await
+            .map_err(|e| Error::msg(e.message).into())
+    }
+
+    /// Applies the patch to the metadata json of a mint.
+    async fn patch_mint(
+        conn: &DatabaseConnection,
+        patch: &MetadataJsonPatchInput,
+        collection: &collections::Model,
+        mint: &collection_mints::Model,
+    ) -> Result<MintOutcome, BackgroundTaskError> {
+        let Some(current) = Self::current_metadata_json(conn, mint.id).await? else {
+            return Ok(MintOutcome::Failed("Metadata json not found".to_string()));
+        };
+
+        let metadata_json = match patch.apply(current.clone()) {
+            Ok(metadata_json) => metadata_json,
+            Err(message) => return Ok(MintOutcome::Failed(message)),
+        };
+
+        if let Err(e) = validate_json(collection.blockchain, &metadata_json) {
+            return Ok(MintOutcome::Failed(e.message));
+        }
+
+        if metadata_json.content_hash().map_err(Error::from)?
+            == current.content_hash().map_err(Error::from)?
+        {
+            return Ok(MintOutcome::Unchanged);
+        }
+
+        Ok(MintOutcome::Updated(metadata_json))
+    }
+
+    /// Counts a processed mint and saves it as the last one, so a retried task resumes after it.
+    async fn record_progress(
+        db: &impl ConnectionTrait,
+        update: collection_mint_updates::Model,
+        mint_id: Uuid,
+        updated: i64,
+        failed: i64,
+    ) -> Result<collection_mint_updates::Model, BackgroundTaskError> {
+        let rows_processed = update.rows_processed;
+        let rows_updated = update.rows_updated;
+        let rows_failed = update.rows_failed;
+
+        let mut update_am: collection_mint_updates::ActiveModel = update.into();
+
+        update_am.rows_processed = Set(rows_processed + 1);
+        update_am.rows_updated = Set(rows_updated + updated);
+        update_am.rows_failed = Set(rows_failed + failed);
+        update_am.last_mint_id = Set(Some(mint_id));
+        update_am.updated_at = Set(Utc::now().into());
+
+        update_am.update(db).await.map_err(Into::into)
+    }
+
+    /// Applies the patch to a mint and, when it changed the metadata json, records the update and uploads it.
+    /// Mints the patch cannot be applied to are reported as failed rather than failing the bulk update.
+    /// Returns the progress of the bulk update after the mint.
+    async fn update_mint(
+        conn: &DatabaseConnection,
+        context: &Context,
+        patch: &MetadataJsonPatchInput,
+        collection: &collections::Model,
+        update: collection_mint_updates::Model,
+        mint: &collection_mints::Model,
+        balance: u64,
+    ) -> Result<collection_mint_updates::Model, BackgroundTaskError> {
+        let metadata_json = match Self::patch_mint(conn, patch, collection, mint).await? {
+            MintOutcome::Updated(metadata_json) => metadata_json,
+            MintOutcome::Unchanged => {
+                return Self::record_progress(conn, update, mint.id, 0, 0).await;
+            },
+            MintOutcome::Failed(message) => {
+                let tx = conn.begin().await?;
+
+                let error_am = collection_mint_update_errors::ActiveModel {
+                    update_id: Set(update.id),
+                    mint_id: Set(mint.id),
+                    message: Set(message),
+                    ..Default::default()
+                };
+
+                error_am.insert(&tx).await?;
+
+                let update = Self::record_progress(&tx, update, mint.id, 0, 1).await?;
+
+                tx.commit().await?;
+
+                return Ok(update);
+            },
+        };
+
+        let deduction = context
+            .credits
+            .submit_pending_deduction(
+                update.organization_id,
+                update.created_by,
+                Actions::UpdateMint,
+                collection.blockchain.into(),
+                balance,
+            )
+            .await;
+        context.metrics.record_credits_deduction(
+            Actions::UpdateMint,
+            collection.blockchain,
+            deduction.is_ok(),
+        );
+
+        let TransactionId(deduction_id) = deduction?;
+
+        let tx = conn.begin().await?;
+
+        let update_history_am = update_histories::ActiveModel {
+            mint_id: Set(mint.id),
+            txn_signature: Set(None),
+            credit_deduction_id: Set(deduction_id),
+            created_by: Set(update.created_by),
+            status: Set(CreationStatus::Pending),
+            collection_mint_update_id: Set(Some(update.id)),
+            ..Default::default()
+        };
+
+        let update_history = update_history_am.insert(&tx).await?;
+
+        record_metadata_version(
+            &tx,
+            mint.id,
+            update_history.id,
+            &metadata_json,
+            update.created_by,
+        )
+        .await
+        .map_err(|e| Error::msg(e.message))?;
+
+        metadata_json
+            .save(mint.id, &tx)
+            .await
+            .map_err(|e| Error::msg(e.message))?;
+
+        let jobs = context
+            .job_queue
+            .track_many(
+                &tx,
+                vec![MetadataJsonUploadTask {
+                    caller: MetadataJsonUploadCaller::UpdateMint(MetadataJsonUploadUpdateMint {
+                        update_history_id: update_history.id,
+                    }),
+                    metadata_json,
+                }],
+            )
+            .await?;
+
+        let update = Self::record_progress(&tx, update, mint.id, 1, 0).await?;
+
+        tx.commit().await?;
+
+        context.job_queue.push(jobs).await?;
+
+        tokio::time::sleep(MINT_UPDATE_INTERVAL).await;
+
+        Ok(update)
+    }
+}
+
+#[async_trait::async_trait]
+impl BackgroundTask<Context> for CollectionMintsUpdateTask {
+    const QUEUE: &'static str = "bulk_update_job_queue";
+    const NAME: &'static str = "CollectionMintsUpdateTask";
+
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn queue(&self) -> &'static str {
+        Self::QUEUE
+    }
+
+    fn payload(&self) -> Result<Value> {
+        serde_json::to_value(self).map_err(Into::into)
+    }
+
+    async fn process(&self, db: Connection, context: Context) -> Result<(), BackgroundTaskError> {
+        let conn = db.get();
+
+        let update = collection_mint_updates::Entity::find_by_id(self.update_id)
+            .one(conn)
+            .await?
+            .ok_or(BackgroundTaskError::RecordNotFound)?;
+
+        if matches!(
+            update.status,
+            CreationStatus::Created | CreationStatus::Canceled
+        ) {
+            return Ok(());
+        }
+
+        // The failure is recorded on the update so the job is not retried.
+        if let Err(e) = self.update_mints(conn, &context, update).await {
+            error!("collection mints update {} failed: {e}", self.update_id);
+
+            let update = collection_mint_updates::Entity::find_by_id(self.update_id)
+                .one(conn)
+                .await?
+                .ok_or(BackgroundTaskError::RecordNotFound)?;
+
+            let mut update_am: collection_mint_updates::ActiveModel = update.into();
+
+            update_am.status = Set(CreationStatus::Failed);
+            update_am.last_error = Set(Some(e.to_string()));
+            update_am.updated_at = Set(Utc::now().into());
+
+            update_am.update(conn).await?;
+        }
+
+        Ok(())
+    }
+}
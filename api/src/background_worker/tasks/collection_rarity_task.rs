@@ -0,0 +1,103 @@
+use hub_core::anyhow::Result;
+use sea_orm::{prelude::*, DbBackend, Statement, TransactionTrait};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{BackgroundTask, BackgroundTaskError};
+use crate::db::Connection;
+
+/// Mints that are part of the collection for rarity purposes. Failed mints and mints removed from the collection by a re-import are skipped.
+///
+/// Polygon mints share the metadata json of their collection so their attributes are read from it.
+const RARITY_MINTS_CTE: &str = "WITH mints AS (
+    SELECT collection_mints.id,
+        CASE WHEN collections.blockchain = 'polygon' THEN collections.id
+            ELSE collection_mints.id END AS metadata_json_id
+    FROM collection_mints
+    INNER JOIN collections ON collections.id = collection_mints.collection_id
+    WHERE collection_mints.collection_id = $1
+        AND collection_mints.creation_status IN ('created', 'pending', 'queued')
+        AND collection_mints.removed_at IS NULL
+)";
+
+/// Deletes the rarity of mints no longer part of the collection.
+const DELETE_STALE_RARITIES_SQL: &str = "DELETE FROM collection_mint_rarities
+    WHERE collection_id = $1 AND collection_mint_id NOT IN (SELECT id FROM mints)";
+
+/// Scores every mint by summing, for each of its attributes, the number of mints divided by the number of mints sharing the attribute value and by the number of distinct values of the trait type.
+const UPSERT_RARITIES_SQL: &str = ", attributes AS (
+    SELECT mints.id AS mint_id,
+        metadata_json_attributes.trait_type,
+        metadata_json_attributes.value
+    FROM metadata_json_attributes
+    INNER JOIN mints ON mints.metadata_json_id = metadata_json_attributes.metadata_json_id
+), trait_values AS (
+    SELECT trait_type, value, COUNT(*) AS occurrences,
+        COUNT(*) OVER (PARTITION BY trait_type) AS trait_type_values
+    FROM attributes
+    GROUP BY trait_type, value
+), total AS (
+    SELECT COUNT(*)::double precision AS mints FROM mints
+), scores AS (
+    SELECT mints.id AS collection_mint_id,
+        COALESCE(SUM(total.mints / trait_values.occurrences / trait_values.trait_type_values), 0) AS score
+    FROM mints
+    CROSS JOIN total
+    LEFT JOIN attributes ON attributes.mint_id = mints.id
+    LEFT JOIN trait_values ON trait_values.trait_type = attributes.trait_type
+        AND trait_values.value = attributes.value
+    GROUP BY mints.id
+)
+INSERT INTO collection_mint_rarities (collection_mint_id, collection_id, score, rank, updated_at)
+SELECT collection_mint_id, $1, score, RANK() OVER (ORDER BY score DESC), now()
+FROM scores
+ON CONFLICT (collection_mint_id) DO UPDATE
+SET score = EXCLUDED.score, rank = EXCLUDED.rank, updated_at = EXCLUDED.updated_at";
+
+/// Recomputes the rarity score and rank of every mint of a collection.
+#[derive(Clone, Serialize, Debug, Deserialize)]
+pub struct CollectionRarityTask {
+    pub collection_id: Uuid,
+}
+
+impl CollectionRarityTask {
+    #[must_use]
+    pub fn new(collection_id: Uuid) -> Self {
+        Self { collection_id }
+    }
+}
+
+#[async_trait::async_trait]
+impl BackgroundTask<()> for CollectionRarityTask {
+    const QUEUE: &'static str = "rarity_job_queue";
+    const NAME: &'static str = "CollectionRarityTask";
+
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn queue(&self) -> &'static str {
+        Self::QUEUE
+    }
+
+    fn payload(&self) -> Result<Value> {
+        serde_json::to_value(self).map_err(Into::into)
+    }
+
+    async fn process(&self, db: Connection, _context: ()) -> Result<(), BackgroundTaskError> {
+        let tx = db.get().begin().await?;
+
+        for sql in [DELETE_STALE_RARITIES_SQL, UPSERT_RARITIES_SQL] {
+            tx.execute(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                &format!("{RARITY_MINTS_CTE} {sql}"),
+                [self.collection_id.into()],
+            ))
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
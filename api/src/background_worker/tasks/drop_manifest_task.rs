@@ -0,0 +1,342 @@
+use hub_core::{
+    anyhow::{anyhow, Context as AnyhowContext, Error, Result},
+    chrono::Utc,
+    prelude::*,
+};
+use redis::{AsyncCommands, Client as RedisClient};
+use sea_orm::{prelude::*, Set, TransactionTrait};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{
+    BackgroundTask, BackgroundTaskError, CollectionRarityTask, MetadataJsonUploadCaller,
+    MetadataJsonUploadQueueMintToDrop, MetadataJsonUploadTask,
+};
+use crate::{
+    background_worker::job_queue::JobQueue,
+    db::Connection,
+    entities::{
+        collection_creators, collection_mints, drop_manifest_errors, drop_manifests, drops,
+        mint_creators, sea_orm_active_enums::CreationStatus,
+    },
+    mutations::collection::validate_json,
+    objects::MetadataJsonInput,
+    outbound,
+};
+
+/// The number of manifest rows queued per transaction.
+const MANIFEST_CHUNK_SIZE: usize = 500;
+
+/// The time allowed for downloading a manifest
+const MANIFEST_FETCH_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// The largest manifest downloaded, in bytes
+const MANIFEST_MAX_BYTES: u64 = 50 * 1024 * 1024;
+
+#[derive(Debug, Clone)]
+pub struct Context {
+    job_queue: JobQueue,
+    redis: RedisClient,
+    http: reqwest::Client,
+}
+
+impl Context {
+    /// Returns the context of the manifest task
+    ///
+    /// # Errors
+    /// if the http client fails to build
+    pub fn new(job_queue: JobQueue, redis: RedisClient) -> Result<Self> {
+        let http = outbound::client_builder()
+            .timeout(MANIFEST_FETCH_TIMEOUT)
+            .build()
+            .context("failed to build manifest http client")?;
+
+        Ok(Self {
+            job_queue,
+            redis,
+            http,
+        })
+    }
+}
+
+/// Queues a mint to a drop for every valid row of a manifest and enqueues the upload of their metadata jsons.
+/// Rows are processed in chunks and the progress of the manifest is saved with every chunk, so a retried task resumes after the last committed chunk.
+/// The upload jobs of a chunk are tracked in its transaction, so the queued mints of a committed chunk always have an upload job.
+#[derive(Clone, Serialize, Debug, Deserialize)]
+pub struct DropManifestTask {
+    pub manifest_id: Uuid,
+}
+
+impl DropManifestTask {
+    #[must_use]
+    pub fn new(manifest_id: Uuid) -> Self {
+        Self { manifest_id }
+    }
+
+    /// Reads the rows of the manifest from the inline entries or downloads them from the source url.
+    /// Only `https` urls of public hosts are downloaded and the download is capped at `MANIFEST_MAX_BYTES`.
+    async fn read_entries(
+        context: &Context,
+        manifest: &drop_manifests::Model,
+    ) -> Result<Vec<Value>> {
+        if let Some(entries) = &manifest.entries {
+            return serde_json::from_value(entries.clone()).context("invalid manifest entries");
+        }
+
+        let url = manifest
+            .source_url
+            .as_ref()
+            .ok_or_else(|| anyhow!("manifest has no entries or source url"))?;
+
+        let url = check_manifest_url(url)?;
+
+        let response = context
+            .http
+            .get(url)
+            .send()
+            .await
+            .context("failed to download manifest")?
+            .error_for_status()
+            .context("failed to download manifest")?;
+
+        let body = outbound::read_limited(response, MANIFEST_MAX_BYTES)
+            .await
+            .context("failed to download manifest")?;
+
+        serde_json::from_slice(&body).context("manifest is not a json array")
+    }
+
+    async fn queue_rows(
+        &self,
+        conn: &DatabaseConnection,
+        context: &Context,
+        manifest: drop_manifests::Model,
+    ) -> Result<(), BackgroundTaskError> {
+        let (drop, collection) = drops::Entity::find_by_id_with_collection(manifest.drop_id)
+            .one(conn)
+            .await?
+            .ok_or(BackgroundTaskError::RecordNotFound)?;
+
+        let collection = collection.ok_or(BackgroundTaskError::RecordNotFound)?;
+
+        let creators = collection_creators::Entity::find()
+            .filter(collection_creators::Column::CollectionId.eq(collection.id))
+            .all(conn)
+            .await?;
+
+        let entries = Self::read_entries(context, &manifest).await?;
+        let processed = usize::try_from(manifest.rows_processed).unwrap_or_default();
+
+        let mut manifest_am: drop_manifests::ActiveModel = manifest.into();
+
+        manifest_am.status = Set(CreationStatus::Pending);
+        manifest_am.rows_total = Set(Some(entries.len().try_into().unwrap_or(i64::MAX)));
+        manifest_am.last_error = Set(None);
+        manifest_am.updated_at = Set(Utc::now().into());
+
+        let mut manifest = manifest_am.update(conn).await?;
+
+        for (chunk_index, chunk) in entries
+            .get(processed..)
+            .unwrap_or_default()
+            .chunks(MANIFEST_CHUNK_SIZE)
+            .enumerate()
+        {
+            let offset = processed + chunk_index * MANIFEST_CHUNK_SIZE;
+            let tx = conn.begin().await?;
+
+            let mut tasks = Vec::new();
+            let mut errors = Vec::new();
+
+            for (index, entry) in chunk.iter().enumerate() {
+                let row = i64::try_from(offset + index).unwrap_or(i64::MAX);
+
+                let metadata_json = match serde_json::from_value::<MetadataJsonInput>(entry.clone())
+                {
+                    Ok(metadata_json) => metadata_json,
+                    Err(e) => {
+                        errors.push((row, format!("Invalid metadata json: {e}")));
+                        continue;
+                    },
+                };
+
+                if let Err(e) = validate_json(collection.blockchain, &metadata_json) {
+                    errors.push((row, e.message));
+                    continue;
+                }
+
+                let mint = collection_mints::ActiveModel {
+                    collection_id: Set(drop.collection_id),
+                    owner: Set(None),
+                    creation_status: Set(CreationStatus::Queued),
+                    created_by: Set(manifest.created_by),
+                    compressed: Set(None),
+                    seller_fee_basis_points: Set(collection.seller_fee_basis_points),
+                    ..Default::default()
+                };
+
+                let mint = mint.insert(&tx).await?;
+
+                metadata_json
+                    .save(mint.id, &tx)
+                    .await
+                    .map_err(|e| Error::msg(e.message))?;
+
+                let mint_creators = creators
+                    .iter()
+                    .map(|creator| mint_creators::ActiveModel {
+                        collection_mint_id: Set(mint.id),
+                        address: Set(creator.address.clone()),
+                        verified: Set(creator.verified),
+                        share: Set(creator.share),
+                    })
+                    .collect::<Vec<_>>();
+
+                if !mint_creators.is_empty() {
+                    mint_creators::Entity::insert_many(mint_creators)
+                        .exec(&tx)
+                        .await?;
+                }
+
+                tasks.push(MetadataJsonUploadTask {
+                    caller: MetadataJsonUploadCaller::QueueMintToDrop(
+                        MetadataJsonUploadQueueMintToDrop {
+                            drop_id: drop.id,
+                            collection_mint_id: mint.id,
+                        },
+                    ),
+                    metadata_json,
+                });
+            }
+
+            let rows_failed = i64::try_from(errors.len()).unwrap_or(i64::MAX);
+
+            if !errors.is_empty() {
+                let errors =
+                    errors
+                        .into_iter()
+                        .map(|(row, message)| drop_manifest_errors::ActiveModel {
+                            manifest_id: Set(manifest.id),
+                            row: Set(row),
+                            message: Set(message),
+                            ..Default::default()
+                        });
+
+                drop_manifest_errors::Entity::insert_many(errors)
+                    .exec(&tx)
+                    .await?;
+            }
+
+            let rows_processed = manifest.rows_processed;
+            let rows_queued = manifest.rows_queued;
+            let failed = manifest.rows_failed;
+
+            let mut manifest_am: drop_manifests::ActiveModel = manifest.into();
+
+            manifest_am.rows_processed =
+                Set(rows_processed + i64::try_from(chunk.len()).unwrap_or(i64::MAX));
+            manifest_am.rows_queued =
+                Set(rows_queued + i64::try_from(tasks.len()).unwrap_or(i64::MAX));
+            manifest_am.rows_failed = Set(failed + rows_failed);
+            manifest_am.updated_at = Set(Utc::now().into());
+
+            manifest = manifest_am.update(&tx).await?;
+
+            let jobs = context.job_queue.track_many(&tx, tasks).await?;
+
+            tx.commit().await?;
+
+            context.job_queue.push(jobs).await?;
+        }
+
+        if manifest.rows_queued > 0 {
+            context
+                .job_queue
+                .enqueue_unless_queued(CollectionRarityTask::new(drop.collection_id))
+                .await?;
+
+            let mut redis_conn = context.redis.get_async_connection().await?;
+
+            redis_conn
+                .del(format!("collection:{}:supply", drop.collection_id))
+                .await?;
+        }
+
+        let mut manifest_am: drop_manifests::ActiveModel = manifest.into();
+
+        manifest_am.status = Set(CreationStatus::Created);
+        manifest_am.updated_at = Set(Utc::now().into());
+        manifest_am.completed_at = Set(Some(Utc::now().into()));
+
+        manifest_am.update(conn).await?;
+
+        Ok(())
+    }
+}
+
+/// Parses a manifest url and checks it is an `https` url that does not point to a non-public address
+///
+/// # Errors
+/// if the url is invalid, is not `https` or points to a non-public address
+pub fn check_manifest_url(url: &str) -> Result<Url> {
+    let url = Url::parse(url).context("invalid manifest url")?;
+
+    if url.scheme() != "https" {
+        bail!("manifest url must be https");
+    }
+
+    outbound::check_url(&url)?;
+
+    Ok(url)
+}
+
+#[async_trait::async_trait]
+impl BackgroundTask<Context> for DropManifestTask {
+    const QUEUE: &'static str = "manifest_job_queue";
+    const NAME: &'static str = "DropManifestTask";
+
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn queue(&self) -> &'static str {
+        Self::QUEUE
+    }
+
+    fn payload(&self) -> Result<Value> {
+        serde_json::to_value(self).map_err(Into::into)
+    }
+
+    async fn process(&self, db: Connection, context: Context) -> Result<(), BackgroundTaskError> {
+        let conn = db.get();
+
+        let manifest = drop_manifests::Entity::find_by_id(self.manifest_id)
+            .one(conn)
+            .await?
+            .ok_or(BackgroundTaskError::RecordNotFound)?;
+
+        if manifest.status == CreationStatus::Created {
+            return Ok(());
+        }
+
+        // The failure is recorded on the manifest so the job is not retried.
+        if let Err(e) = self.queue_rows(conn, &context, manifest).await {
+            error!("drop manifest {} failed: {e}", self.manifest_id);
+
+            let manifest = drop_manifests::Entity::find_by_id(self.manifest_id)
+                .one(conn)
+                .await?
+                .ok_or(BackgroundTaskError::RecordNotFound)?;
+
+            let mut manifest_am: drop_manifests::ActiveModel = manifest.into();
+
+            manifest_am.status = Set(CreationStatus::Failed);
+            manifest_am.last_error = Set(Some(e.to_string()));
+            manifest_am.updated_at = Set(Utc::now().into());
+
+            manifest_am.update(conn).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,5 +1,11 @@
-use hub_core::anyhow::Result;
-use sea_orm::{prelude::*, Set};
+use std::collections::HashMap;
+
+use hub_core::anyhow::{Error, Result};
+use sea_orm::{
+    prelude::*,
+    sea_query::{Expr, OnConflict},
+    Set, TransactionTrait,
+};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -8,12 +14,15 @@ use crate::{
     blockchains::{polygon::Polygon, solana::Solana, CollectionEvent, DropEvent},
     db::Connection,
     entities::{
-        collection_creators, collection_mints, collections, drops, metadata_jsons, mint_creators,
-        sea_orm_active_enums::Blockchain as BlockchainEnum, update_histories,
+        collection_creators, collection_mints, collections, drops, media_assets,
+        metadata_json_files, metadata_json_uploads, metadata_json_versions, metadata_jsons,
+        mint_creators,
+        sea_orm_active_enums::{Blockchain as BlockchainEnum, CreationStatus},
+        update_histories,
     },
-    hub_uploads::{HubUploadClient, UploadResponse},
+    hub_uploads::{AssetIngestion, AssetKind, HubUploadClient, InvalidAsset, UploadResponse},
     mutations::collection::fetch_owner,
-    objects::MetadataJsonInput,
+    objects::{canonical_hash, MetadataJsonInput},
     proto::{
         CreateEditionTransaction, EditionInfo, MasterEdition, MetaplexMasterEditionTransaction,
         MetaplexMetadata, MintMetaplexMetadataTransaction, NftEventKey, UpdateEdtionTransaction,
@@ -313,10 +322,13 @@ impl After for QueueMintToDrop {
     ) -> Result<(), BackgroundTaskError> {
         let conn = db.get();
 
-        let metadata_json = metadata_jsons::Entity::find_by_id(self.collection_mint_id)
+        // The queued mint was deleted before its metadata json was uploaded.
+        let Some(metadata_json) = metadata_jsons::Entity::find_by_id(self.collection_mint_id)
             .one(conn)
             .await?
-            .ok_or(BackgroundTaskError::RecordNotFound)?;
+        else {
+            return Ok(());
+        };
 
         let mut metadata_json_am: metadata_jsons::ActiveModel = metadata_json.clone().into();
 
@@ -371,6 +383,19 @@ impl After for UpdateMint {
             .await
             .map_err(|_| BackgroundTaskError::NoProjectWallet)?;
 
+        metadata_json_versions::Entity::update_many()
+            .col_expr(
+                metadata_json_versions::Column::Uri,
+                Expr::value(Some(upload_response.uri.clone())),
+            )
+            .col_expr(
+                metadata_json_versions::Column::Identifier,
+                Expr::value(Some(upload_response.cid.clone())),
+            )
+            .filter(metadata_json_versions::Column::UpdateHistoryId.eq(update_history.id))
+            .exec(conn)
+            .await?;
+
         let mut metadata_json_am: metadata_jsons::ActiveModel = metadata_json.clone().into();
 
         metadata_json_am.uri = Set(Some(upload_response.uri));
@@ -610,6 +635,155 @@ pub enum Caller {
     UpdateMint(UpdateMint),
 }
 
+/// The metadata json a caller uploads and the blockchain its collection is on.
+#[derive(Debug, Clone, Copy)]
+struct Target {
+    metadata_json_id: Uuid,
+    blockchain: BlockchainEnum,
+}
+
+impl Caller {
+    /// Returns the metadata json the caller uploads, or `None` if it was deleted before the upload.
+    /// Collections and drops share the metadata json of their collection; mints have their own.
+    async fn target(
+        &self,
+        conn: &DatabaseConnection,
+    ) -> Result<Option<Target>, BackgroundTaskError> {
+        let target = match self {
+            Self::CreateCollection(CreateCollection { collection_id })
+            | Self::PatchCollection(PatchCollection { collection_id, .. }) => {
+                collections::Entity::find_by_id(*collection_id)
+                    .one(conn)
+                    .await?
+                    .map(|collection| Target {
+                        metadata_json_id: collection.id,
+                        blockchain: collection.blockchain,
+                    })
+            },
+            Self::CreateDrop(CreateDrop { drop_id })
+            | Self::PatchDrop(PatchDrop { drop_id, .. }) => {
+                drops::Entity::find_by_id_with_collection(*drop_id)
+                    .one(conn)
+                    .await?
+                    .and_then(|(_, collection)| collection)
+                    .map(|collection| Target {
+                        metadata_json_id: collection.id,
+                        blockchain: collection.blockchain,
+                    })
+            },
+            Self::MintToCollection(MintToCollection { collection_mint_id })
+            | Self::QueueMintToDrop(QueueMintToDrop {
+                collection_mint_id, ..
+            }) => mint_target(conn, *collection_mint_id).await?,
+            Self::UpdateMint(UpdateMint { update_history_id }) => {
+                let update_history = update_histories::Entity::find_by_id(*update_history_id)
+                    .one(conn)
+                    .await?
+                    .ok_or(BackgroundTaskError::RecordNotFound)?;
+
+                mint_target(conn, update_history.mint_id).await?
+            },
+        };
+
+        let Some(target) = target else {
+            return Ok(None);
+        };
+
+        let metadata_json = metadata_jsons::Entity::find_by_id(target.metadata_json_id)
+            .one(conn)
+            .await?;
+
+        Ok(metadata_json.map(|_| target))
+    }
+
+    /// Records why the media of the metadata json could not be ingested and fails the record the caller was creating.
+    /// Patches keep the status of their collection or drop.
+    async fn fail(
+        &self,
+        conn: &DatabaseConnection,
+        target: Target,
+        reason: String,
+    ) -> Result<(), BackgroundTaskError> {
+        let tx = conn.begin().await?;
+
+        metadata_jsons::Entity::update_many()
+            .col_expr(
+                metadata_jsons::Column::IngestionError,
+                Expr::value(Some(reason)),
+            )
+            .filter(metadata_jsons::Column::Id.eq(target.metadata_json_id))
+            .exec(&tx)
+            .await?;
+
+        match self {
+            Self::CreateCollection(CreateCollection { collection_id }) => {
+                collections::Entity::update_many()
+                    .set(collections::ActiveModel {
+                        creation_status: Set(CreationStatus::Failed),
+                        ..Default::default()
+                    })
+                    .filter(collections::Column::Id.eq(*collection_id))
+                    .exec(&tx)
+                    .await?;
+            },
+            Self::CreateDrop(CreateDrop { drop_id }) => {
+                drops::Entity::update_many()
+                    .set(drops::ActiveModel {
+                        creation_status: Set(CreationStatus::Failed),
+                        ..Default::default()
+                    })
+                    .filter(drops::Column::Id.eq(*drop_id))
+                    .exec(&tx)
+                    .await?;
+            },
+            Self::MintToCollection(MintToCollection { collection_mint_id })
+            | Self::QueueMintToDrop(QueueMintToDrop {
+                collection_mint_id, ..
+            }) => {
+                collection_mints::Entity::update_many()
+                    .set(collection_mints::ActiveModel {
+                        creation_status: Set(CreationStatus::Failed),
+                        ..Default::default()
+                    })
+                    .filter(collection_mints::Column::Id.eq(*collection_mint_id))
+                    .exec(&tx)
+                    .await?;
+            },
+            Self::UpdateMint(UpdateMint { update_history_id }) => {
+                update_histories::Entity::update_many()
+                    .set(update_histories::ActiveModel {
+                        status: Set(CreationStatus::Failed),
+                        ..Default::default()
+                    })
+                    .filter(update_histories::Column::Id.eq(*update_history_id))
+                    .exec(&tx)
+                    .await?;
+            },
+            Self::PatchCollection(_) | Self::PatchDrop(_) => {},
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
+
+async fn mint_target(
+    conn: &DatabaseConnection,
+    collection_mint_id: Uuid,
+) -> Result<Option<Target>, BackgroundTaskError> {
+    let mint = collection_mints::Entity::find_by_id_with_collection(collection_mint_id)
+        .one(conn)
+        .await?;
+
+    Ok(mint.and_then(|(mint, collection)| {
+        collection.map(|collection| Target {
+            metadata_json_id: mint.id,
+            blockchain: collection.blockchain,
+        })
+    }))
+}
+
 #[async_trait::async_trait]
 impl After for Caller {
     async fn after(
@@ -644,6 +818,204 @@ impl MetadataJsonUploadTask {
             caller,
         }
     }
+
+    /// Re-hosts the image, animation and files referenced by the metadata json and returns it with their pinned URIs.
+    /// The saved metadata json and its files are updated to the pinned URIs and the detected file types.
+    async fn ingest_assets(
+        &self,
+        conn: &DatabaseConnection,
+        hub_uploads: &HubUploadClient,
+        assets: &AssetIngestion,
+        metadata_json_id: Uuid,
+    ) -> Result<MetadataJsonInput, BackgroundTaskError> {
+        let mut metadata_json = self.metadata_json.clone();
+        let mut ingested = HashMap::new();
+
+        let image = ingest_asset(
+            conn,
+            hub_uploads,
+            assets,
+            &mut ingested,
+            &metadata_json.image,
+            AssetKind::Image,
+        )
+        .await?;
+        metadata_json.image = image.uri;
+
+        if let Some(animation_url) = metadata_json.animation_url.clone() {
+            let animation = ingest_asset(
+                conn,
+                hub_uploads,
+                assets,
+                &mut ingested,
+                &animation_url,
+                AssetKind::Media,
+            )
+            .await?;
+            metadata_json.animation_url = Some(animation.uri);
+        }
+
+        let files = metadata_json
+            .properties
+            .as_mut()
+            .and_then(|properties| properties.files.as_mut());
+
+        if let Some(files) = files {
+            for file in files.iter_mut() {
+                let Some(uri) = file.uri.clone() else {
+                    continue;
+                };
+
+                let asset = ingest_asset(
+                    conn,
+                    hub_uploads,
+                    assets,
+                    &mut ingested,
+                    &uri,
+                    AssetKind::Media,
+                )
+                .await?;
+
+                file.uri = Some(asset.uri);
+                file.file_type = Some(asset.content_type);
+            }
+        }
+
+        let tx = conn.begin().await?;
+
+        metadata_jsons::Entity::update_many()
+            .col_expr(
+                metadata_jsons::Column::Image,
+                Expr::value(metadata_json.image.clone()),
+            )
+            .col_expr(
+                metadata_jsons::Column::AnimationUrl,
+                Expr::value(metadata_json.animation_url.clone()),
+            )
+            .col_expr(
+                metadata_jsons::Column::ContentHash,
+                Expr::value(Some(metadata_json.content_hash().map_err(Error::from)?)),
+            )
+            .col_expr(
+                metadata_jsons::Column::Document,
+                Expr::value(Some(
+                    serde_json::to_value(&metadata_json).map_err(Error::from)?,
+                )),
+            )
+            .filter(metadata_jsons::Column::Id.eq(metadata_json_id))
+            .exec(&tx)
+            .await?;
+
+        if let Some(files) = metadata_json
+            .properties
+            .as_ref()
+            .and_then(|properties| properties.files.as_ref())
+        {
+            metadata_json_files::Entity::delete_many()
+                .filter(metadata_json_files::Column::MetadataJsonId.eq(metadata_json_id))
+                .exec(&tx)
+                .await?;
+
+            let files = files
+                .iter()
+                .map(|file| metadata_json_files::ActiveModel {
+                    metadata_json_id: Set(metadata_json_id),
+                    uri: Set(file.uri.clone()),
+                    file_type: Set(file.file_type.clone()),
+                    ..Default::default()
+                })
+                .collect::<Vec<_>>();
+
+            if !files.is_empty() {
+                metadata_json_files::Entity::insert_many(files)
+                    .exec(&tx)
+                    .await?;
+            }
+        }
+
+        tx.commit().await?;
+
+        Ok(metadata_json)
+    }
+}
+
+/// Returns the re-hosted asset for a URI referenced by a metadata json.
+/// URIs already pinned by a previous ingestion are kept and assets with the same contents are only uploaded once.
+async fn ingest_asset(
+    conn: &DatabaseConnection,
+    hub_uploads: &HubUploadClient,
+    assets: &AssetIngestion,
+    ingested: &mut HashMap<String, media_assets::Model>,
+    uri: &str,
+    kind: AssetKind,
+) -> Result<media_assets::Model, BackgroundTaskError> {
+    let asset = if let Some(asset) = ingested.get(uri) {
+        asset.clone()
+    } else if let Some(asset) = media_assets::Entity::find()
+        .filter(media_assets::Column::Uri.eq(uri))
+        .one(conn)
+        .await?
+    {
+        asset
+    } else {
+        let fetched = assets.fetch(uri, kind).await.map_err(asset_error)?;
+
+        if let Some(asset) = media_assets::Entity::find_by_id(fetched.content_hash.clone())
+            .one(conn)
+            .await?
+        {
+            asset
+        } else {
+            let size = i64::try_from(fetched.contents.len()).unwrap_or(i64::MAX);
+            let upload = hub_uploads
+                .upload_file(&fetched.file_name, &fetched.content_type, fetched.contents)
+                .await?;
+
+            let asset_am = media_assets::ActiveModel {
+                content_hash: Set(fetched.content_hash.clone()),
+                uri: Set(upload.uri),
+                cid: Set(upload.cid),
+                content_type: Set(fetched.content_type),
+                size: Set(size),
+                width: Set(fetched.width.and_then(|width| i32::try_from(width).ok())),
+                height: Set(fetched.height.and_then(|height| i32::try_from(height).ok())),
+                ..Default::default()
+            };
+
+            media_assets::Entity::insert(asset_am)
+                .on_conflict(
+                    OnConflict::column(media_assets::Column::ContentHash)
+                        .do_nothing()
+                        .to_owned(),
+                )
+                .exec_without_returning(conn)
+                .await?;
+
+            media_assets::Entity::find_by_id(fetched.content_hash)
+                .one(conn)
+                .await?
+                .ok_or(BackgroundTaskError::RecordNotFound)?
+        }
+    };
+
+    if !kind.accepts(&asset.content_type) {
+        return Err(BackgroundTaskError::InvalidAsset(format!(
+            "asset {uri} has unsupported content type {}",
+            asset.content_type
+        )));
+    }
+
+    ingested.insert(uri.to_string(), asset.clone());
+
+    Ok(asset)
+}
+
+/// Separates assets that failed validation, which are not retried, from failed downloads and uploads
+fn asset_error(error: Error) -> BackgroundTaskError {
+    match error.downcast::<InvalidAsset>() {
+        Ok(invalid) => BackgroundTaskError::InvalidAsset(invalid.to_string()),
+        Err(error) => error.into(),
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -682,7 +1054,73 @@ impl BackgroundTask<Context> for MetadataJsonUploadTask {
     }
 
     async fn process(&self, db: Connection, context: Context) -> Result<(), BackgroundTaskError> {
-        let response = context.hub_uploads.upload(&self.metadata_json).await?;
+        let conn = db.get();
+
+        // The record was deleted before its metadata json was uploaded.
+        let Some(target) = self.caller.target(conn).await? else {
+            return Ok(());
+        };
+
+        let ingested = match context.hub_uploads.assets() {
+            Some(assets) => {
+                self.ingest_assets(conn, &context.hub_uploads, assets, target.metadata_json_id)
+                    .await
+            },
+            None => Ok(self.metadata_json.clone()),
+        };
+
+        // An asset that failed validation fails the same way every time, so the task is not retried.
+        let metadata_json = match ingested {
+            Ok(metadata_json) => metadata_json,
+            Err(BackgroundTaskError::InvalidAsset(reason)) => {
+                self.caller.fail(conn, target, reason).await?;
+
+                return Ok(());
+            },
+            Err(e) => return Err(e),
+        };
+
+        let document = metadata_json
+            .render(target.blockchain.into())
+            .map_err(Error::from)?;
+        let content_hash = canonical_hash(&document);
+        let storage = context.hub_uploads.location();
+
+        let upload =
+            metadata_json_uploads::Entity::find_by_id((storage.clone(), content_hash.clone()))
+                .one(conn)
+                .await?;
+
+        let response = if let Some(upload) = upload {
+            upload.into()
+        } else {
+            let response = context
+                .hub_uploads
+                .upload(&document, target.blockchain)
+                .await?;
+
+            let upload_am = metadata_json_uploads::ActiveModel {
+                storage: Set(storage),
+                content_hash: Set(content_hash),
+                uri: Set(response.uri.clone()),
+                cid: Set(response.cid.clone()),
+                ..Default::default()
+            };
+
+            metadata_json_uploads::Entity::insert(upload_am)
+                .on_conflict(
+                    OnConflict::columns([
+                        metadata_json_uploads::Column::Storage,
+                        metadata_json_uploads::Column::ContentHash,
+                    ])
+                    .do_nothing()
+                    .to_owned(),
+                )
+                .exec_without_returning(conn)
+                .await?;
+
+            response
+        };
 
         self.caller.after(db, context.clone(), response).await?;
 
@@ -1,10 +1,16 @@
 use hub_core::{anyhow::Result, prelude::*, producer::SendError, thiserror, url};
+use redis::RedisError;
 use sea_orm::error::DbErr;
 use serde_json::Value as Json;
 
-use crate::db::Connection;
+use crate::{background_worker::job_queue::JobQueueError, db::Connection};
 
+mod collection_export_task;
+mod collection_mints_update_task;
+mod collection_rarity_task;
+mod drop_manifest_task;
 mod metadata_json_upload_task;
+mod webhook_delivery_task;
 
 #[derive(thiserror::Error, Debug)]
 pub enum BackgroundTaskError {
@@ -32,6 +38,14 @@ pub enum BackgroundTaskError {
     NoCreator,
     #[error("No metadata json uri")]
     NoMetadataUri,
+    #[error("Job queue error: {0}")]
+    JobQueue(#[from] JobQueueError),
+    #[error("Redis error: {0}")]
+    Redis(#[from] RedisError),
+    #[error("Credits error: {0}")]
+    Credits(#[from] hub_core::credits::DeductionError<crate::Actions>),
+    #[error("Invalid asset: {0}")]
+    InvalidAsset(String),
 }
 
 #[async_trait::async_trait]
@@ -64,6 +78,14 @@ pub trait BackgroundTask<C: Clone + Send + Sync>: Send + Sync + std::fmt::Debug
     fn queue(&self) -> &'static str;
 }
 
+pub use collection_export_task::CollectionExportTask;
+pub use collection_mints_update_task::{
+    CollectionMintsUpdateTask, Context as CollectionMintsUpdateContext,
+};
+pub use collection_rarity_task::CollectionRarityTask;
+pub use drop_manifest_task::{
+    check_manifest_url, Context as DropManifestContext, DropManifestTask,
+};
 pub use metadata_json_upload_task::{
     Caller as MetadataJsonUploadCaller, Context as MetadataJsonUploadContext,
     CreateCollection as MetadataJsonUploadCreateCollection,
@@ -73,3 +95,4 @@ pub use metadata_json_upload_task::{
     QueueMintToDrop as MetadataJsonUploadQueueMintToDrop,
     UpdateMint as MetadataJsonUploadUpdateMint,
 };
+pub use webhook_delivery_task::WebhookDeliveryTask;
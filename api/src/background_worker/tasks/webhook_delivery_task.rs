@@ -0,0 +1,133 @@
+use hub_core::{
+    anyhow::Result,
+    chrono::{Duration as ChronoDuration, Utc},
+};
+use sea_orm::{prelude::*, Set};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{BackgroundTask, BackgroundTaskError};
+use crate::{
+    db::Connection,
+    entities::{
+        sea_orm_active_enums::WebhookDeliveryStatus, webhook_deliveries, webhook_endpoints,
+    },
+    webhooks::Webhooks,
+};
+
+/// Sends a webhook delivery to its endpoint.
+/// A failed attempt schedules the next one with an exponential backoff on the delivery, which is queued again by `Webhooks::poll` once it is due.
+/// The task completes after every attempt, so job trackings are not failed by endpoints that are down.
+#[derive(Clone, Serialize, Debug, Deserialize)]
+pub struct WebhookDeliveryTask {
+    pub delivery_id: Uuid,
+}
+
+impl WebhookDeliveryTask {
+    #[must_use]
+    pub fn new(delivery_id: Uuid) -> Self {
+        Self { delivery_id }
+    }
+}
+
+#[async_trait::async_trait]
+impl BackgroundTask<Webhooks> for WebhookDeliveryTask {
+    const QUEUE: &'static str = "webhook_job_queue";
+    const NAME: &'static str = "WebhookDeliveryTask";
+
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn queue(&self) -> &'static str {
+        Self::QUEUE
+    }
+
+    fn payload(&self) -> Result<Value> {
+        serde_json::to_value(self).map_err(Into::into)
+    }
+
+    async fn process(&self, db: Connection, webhooks: Webhooks) -> Result<(), BackgroundTaskError> {
+        let conn = db.get();
+
+        // deliveries are removed with their endpoint
+        let Some((delivery, endpoint)) = webhook_deliveries::Entity::find_by_id(self.delivery_id)
+            .find_also_related(webhook_endpoints::Entity)
+            .one(conn)
+            .await?
+        else {
+            return Ok(());
+        };
+
+        if delivery.status != WebhookDeliveryStatus::Pending {
+            return Ok(());
+        }
+
+        let endpoint = endpoint.ok_or(BackgroundTaskError::RecordNotFound)?;
+        let attempts = delivery.attempts + 1;
+
+        let mut delivery_am: webhook_deliveries::ActiveModel = delivery.clone().into();
+
+        if !endpoint.active {
+            delivery_am.status = Set(WebhookDeliveryStatus::Failed);
+            delivery_am.last_error = Set(Some("Webhook endpoint is disabled".to_string()));
+            delivery_am.next_attempt_at = Set(None);
+            delivery_am.updated_at = Set(Utc::now().into());
+
+            delivery_am.update(conn).await?;
+
+            return Ok(());
+        }
+
+        let error = match webhooks.send(&endpoint, &delivery).await {
+            Ok((status, body)) => {
+                delivery_am.response_status = Set(Some(status.as_u16().into()));
+                delivery_am.response_body = Set(Some(body));
+
+                (!status.is_success()).then(|| format!("Endpoint responded with {status}"))
+            },
+            Err(e) => {
+                delivery_am.response_status = Set(None);
+                delivery_am.response_body = Set(None);
+
+                Some(e.to_string())
+            },
+        };
+
+        let now = Utc::now();
+
+        delivery_am.attempts = Set(attempts);
+        delivery_am.updated_at = Set(now.into());
+
+        if error.is_none() {
+            delivery_am.last_error = Set(None);
+            delivery_am.status = Set(WebhookDeliveryStatus::Delivered);
+            delivery_am.next_attempt_at = Set(None);
+            delivery_am.delivered_at = Set(Some(now.into()));
+
+            delivery_am.update(conn).await?;
+
+            return Ok(());
+        }
+
+        delivery_am.last_error = Set(error);
+
+        if attempts >= webhooks.max_attempts() {
+            delivery_am.status = Set(WebhookDeliveryStatus::Failed);
+            delivery_am.next_attempt_at = Set(None);
+
+            delivery_am.update(conn).await?;
+
+            return Ok(());
+        }
+
+        let delay = ChronoDuration::from_std(webhooks.retry_delay(attempts))
+            .unwrap_or_else(|_| ChronoDuration::hours(1));
+
+        delivery_am.next_attempt_at = Set(Some((now + delay).into()));
+
+        delivery_am.update(conn).await?;
+
+        Ok(())
+    }
+}
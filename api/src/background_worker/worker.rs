@@ -1,3 +1,5 @@
+use std::time::Instant;
+
 use hub_core::{thiserror, tokio, tracing::error};
 use sea_orm::{error::DbErr, ActiveModelTrait};
 use serde::{Deserialize, Serialize};
@@ -7,7 +9,7 @@ use super::{
     job_queue::{JobQueue, JobQueueError},
     tasks::BackgroundTask,
 };
-use crate::{db::Connection, entities::job_trackings};
+use crate::{db::Connection, entities::job_trackings, metrics::Metrics};
 
 #[derive(thiserror::Error, Debug)]
 pub enum WorkerError {
@@ -20,6 +22,7 @@ pub struct Worker<C: Clone + std::fmt::Debug + Send + Sync, T: BackgroundTask<C>
     job_queue: JobQueue,
     db_pool: Connection,
     context: C,
+    metrics: Metrics,
     _task_marker: std::marker::PhantomData<T>,
 }
 
@@ -28,11 +31,12 @@ where
     T: 'static + Serialize + for<'de> Deserialize<'de> + Send + Sync + BackgroundTask<C>,
     C: 'static + Clone + std::fmt::Debug + Send + Sync,
 {
-    pub fn new(job_queue: JobQueue, db_pool: Connection, context: C) -> Self {
+    pub fn new(job_queue: JobQueue, db_pool: Connection, context: C, metrics: Metrics) -> Self {
         Self {
             job_queue,
             db_pool,
             context,
+            metrics,
             _task_marker: std::marker::PhantomData,
         }
     }
@@ -58,15 +62,22 @@ where
         let db_pool = self.db_pool.clone();
         let context = self.context.clone();
         let job_queue = self.job_queue.clone();
+        let metrics = self.metrics.clone();
 
         loop {
             // Dequeue the next job to process
             let job_option = job_queue.dequeue::<C, T>().await?;
 
+            match job_queue.depth(T::QUEUE).await {
+                Ok(depth) => metrics.set_job_queue_depth(T::NAME, T::QUEUE, depth),
+                Err(e) => error!("Error reading depth of queue {}: {}", T::QUEUE, e),
+            }
+
             tokio::spawn({
                 let db_pool = db_pool.clone();
                 let context = context.clone();
                 let job_queue = job_queue.clone();
+                let metrics = metrics.clone();
 
                 async move {
                     let db_conn = db_pool.get();
@@ -91,7 +102,9 @@ where
                             return;
                         };
 
+                        let started_at = Instant::now();
                         let result = job.task.process(db_pool_process, context).await;
+                        metrics.record_job(T::NAME, result.is_ok(), started_at.elapsed());
 
                         match result {
                             Ok(_) => {
@@ -157,10 +170,13 @@ where
                 Ok(task_payload) => {
                     let job = Job::new(failed_job.id, task_payload);
 
+                    let started_at = Instant::now();
                     let task_results = job
                         .task
                         .process(db_pool.clone(), self.context.clone())
                         .await;
+                    self.metrics
+                        .record_job(T::NAME, task_results.is_ok(), started_at.elapsed());
 
                     if let Err(e) = task_results {
                         error!("Error retrying job: {}", e);
@@ -0,0 +1,262 @@
+use std::collections::{BTreeMap, HashMap};
+
+use async_graphql::{dataloader::Loader as DataLoader, FieldError, Result};
+use hub_core::chrono::{DateTime, Utc};
+use poem::async_trait;
+use redis::{AsyncCommands, Client as Redis};
+use sea_orm::{prelude::*, DbBackend, FromQueryResult, Statement, Value};
+
+use crate::{
+    db::Connection,
+    objects::{
+        AnalyticsBucket, AnalyticsGranularity, AnalyticsRange, CollectionAnalytics, TopHolder,
+    },
+};
+
+/// How long computed analytics are cached in Redis.
+const CACHE_TTL_SECONDS: usize = 300;
+/// The number of holders returned in `topHolders`.
+const TOP_HOLDERS_LIMIT: i64 = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AnalyticsKey {
+    pub collection_id: Uuid,
+    pub range: AnalyticsRange,
+    pub granularity: AnalyticsGranularity,
+}
+
+impl AnalyticsKey {
+    fn redis_key(&self) -> String {
+        format!(
+            "collection:{}:analytics:{}:{}:{}",
+            self.collection_id,
+            self.range.from.timestamp(),
+            self.range.to.timestamp(),
+            self.granularity.as_date_trunc_field()
+        )
+    }
+}
+
+#[derive(FromQueryResult)]
+struct MintsBucket {
+    bucket: DateTime<Utc>,
+    mints: i64,
+    failed_mints: i64,
+}
+
+#[derive(FromQueryResult)]
+struct TransfersBucket {
+    bucket: DateTime<Utc>,
+    transfers: i64,
+}
+
+#[derive(FromQueryResult)]
+struct HoldersBucket {
+    bucket: DateTime<Utc>,
+    unique_holders: i64,
+}
+
+#[derive(FromQueryResult)]
+struct UniqueHolders {
+    unique_holders: i64,
+}
+
+#[derive(FromQueryResult)]
+struct HolderCount {
+    address: String,
+    owns: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Loader {
+    pub db: Connection,
+    pub redis: Redis,
+}
+
+impl Loader {
+    #[must_use]
+    pub fn new(db: Connection, redis: Redis) -> Self {
+        Self { db, redis }
+    }
+
+    async fn compute(&self, key: &AnalyticsKey) -> Result<CollectionAnalytics> {
+        let conn = self.db.get();
+        let AnalyticsKey {
+            collection_id,
+            range: AnalyticsRange { from, to },
+            granularity,
+        } = *key;
+        let values: [Value; 4] = [
+            granularity.as_date_trunc_field().into(),
+            collection_id.into(),
+            from.into(),
+            to.into(),
+        ];
+
+        let mints = MintsBucket::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT date_trunc($1, created_at) AS bucket,
+                COUNT(*) FILTER (WHERE status = 'created') AS mints,
+                COUNT(*) FILTER (WHERE status = 'failed') AS failed_mints
+            FROM mint_histories
+            WHERE collection_id = $2 AND created_at >= $3 AND created_at < $4
+            GROUP BY bucket",
+            values.clone(),
+        ))
+        .all(conn)
+        .await?;
+
+        let transfers = TransfersBucket::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT date_trunc($1, nft_transfers.created_at) AS bucket,
+                COUNT(*) AS transfers
+            FROM nft_transfers
+            INNER JOIN collection_mints ON collection_mints.id = nft_transfers.collection_mint_id
+            WHERE collection_mints.collection_id = $2
+                AND nft_transfers.created_at >= $3 AND nft_transfers.created_at < $4
+            GROUP BY bucket",
+            values.clone(),
+        ))
+        .all(conn)
+        .await?;
+
+        let holders = HoldersBucket::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT bucket, COUNT(DISTINCT wallet) AS unique_holders
+            FROM (
+                SELECT date_trunc($1, created_at) AS bucket, wallet
+                FROM mint_histories
+                WHERE collection_id = $2 AND status = 'created'
+                    AND created_at >= $3 AND created_at < $4
+                UNION ALL
+                SELECT date_trunc($1, nft_transfers.created_at) AS bucket, nft_transfers.recipient AS wallet
+                FROM nft_transfers
+                INNER JOIN collection_mints ON collection_mints.id = nft_transfers.collection_mint_id
+                WHERE collection_mints.collection_id = $2
+                    AND nft_transfers.created_at >= $3 AND nft_transfers.created_at < $4
+            ) AS activity
+            GROUP BY bucket",
+            values,
+        ))
+        .all(conn)
+        .await?;
+
+        let mut buckets = BTreeMap::new();
+
+        for MintsBucket {
+            bucket,
+            mints,
+            failed_mints,
+        } in mints
+        {
+            let entry = buckets
+                .entry(bucket)
+                .or_insert_with(|| empty_bucket(bucket));
+            entry.mints = mints;
+            entry.failed_mints = failed_mints;
+        }
+
+        for TransfersBucket { bucket, transfers } in transfers {
+            buckets
+                .entry(bucket)
+                .or_insert_with(|| empty_bucket(bucket))
+                .transfers = transfers;
+        }
+
+        for HoldersBucket {
+            bucket,
+            unique_holders,
+        } in holders
+        {
+            buckets
+                .entry(bucket)
+                .or_insert_with(|| empty_bucket(bucket))
+                .unique_holders = unique_holders;
+        }
+
+        let UniqueHolders { unique_holders } =
+            UniqueHolders::find_by_statement(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "SELECT COUNT(DISTINCT owner) AS unique_holders
+                FROM collection_mints
+                WHERE collection_id = $1 AND owner IS NOT NULL AND removed_at IS NULL",
+                [Value::from(collection_id)],
+            ))
+            .one(conn)
+            .await?
+            .unwrap_or(UniqueHolders { unique_holders: 0 });
+
+        let top_holders = HolderCount::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT owner AS address, COUNT(*) AS owns
+            FROM collection_mints
+            WHERE collection_id = $1 AND owner IS NOT NULL AND removed_at IS NULL
+            GROUP BY owner
+            ORDER BY owns DESC, address
+            LIMIT $2",
+            [Value::from(collection_id), Value::from(TOP_HOLDERS_LIMIT)],
+        ))
+        .all(conn)
+        .await?
+        .into_iter()
+        .map(|HolderCount { address, owns }| TopHolder { address, owns })
+        .collect();
+
+        Ok(CollectionAnalytics {
+            buckets: buckets.into_values().collect(),
+            unique_holders,
+            top_holders,
+        })
+    }
+}
+
+fn empty_bucket(start: DateTime<Utc>) -> AnalyticsBucket {
+    AnalyticsBucket {
+        start,
+        mints: 0,
+        failed_mints: 0,
+        transfers: 0,
+        unique_holders: 0,
+    }
+}
+
+#[async_trait]
+impl DataLoader<AnalyticsKey> for Loader {
+    type Error = FieldError;
+    type Value = CollectionAnalytics;
+
+    async fn load(
+        &self,
+        keys: &[AnalyticsKey],
+    ) -> Result<HashMap<AnalyticsKey, Self::Value>, Self::Error> {
+        let mut results: HashMap<AnalyticsKey, Self::Value> = HashMap::new();
+
+        let mut redis_connection = self.redis.get_async_connection().await?;
+
+        for key in keys {
+            let redis_key = key.redis_key();
+
+            if let Ok(value) = redis_connection.get::<_, String>(&redis_key).await {
+                if let Ok(analytics) = serde_json::from_str(&value) {
+                    results.insert(*key, analytics);
+
+                    continue;
+                }
+            }
+
+            let analytics = self.compute(key).await?;
+
+            redis_connection
+                .set_ex(
+                    &redis_key,
+                    serde_json::to_string(&analytics)?,
+                    CACHE_TTL_SECONDS,
+                )
+                .await?;
+
+            results.insert(*key, analytics);
+        }
+
+        Ok(results)
+    }
+}
@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+
+use async_graphql::{dataloader::Loader as DataLoader, FieldError, Result};
+use poem::async_trait;
+use sea_orm::prelude::*;
+
+use crate::{db::Connection, entities::collection_exports};
+
+#[derive(Debug, Clone)]
+pub struct Loader {
+    pub db: Connection,
+}
+
+impl Loader {
+    #[must_use]
+    pub fn new(db: Connection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DataLoader<Uuid> for Loader {
+    type Error = FieldError;
+    type Value = collection_exports::Model;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        let exports = collection_exports::Entity::find()
+            .filter(collection_exports::Column::Id.is_in(keys.iter().map(ToOwned::to_owned)))
+            .all(self.db.get())
+            .await?;
+
+        Ok(exports
+            .into_iter()
+            .map(|export| (export.id, export))
+            .collect())
+    }
+}
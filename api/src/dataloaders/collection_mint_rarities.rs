@@ -0,0 +1,40 @@
+use std::collections::HashMap;
+
+use async_graphql::{dataloader::Loader as DataLoader, FieldError, Result};
+use poem::async_trait;
+use sea_orm::prelude::*;
+
+use crate::{db::Connection, entities::collection_mint_rarities};
+
+#[derive(Debug, Clone)]
+pub struct Loader {
+    pub db: Connection,
+}
+
+impl Loader {
+    #[must_use]
+    pub fn new(db: Connection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DataLoader<Uuid> for Loader {
+    type Error = FieldError;
+    type Value = collection_mint_rarities::Model;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        let rarities = collection_mint_rarities::Entity::find()
+            .filter(
+                collection_mint_rarities::Column::CollectionMintId
+                    .is_in(keys.iter().map(ToOwned::to_owned)),
+            )
+            .all(self.db.get())
+            .await?;
+
+        Ok(rarities
+            .into_iter()
+            .map(|rarity| (rarity.collection_mint_id, rarity))
+            .collect())
+    }
+}
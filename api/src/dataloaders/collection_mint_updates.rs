@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use async_graphql::{dataloader::Loader as DataLoader, FieldError, Result};
+use poem::async_trait;
+use sea_orm::{prelude::*, QueryOrder};
+
+use crate::{
+    db::Connection,
+    entities::{collection_mint_update_errors, collection_mint_updates},
+};
+
+#[derive(Debug, Clone)]
+pub struct Loader {
+    pub db: Connection,
+}
+
+impl Loader {
+    #[must_use]
+    pub fn new(db: Connection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DataLoader<Uuid> for Loader {
+    type Error = FieldError;
+    type Value = collection_mint_updates::Model;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        let updates = collection_mint_updates::Entity::find()
+            .filter(collection_mint_updates::Column::Id.is_in(keys.iter().map(ToOwned::to_owned)))
+            .all(self.db.get())
+            .await?;
+
+        Ok(updates
+            .into_iter()
+            .map(|update| (update.id, update))
+            .collect())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ErrorsLoader {
+    pub db: Connection,
+}
+
+impl ErrorsLoader {
+    #[must_use]
+    pub fn new(db: Connection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DataLoader<Uuid> for ErrorsLoader {
+    type Error = FieldError;
+    type Value = Vec<collection_mint_update_errors::Model>;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        let errors = collection_mint_update_errors::Entity::find()
+            .filter(
+                collection_mint_update_errors::Column::UpdateId
+                    .is_in(keys.iter().map(ToOwned::to_owned)),
+            )
+            .order_by_asc(collection_mint_update_errors::Column::MintId)
+            .all(self.db.get())
+            .await?;
+
+        Ok(errors.into_iter().fold(HashMap::new(), |mut acc, error| {
+            acc.entry(error.update_id)
+                .or_insert_with(Vec::new)
+                .push(error);
+
+            acc
+        }))
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::{BTreeMap, HashMap};
+
+use async_graphql::{dataloader::Loader as DataLoader, FieldError, Result};
+use poem::async_trait;
+use sea_orm::{prelude::*, FromQueryResult, JoinType, QuerySelect, QueryTrait};
+
+use crate::{
+    db::Connection,
+    entities::{
+        collection_mints, collections, metadata_json_attributes,
+        sea_orm_active_enums::{Blockchain, CreationStatus},
+    },
+    objects::{CollectionTrait, TraitValue},
+};
+
+#[derive(FromQueryResult)]
+struct TraitValueCount {
+    collection_id: Uuid,
+    trait_type: String,
+    value: String,
+    count: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Loader {
+    pub db: Connection,
+}
+
+impl Loader {
+    #[must_use]
+    pub fn new(db: Connection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DataLoader<Uuid> for Loader {
+    type Error = FieldError;
+    type Value = Vec<CollectionTrait>;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        let mut select = collection_mints::Entity::find().join(
+            JoinType::InnerJoin,
+            collection_mints::Relation::Collections.def(),
+        );
+
+        // Polygon mints share the metadata json of their collection.
+        QueryTrait::query(&mut select).join(
+            JoinType::InnerJoin,
+            metadata_json_attributes::Entity,
+            Expr::col((
+                metadata_json_attributes::Entity,
+                metadata_json_attributes::Column::MetadataJsonId,
+            ))
+            .eq(Expr::case(
+                collections::Column::Blockchain.eq(Blockchain::Polygon),
+                Expr::col((collections::Entity, collections::Column::Id)),
+            )
+            .finally(Expr::col((
+                collection_mints::Entity,
+                collection_mints::Column::Id,
+            )))),
+        );
+
+        let counts = select
+            .filter(
+                collection_mints::Column::CollectionId
+                    .is_in(keys.iter().map(ToOwned::to_owned))
+                    .and(collection_mints::Column::CreationStatus.is_in([
+                        CreationStatus::Created,
+                        CreationStatus::Pending,
+                        CreationStatus::Queued,
+                    ]))
+                    .and(collection_mints::Column::RemovedAt.is_null()),
+            )
+            .select_only()
+            .column(collection_mints::Column::CollectionId)
+            .column(metadata_json_attributes::Column::TraitType)
+            .column(metadata_json_attributes::Column::Value)
+            .column_as(metadata_json_attributes::Column::Id.count(), "count")
+            .group_by(collection_mints::Column::CollectionId)
+            .group_by(metadata_json_attributes::Column::TraitType)
+            .group_by(metadata_json_attributes::Column::Value)
+            .into_model::<TraitValueCount>()
+            .all(self.db.get())
+            .await?;
+
+        let grouped = counts.into_iter().fold(
+            HashMap::<Uuid, BTreeMap<String, Vec<TraitValue>>>::new(),
+            |mut acc, count| {
+                acc.entry(count.collection_id)
+                    .or_default()
+                    .entry(count.trait_type)
+                    .or_default()
+                    .push(TraitValue {
+                        value: count.value,
+                        count: count.count,
+                    });
+
+                acc
+            },
+        );
+
+        Ok(grouped
+            .into_iter()
+            .map(|(collection_id, traits)| {
+                let traits = traits
+                    .into_iter()
+                    .map(|(trait_type, mut values)| {
+                        values.sort_by(|a, b| {
+                            b.count.cmp(&a.count).then_with(|| a.value.cmp(&b.value))
+                        });
+
+                        CollectionTrait {
+                            trait_type,
+                            count: values.iter().map(|value| value.count).sum(),
+                            values,
+                        }
+                    })
+                    .collect();
+
+                (collection_id, traits)
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use async_graphql::{dataloader::Loader as DataLoader, FieldError, Result};
+use poem::async_trait;
+use sea_orm::{prelude::*, QueryOrder};
+
+use crate::{
+    db::Connection,
+    entities::{drop_manifest_errors, drop_manifests},
+};
+
+#[derive(Debug, Clone)]
+pub struct Loader {
+    pub db: Connection,
+}
+
+impl Loader {
+    #[must_use]
+    pub fn new(db: Connection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DataLoader<Uuid> for Loader {
+    type Error = FieldError;
+    type Value = drop_manifests::Model;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        let manifests = drop_manifests::Entity::find()
+            .filter(drop_manifests::Column::Id.is_in(keys.iter().map(ToOwned::to_owned)))
+            .all(self.db.get())
+            .await?;
+
+        Ok(manifests
+            .into_iter()
+            .map(|manifest| (manifest.id, manifest))
+            .collect())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ErrorsLoader {
+    pub db: Connection,
+}
+
+impl ErrorsLoader {
+    #[must_use]
+    pub fn new(db: Connection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DataLoader<Uuid> for ErrorsLoader {
+    type Error = FieldError;
+    type Value = Vec<drop_manifest_errors::Model>;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        let errors = drop_manifest_errors::Entity::find()
+            .filter(
+                drop_manifest_errors::Column::ManifestId.is_in(keys.iter().map(ToOwned::to_owned)),
+            )
+            .order_by_asc(drop_manifest_errors::Column::Row)
+            .all(self.db.get())
+            .await?;
+
+        Ok(errors.into_iter().fold(HashMap::new(), |mut acc, error| {
+            acc.entry(error.manifest_id)
+                .or_insert_with(Vec::new)
+                .push(error);
+
+            acc
+        }))
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use async_graphql::{dataloader::Loader as DataLoader, FieldError, Result};
+use poem::async_trait;
+use sea_orm::prelude::*;
+
+use crate::{
+    db::Connection,
+    entities::{holder_snapshot_mints, holder_snapshots},
+    objects::SnapshotHolder,
+};
+
+#[derive(Debug, Clone)]
+pub struct Loader {
+    pub db: Connection,
+}
+
+impl Loader {
+    #[must_use]
+    pub fn new(db: Connection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DataLoader<Uuid> for Loader {
+    type Error = FieldError;
+    type Value = holder_snapshots::Model;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        let snapshots = holder_snapshots::Entity::find()
+            .filter(holder_snapshots::Column::Id.is_in(keys.iter().map(ToOwned::to_owned)))
+            .all(self.db.get())
+            .await?;
+
+        Ok(snapshots
+            .into_iter()
+            .map(|snapshot| (snapshot.id, snapshot))
+            .collect())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HoldersLoader {
+    pub db: Connection,
+}
+
+impl HoldersLoader {
+    #[must_use]
+    pub fn new(db: Connection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DataLoader<Uuid> for HoldersLoader {
+    type Error = FieldError;
+    type Value = Vec<SnapshotHolder>;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        let snapshot_mints = holder_snapshot_mints::Entity::find()
+            .filter(
+                holder_snapshot_mints::Column::SnapshotId.is_in(keys.iter().map(ToOwned::to_owned)),
+            )
+            .all(self.db.get())
+            .await?;
+
+        let owners = snapshot_mints
+            .into_iter()
+            .fold(HashMap::new(), |mut acc, snapshot_mint| {
+                acc.entry(snapshot_mint.snapshot_id)
+                    .or_insert_with(Vec::new)
+                    .push((snapshot_mint.collection_mint_id, snapshot_mint.owner));
+
+                acc
+            });
+
+        Ok(owners
+            .into_iter()
+            .map(|(snapshot_id, owners)| (snapshot_id, SnapshotHolder::group(owners)))
+            .collect())
+    }
+}
@@ -1,10 +1,22 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use async_graphql::{dataloader::Loader as DataLoader, FieldError, Result};
 use poem::async_trait;
-use sea_orm::{prelude::*, QuerySelect};
+use sea_orm::{prelude::*, sea_query::Expr, Order, QueryOrder, QuerySelect};
 
-use crate::{db::Connection, entities::collection_mints, objects::Holder};
+use crate::{
+    db::Connection,
+    entities::{collection_mints, sea_orm_active_enums::CreationStatus},
+    objects::{Holder, HolderSort},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HoldersKey {
+    pub collection_id: Uuid,
+    pub sort: HolderSort,
+    pub limit: u64,
+    pub offset: u64,
+}
 
 #[derive(Debug, Clone)]
 pub struct Loader {
@@ -19,33 +31,97 @@ impl Loader {
 }
 
 #[async_trait]
-impl DataLoader<Uuid> for Loader {
+impl DataLoader<HoldersKey> for Loader {
     type Error = FieldError;
     type Value = Vec<Holder>;
 
-    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
-        let holders = collection_mints::Entity::find()
+    async fn load(
+        &self,
+        keys: &[HoldersKey],
+    ) -> Result<HashMap<HoldersKey, Self::Value>, Self::Error> {
+        let conn = self.db.get();
+        let mut results = HashMap::new();
+
+        for key in keys {
+            let order = match key.sort {
+                HolderSort::OwnsDesc => Order::Desc,
+                HolderSort::OwnsAsc => Order::Asc,
+            };
+
+            let holders = collection_mints::Entity::find()
+                .filter(collection_mints::Column::CollectionId.eq(key.collection_id))
+                .filter(collection_mints::Column::Owner.is_not_null())
+                .filter(collection_mints::Column::RemovedAt.is_null())
+                .select_only()
+                .column(collection_mints::Column::CollectionId)
+                .column_as(collection_mints::Column::Owner, "address")
+                .column_as(collection_mints::Column::Id.count(), "owns")
+                .group_by(collection_mints::Column::Owner)
+                .group_by(collection_mints::Column::CollectionId)
+                .order_by(Expr::col(collection_mints::Column::Id).count(), order)
+                .order_by_asc(collection_mints::Column::Owner)
+                .offset(key.offset)
+                .limit(key.limit)
+                .into_model::<Holder>()
+                .all(conn)
+                .await?;
+
+            results.insert(*key, holders);
+        }
+
+        Ok(results)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MintsLoader {
+    pub db: Connection,
+}
+
+impl MintsLoader {
+    #[must_use]
+    pub fn new(db: Connection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DataLoader<(Uuid, String)> for MintsLoader {
+    type Error = FieldError;
+    type Value = Vec<Uuid>;
+
+    async fn load(
+        &self,
+        keys: &[(Uuid, String)],
+    ) -> Result<HashMap<(Uuid, String), Self::Value>, Self::Error> {
+        let keys = keys.iter().collect::<HashSet<_>>();
+
+        let collection_mints = collection_mints::Entity::find()
+            .filter(
+                collection_mints::Column::CollectionId
+                    .is_in(keys.iter().map(|(collection_id, _)| *collection_id)),
+            )
             .filter(
-                collection_mints::Column::CollectionId.is_in(keys.iter().map(ToOwned::to_owned)),
+                collection_mints::Column::Owner.is_in(keys.iter().map(|(_, owner)| owner.clone())),
             )
-            .filter(collection_mints::Column::Owner.is_not_null())
-            .select_only()
-            .column(collection_mints::Column::CollectionId)
-            .column_as(collection_mints::Column::Owner, "address")
-            .column_as(collection_mints::Column::Id.count(), "owns")
-            .group_by(collection_mints::Column::Owner)
-            .group_by(collection_mints::Column::CollectionId)
-            .into_model::<Holder>()
+            .filter(collection_mints::Column::CreationStatus.ne(CreationStatus::Queued))
             .all(self.db.get())
             .await?;
 
-        Ok(holders.into_iter().fold(HashMap::new(), |mut acc, holder| {
-            acc.entry(holder.collection_id).or_insert_with(Vec::new);
+        Ok(collection_mints
+            .into_iter()
+            .fold(HashMap::new(), |mut acc, collection_mint| {
+                if let Some(owner) = collection_mint.owner {
+                    let key = (collection_mint.collection_id, owner);
 
-            acc.entry(holder.collection_id)
-                .and_modify(|holders| holders.push(holder));
+                    if keys.contains(&key) {
+                        acc.entry(key)
+                            .or_insert_with(Vec::new)
+                            .push(collection_mint.id);
+                    }
+                }
 
-            acc
-        }))
+                acc
+            }))
     }
 }
@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+
+use async_graphql::{dataloader::Loader, FieldError, Result};
+use poem::async_trait;
+use sea_orm::prelude::*;
+
+use crate::{db::Connection, entities::imports};
+
+#[derive(Debug, Clone)]
+pub struct ImportLoader {
+    pub db: Connection,
+}
+
+impl ImportLoader {
+    #[must_use]
+    pub fn new(db: Connection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl Loader<Uuid> for ImportLoader {
+    type Error = FieldError;
+    type Value = imports::Model;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        let imports = imports::Entity::find()
+            .filter(imports::Column::Id.is_in(keys.iter().map(ToOwned::to_owned)))
+            .all(self.db.get())
+            .await?;
+
+        Ok(imports
+            .into_iter()
+            .map(|import| (import.id, import))
+            .collect())
+    }
+}
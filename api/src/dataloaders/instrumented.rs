@@ -0,0 +1,42 @@
+use std::{collections::HashMap, hash::Hash};
+
+use async_graphql::dataloader::Loader;
+use poem::async_trait;
+
+use crate::metrics::Metrics;
+
+/// Wraps a loader to record the number of keys of every batch it loads
+#[derive(Debug, Clone)]
+pub struct Instrumented<L> {
+    loader: L,
+    metrics: Metrics,
+}
+
+impl<L> Instrumented<L> {
+    #[must_use]
+    pub fn new(loader: L, metrics: Metrics) -> Self {
+        Self { loader, metrics }
+    }
+
+    /// The name the batches of the wrapped loader are recorded under
+    fn name() -> &'static str {
+        std::any::type_name::<L>().trim_start_matches("holaplex_hub_nfts::dataloaders::")
+    }
+}
+
+#[async_trait]
+impl<K, L> Loader<K> for Instrumented<L>
+where
+    K: Send + Sync + Hash + Eq + Clone + 'static,
+    L: Loader<K>,
+{
+    type Error = L::Error;
+    type Value = L::Value;
+
+    async fn load(&self, keys: &[K]) -> Result<HashMap<K, Self::Value>, Self::Error> {
+        self.metrics
+            .record_dataloader_batch(Self::name(), keys.len());
+
+        self.loader.load(keys).await
+    }
+}
@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use async_graphql::{dataloader::Loader as DataLoader, FieldError, Result};
+use poem::async_trait;
+use sea_orm::{prelude::*, QueryOrder};
+
+use crate::{db::Connection, entities::metadata_json_versions};
+
+#[derive(Debug, Clone)]
+pub struct Loader {
+    pub db: Connection,
+}
+
+impl Loader {
+    #[must_use]
+    pub fn new(db: Connection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DataLoader<Uuid> for Loader {
+    type Error = FieldError;
+    type Value = Vec<metadata_json_versions::Model>;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        let versions = metadata_json_versions::Entity::find()
+            .filter(
+                metadata_json_versions::Column::CollectionMintId
+                    .is_in(keys.iter().map(ToOwned::to_owned)),
+            )
+            .order_by_desc(metadata_json_versions::Column::Version)
+            .all(self.db.get())
+            .await?;
+
+        Ok(versions
+            .into_iter()
+            .fold(HashMap::new(), |mut acc, version| {
+                acc.entry(version.collection_mint_id)
+                    .or_insert_with(Vec::new)
+                    .push(version);
+
+                acc
+            }))
+    }
+}
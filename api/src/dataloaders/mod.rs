@@ -1,42 +1,78 @@
 mod collection;
+mod collection_analytics;
 mod collection_drop;
+mod collection_exports;
+mod collection_mint_rarities;
+mod collection_mint_updates;
 mod collection_mints;
+mod collection_traits;
 mod creators;
 mod drop;
+mod drop_manifests;
 mod drops;
+mod holder_snapshots;
 mod holders;
+mod imports;
+mod instrumented;
 mod metadata_json;
+mod metadata_json_versions;
 mod mint_creators;
 mod mint_histories;
 mod nft_transfers;
+mod owned_mints;
 mod project_collection;
 mod project_collections;
+mod project_usage;
 mod switch_collection_histories;
+mod transfers;
 mod update_histories;
+mod webhook_subscriptions;
 
 pub use collection::{
     Loader as CollectionLoader, SupplyLoader as CollectionSupplyLoader,
     TotalMintsLoader as CollectionTotalMintsLoader,
 };
+pub use collection_analytics::{AnalyticsKey, Loader as CollectionAnalyticsLoader};
 pub use collection_drop::Loader as CollectionDropLoader;
+pub use collection_exports::Loader as CollectionExportLoader;
+pub use collection_mint_rarities::Loader as CollectionMintRarityLoader;
+pub use collection_mint_updates::{
+    ErrorsLoader as CollectionMintUpdateErrorsLoader, Loader as CollectionMintUpdateLoader,
+};
 pub use collection_mints::{
-    CollectionMintLoader, Loader as CollectionMintsLoader,
-    OwnerLoader as CollectionMintsOwnerLoader, QueuedMintsLoader,
+    CollectionMintLoader, Loader as CollectionMintsLoader, QueuedMintsLoader,
 };
+pub use collection_traits::Loader as CollectionTraitsLoader;
 pub use creators::Loader as CreatorsLoader;
 pub use drop::DropLoader;
+pub use drop_manifests::{ErrorsLoader as DropManifestErrorsLoader, Loader as DropManifestLoader};
 pub use drops::ProjectLoader as ProjectDropsLoader;
-pub use holders::Loader as HoldersLoader;
+pub use holder_snapshots::{
+    HoldersLoader as HolderSnapshotHoldersLoader, Loader as HolderSnapshotLoader,
+};
+pub use holders::{HoldersKey, Loader as HoldersLoader, MintsLoader as HolderMintsLoader};
+pub use imports::ImportLoader;
+pub use instrumented::Instrumented;
 pub use metadata_json::{
     AttributesLoader as MetadataJsonAttributesLoader, Loader as MetadataJsonLoader,
 };
+pub use metadata_json_versions::Loader as MetadataJsonVersionsLoader;
 pub use mint_creators::Loader as MintCreatorsLoader;
 pub use mint_histories::{
     CollectionMintHistoriesLoader, CollectionMintMintHistoryLoader, DropMintHistoryLoader,
     MinterLoader as MinterMintHistoryLoader,
 };
 pub use nft_transfers::CollectionMintTransfersLoader;
+pub use owned_mints::{
+    CollectionsLoader as OwnedCollectionsLoader, MintsLoader as OwnedMintsLoader, OwnedMintsKey,
+};
 pub use project_collection::ProjectCollectionLoader;
 pub use project_collections::ProjectCollectionsLoader;
+pub use project_usage::{Loader as ProjectUsageLoader, UsageKey};
 pub use switch_collection_histories::SwitchCollectionHistoryLoader;
+pub use transfers::{
+    BatchLoader as TransferBatchLoader, Loader as TransferLoader,
+    PendingLoader as PendingTransfersLoader,
+};
 pub use update_histories::UpdateMintHistoryLoader;
+pub use webhook_subscriptions::Loader as WebhookSubscriptionsLoader;
@@ -0,0 +1,171 @@
+use std::{borrow::Cow, collections::HashMap};
+
+use async_graphql::{dataloader::Loader as DataLoader, FieldError, Result};
+use poem::async_trait;
+use sea_orm::{prelude::*, sea_query::Expr, JoinType, Order, QueryOrder, QuerySelect};
+
+use crate::{
+    db::Connection,
+    entities::{collection_mints, collections},
+    objects::{CollectionMint, OwnedCollection, OwnedMintsFilter},
+};
+
+/// The number of mints or collections returned when `limit` is not provided.
+const DEFAULT_OWNED_LIMIT: u64 = 25;
+/// The maximum number of mints or collections returned by a single page.
+const MAX_OWNED_LIMIT: u64 = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OwnedMintsKey {
+    pub owners: Vec<String>,
+    pub filter: OwnedMintsFilter,
+    pub limit: u64,
+    pub offset: u64,
+}
+
+impl OwnedMintsKey {
+    /// A page of the mints owned by the addresses. `limit` defaults to 25 and is capped at 100.
+    #[must_use]
+    pub fn new(
+        owners: Vec<String>,
+        filter: OwnedMintsFilter,
+        limit: Option<u64>,
+        offset: u64,
+    ) -> Self {
+        Self {
+            owners,
+            filter,
+            limit: limit.unwrap_or(DEFAULT_OWNED_LIMIT).min(MAX_OWNED_LIMIT),
+            offset,
+        }
+    }
+
+    /// The mints owned by any of the addresses of the key that match its filter.
+    fn select(&self) -> Select<collection_mints::Entity> {
+        let OwnedMintsFilter {
+            project,
+            collection,
+            blockchain,
+            compressed,
+            status,
+        } = self.filter;
+
+        let mut select =
+            collection_mints::Entity::find()
+                .join(
+                    JoinType::InnerJoin,
+                    collection_mints::Relation::Collections.def(),
+                )
+                .filter(collection_mints::Column::Owner.is_in(
+                    hub_core::util::downcase_evm_addresses(&self.owners).map(Cow::into_owned),
+                ));
+
+        if let Some(project) = project {
+            select = select.filter(collections::Column::ProjectId.eq(project));
+        }
+
+        if let Some(collection) = collection {
+            select = select.filter(collection_mints::Column::CollectionId.eq(collection));
+        }
+
+        if let Some(blockchain) = blockchain {
+            select = select.filter(collections::Column::Blockchain.eq(blockchain));
+        }
+
+        if let Some(compressed) = compressed {
+            select = select.filter(collection_mints::Column::Compressed.eq(compressed));
+        }
+
+        if let Some(status) = status {
+            select = select.filter(collection_mints::Column::CreationStatus.eq(status));
+        }
+
+        select.offset(self.offset).limit(self.limit)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MintsLoader {
+    pub db: Connection,
+}
+
+impl MintsLoader {
+    #[must_use]
+    pub fn new(db: Connection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DataLoader<OwnedMintsKey> for MintsLoader {
+    type Error = FieldError;
+    type Value = Vec<CollectionMint>;
+
+    async fn load(
+        &self,
+        keys: &[OwnedMintsKey],
+    ) -> Result<HashMap<OwnedMintsKey, Self::Value>, Self::Error> {
+        let conn = self.db.get();
+        let mut results = HashMap::new();
+
+        for key in keys {
+            let collection_mints = key
+                .select()
+                .order_by_desc(collection_mints::Column::CreatedAt)
+                .order_by_desc(collection_mints::Column::Id)
+                .all(conn)
+                .await?;
+
+            results.insert(
+                key.clone(),
+                collection_mints.into_iter().map(Into::into).collect(),
+            );
+        }
+
+        Ok(results)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CollectionsLoader {
+    pub db: Connection,
+}
+
+impl CollectionsLoader {
+    #[must_use]
+    pub fn new(db: Connection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DataLoader<OwnedMintsKey> for CollectionsLoader {
+    type Error = FieldError;
+    type Value = Vec<OwnedCollection>;
+
+    async fn load(
+        &self,
+        keys: &[OwnedMintsKey],
+    ) -> Result<HashMap<OwnedMintsKey, Self::Value>, Self::Error> {
+        let conn = self.db.get();
+        let mut results = HashMap::new();
+
+        for key in keys {
+            let collections = key
+                .select()
+                .select_only()
+                .column(collection_mints::Column::CollectionId)
+                .column_as(collection_mints::Column::Id.count(), "owns")
+                .group_by(collection_mints::Column::CollectionId)
+                .order_by(Expr::col(collection_mints::Column::Id).count(), Order::Desc)
+                .order_by_asc(collection_mints::Column::CollectionId)
+                .into_model::<OwnedCollection>()
+                .all(conn)
+                .await?;
+
+            results.insert(key.clone(), collections);
+        }
+
+        Ok(results)
+    }
+}
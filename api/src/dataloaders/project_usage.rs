@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use async_graphql::{dataloader::Loader as DataLoader, FieldError, Result};
+use hub_core::chrono::{DateTime, Utc};
+use poem::async_trait;
+use sea_orm::{prelude::*, DbBackend, FromQueryResult, Statement, Value};
+
+use crate::{db::Connection, objects::ProjectUsage};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UsageKey {
+    pub project_id: Uuid,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+}
+
+#[derive(FromQueryResult)]
+struct UsageCounts {
+    mints: i64,
+    compressed_mints: i64,
+    transfers: i64,
+    updates: i64,
+    collection_switches: i64,
+    retries: i64,
+}
+
+/// Only successful operations are counted. `update_histories` and `switch_collection_histories` store `created_at` without a time zone in UTC.
+/// Transfers charged before they were recorded in `transfers` cannot be attributed to a project and are skipped.
+const USAGE_SQL: &str = "SELECT
+    (SELECT COUNT(*) FILTER (WHERE collection_mints.compressed IS NOT TRUE)
+        FROM mint_histories
+        INNER JOIN collections ON collections.id = mint_histories.collection_id
+        INNER JOIN collection_mints ON collection_mints.id = mint_histories.mint_id
+        WHERE collections.project_id = $1 AND mint_histories.status = 'created'
+            AND mint_histories.created_at >= $2 AND mint_histories.created_at < $3) AS mints,
+    (SELECT COUNT(*) FILTER (WHERE collection_mints.compressed IS TRUE)
+        FROM mint_histories
+        INNER JOIN collections ON collections.id = mint_histories.collection_id
+        INNER JOIN collection_mints ON collection_mints.id = mint_histories.mint_id
+        WHERE collections.project_id = $1 AND mint_histories.status = 'created'
+            AND mint_histories.created_at >= $2 AND mint_histories.created_at < $3) AS compressed_mints,
+    (SELECT COUNT(*)
+        FROM transfers
+        INNER JOIN collection_mints ON collection_mints.id = transfers.collection_mint_id
+        INNER JOIN collections ON collections.id = collection_mints.collection_id
+        WHERE collections.project_id = $1 AND transfers.status = 'created'
+            AND transfers.created_at >= $2 AND transfers.created_at < $3) AS transfers,
+    (SELECT COUNT(*)
+        FROM update_histories
+        INNER JOIN collection_mints ON collection_mints.id = update_histories.mint_id
+        INNER JOIN collections ON collections.id = collection_mints.collection_id
+        WHERE collections.project_id = $1 AND update_histories.status = 'created'
+            AND update_histories.created_at >= ($2 AT TIME ZONE 'UTC')
+            AND update_histories.created_at < ($3 AT TIME ZONE 'UTC')) AS updates,
+    (SELECT COUNT(*)
+        FROM switch_collection_histories
+        INNER JOIN collections ON collections.id = switch_collection_histories.collection_id
+        WHERE collections.project_id = $1 AND switch_collection_histories.status = 'created'
+            AND switch_collection_histories.created_at >= ($2 AT TIME ZONE 'UTC')
+            AND switch_collection_histories.created_at < ($3 AT TIME ZONE 'UTC')) AS collection_switches,
+    (SELECT COUNT(*)
+        FROM retry_histories
+        WHERE project_id = $1 AND created_at >= $2 AND created_at < $3) AS retries";
+
+#[derive(Debug, Clone)]
+pub struct Loader {
+    pub db: Connection,
+}
+
+impl Loader {
+    #[must_use]
+    pub fn new(db: Connection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DataLoader<UsageKey> for Loader {
+    type Error = FieldError;
+    type Value = ProjectUsage;
+
+    async fn load(&self, keys: &[UsageKey]) -> Result<HashMap<UsageKey, Self::Value>, Self::Error> {
+        let conn = self.db.get();
+        let mut results = HashMap::new();
+
+        for key in keys {
+            let UsageKey {
+                project_id,
+                from,
+                to,
+            } = *key;
+
+            let counts = UsageCounts::find_by_statement(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                USAGE_SQL,
+                [Value::from(project_id), from.into(), to.into()],
+            ))
+            .one(conn)
+            .await?
+            .unwrap_or(UsageCounts {
+                mints: 0,
+                compressed_mints: 0,
+                transfers: 0,
+                updates: 0,
+                collection_switches: 0,
+                retries: 0,
+            });
+
+            results.insert(*key, ProjectUsage {
+                from,
+                to,
+                mints: counts.mints,
+                compressed_mints: counts.compressed_mints,
+                transfers: counts.transfers,
+                updates: counts.updates,
+                collection_switches: counts.collection_switches,
+                retries: counts.retries,
+            });
+        }
+
+        Ok(results)
+    }
+}
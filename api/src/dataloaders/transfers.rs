@@ -0,0 +1,152 @@
+use std::collections::{HashMap, HashSet};
+
+use async_graphql::{dataloader::Loader as DataLoader, FieldError, Result};
+use poem::async_trait;
+use sea_orm::{prelude::*, Order, QueryOrder};
+
+use crate::{
+    db::Connection,
+    entities::{nft_transfers, sea_orm_active_enums::CreationStatus, transfers},
+    objects::TransferBatch,
+};
+
+#[derive(Debug, Clone)]
+pub struct Loader {
+    pub db: Connection,
+}
+
+impl Loader {
+    #[must_use]
+    pub fn new(db: Connection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DataLoader<Uuid> for Loader {
+    type Error = FieldError;
+    type Value = transfers::Model;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        let transfers = transfers::Entity::find()
+            .filter(transfers::Column::Id.is_in(keys.iter().map(ToOwned::to_owned)))
+            .all(self.db.get())
+            .await?;
+
+        Ok(transfers
+            .into_iter()
+            .map(|transfer| (transfer.id, transfer))
+            .collect())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PendingLoader {
+    pub db: Connection,
+}
+
+impl PendingLoader {
+    #[must_use]
+    pub fn new(db: Connection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DataLoader<Uuid> for PendingLoader {
+    type Error = FieldError;
+    type Value = Vec<transfers::Model>;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        let transfers = transfers::Entity::find()
+            .filter(
+                transfers::Column::CollectionMintId
+                    .is_in(keys.iter().map(ToOwned::to_owned))
+                    .and(transfers::Column::Status.eq(CreationStatus::Pending)),
+            )
+            .order_by(transfers::Column::CreatedAt, Order::Desc)
+            .all(self.db.get())
+            .await?;
+
+        Ok(transfers
+            .into_iter()
+            .fold(HashMap::new(), |mut acc, transfer| {
+                acc.entry(transfer.collection_mint_id)
+                    .or_insert_with(Vec::new);
+
+                acc.entry(transfer.collection_mint_id)
+                    .and_modify(|transfers| transfers.push(transfer));
+
+                acc
+            }))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BatchLoader {
+    pub db: Connection,
+}
+
+impl BatchLoader {
+    #[must_use]
+    pub fn new(db: Connection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DataLoader<Uuid> for BatchLoader {
+    type Error = FieldError;
+    type Value = TransferBatch;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        let conn = self.db.get();
+
+        let transfers = transfers::Entity::find()
+            .filter(transfers::Column::BatchId.is_in(keys.iter().map(ToOwned::to_owned)))
+            .all(conn)
+            .await?;
+
+        let mint_ids = transfers
+            .iter()
+            .map(|transfer| transfer.collection_mint_id)
+            .collect::<HashSet<_>>();
+
+        let nft_transfers = nft_transfers::Entity::find()
+            .filter(nft_transfers::Column::CollectionMintId.is_in(mint_ids))
+            .all(conn)
+            .await?;
+
+        Ok(transfers
+            .into_iter()
+            .fold(HashMap::new(), |mut acc, transfer| {
+                let Some(batch_id) = transfer.batch_id else {
+                    return acc;
+                };
+
+                let batch = acc.entry(batch_id).or_insert_with(|| TransferBatch {
+                    id: batch_id,
+                    ..Default::default()
+                });
+
+                batch.total += 1;
+
+                match transfer.status {
+                    CreationStatus::Created => batch.completed += 1,
+                    CreationStatus::Failed => batch.failed += 1,
+                    _ => batch.pending += 1,
+                }
+
+                let transferred = nft_transfers.iter().any(|nft_transfer| {
+                    nft_transfer.collection_mint_id == transfer.collection_mint_id
+                        && nft_transfer.created_at >= transfer.created_at
+                });
+
+                if transferred {
+                    batch.transferred += 1;
+                }
+
+                acc
+            }))
+    }
+}
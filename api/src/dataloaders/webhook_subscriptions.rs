@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use async_graphql::{dataloader::Loader as DataLoader, FieldError, Result};
+use poem::async_trait;
+use sea_orm::{prelude::*, QueryOrder};
+
+use crate::{
+    db::Connection,
+    entities::{sea_orm_active_enums::WebhookEventType, webhook_subscriptions},
+};
+
+#[derive(Debug, Clone)]
+pub struct Loader {
+    pub db: Connection,
+}
+
+impl Loader {
+    #[must_use]
+    pub fn new(db: Connection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DataLoader<Uuid> for Loader {
+    type Error = FieldError;
+    type Value = Vec<WebhookEventType>;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        let subscriptions = webhook_subscriptions::Entity::find()
+            .filter(
+                webhook_subscriptions::Column::EndpointId.is_in(keys.iter().map(ToOwned::to_owned)),
+            )
+            .order_by_asc(webhook_subscriptions::Column::EventType)
+            .all(self.db.get())
+            .await?;
+
+        Ok(subscriptions
+            .into_iter()
+            .fold(HashMap::new(), |mut acc, subscription| {
+                acc.entry(subscription.endpoint_id)
+                    .or_insert_with(Vec::new)
+                    .push(subscription.event_type);
+
+                acc
+            }))
+    }
+}
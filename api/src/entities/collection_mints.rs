@@ -25,6 +25,8 @@ pub struct Model {
     pub credits_deduction_id: Option<Uuid>,
     #[sea_orm(nullable)]
     pub compressed: Option<bool>,
+    pub import_id: Option<Uuid>,
+    pub removed_at: Option<DateTimeWithTimeZone>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -3,8 +3,10 @@
 use async_graphql::SimpleObject;
 use sea_orm::entity::prelude::*;
 
+use super::sea_orm_active_enums::AttributeValueType;
+
 /// An attribute of the NFT.
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, SimpleObject)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, SimpleObject)]
 #[sea_orm(table_name = "metadata_json_attributes")]
 #[graphql(concrete(name = "MetadataJsonAttribute", params()))]
 pub struct Model {
@@ -17,6 +19,21 @@ pub struct Model {
     /// The value of the attribute.
     #[sea_orm(column_type = "Text")]
     pub value: String,
+    /// The type of the value.
+    pub value_type: AttributeValueType,
+    /// The value of a `NUMBER` attribute.
+    #[sea_orm(column_type = "Double", nullable)]
+    pub number_value: Option<f64>,
+    /// The value of a `BOOLEAN` attribute.
+    pub boolean_value: Option<bool>,
+    /// The value of a `DATE` attribute.
+    pub date_value: Option<DateTimeWithTimeZone>,
+    /// How marketplaces display the attribute. ERC-721 and ERC-1155 only.
+    #[sea_orm(column_type = "Text", nullable)]
+    pub display_type: Option<String>,
+    /// The maximum of a `NUMBER` attribute. ERC-721 and ERC-1155 only.
+    #[sea_orm(column_type = "Double", nullable)]
+    pub max_value: Option<f64>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
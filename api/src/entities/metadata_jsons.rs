@@ -2,6 +2,7 @@
 
 use async_graphql::Result;
 use sea_orm::entity::prelude::*;
+use serde_json::Value as Json;
 
 /// The collection's associated metadata JSON.
 /// ## References
@@ -30,6 +31,22 @@ pub struct Model {
     /// An optional URL where viewers can find more information on the NFT, such as the collection's homepage or Twitter page.
     #[sea_orm(nullable)]
     pub external_url: Option<String>,
+    /// The SHA-256 of the canonical JSON the metadata json was uploaded as.
+    #[sea_orm(column_type = "Text", nullable)]
+    pub content_hash: Option<String>,
+    /// The reason the media referenced by the metadata json could not be ingested.
+    #[sea_orm(column_type = "Text", nullable)]
+    pub ingestion_error: Option<String>,
+    /// The background color of the NFT as a six-character hexadecimal without a pre-pended `#`. ERC-721 and ERC-1155 only.
+    #[sea_orm(column_type = "Text", nullable)]
+    pub background_color: Option<String>,
+    /// A URL to a YouTube video of the NFT. ERC-721 and ERC-1155 only.
+    #[sea_orm(column_type = "Text", nullable)]
+    pub youtube_url: Option<String>,
+    /// The complete metadata json input as it was last saved, including the fields that have no column.
+    /// Not set for metadata jsons imported from the blockchain or saved before it was recorded.
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub document: Option<Json>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
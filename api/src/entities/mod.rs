@@ -4,19 +4,37 @@
 pub mod prelude;
 
 pub mod collection_creators;
+pub mod collection_exports;
+pub mod collection_mint_rarities;
+pub mod collection_mint_update_errors;
+pub mod collection_mint_updates;
 pub mod collection_mints;
 pub mod collections;
 pub mod customer_wallets;
+pub mod drop_manifest_errors;
+pub mod drop_manifests;
 pub mod drops;
+pub mod holder_snapshot_mints;
+pub mod holder_snapshots;
+pub mod import_mint_failures;
+pub mod imports;
 pub mod job_trackings;
+pub mod media_assets;
 pub mod metadata_json_attributes;
 pub mod metadata_json_files;
+pub mod metadata_json_uploads;
+pub mod metadata_json_versions;
 pub mod metadata_jsons;
 pub mod mint_creators;
 pub mod mint_histories;
 pub mod nft_transfers;
 pub mod project_wallets;
+pub mod retry_histories;
 pub mod sea_orm_active_enums;
 pub mod switch_collection_histories;
 pub mod transfer_charges;
+pub mod transfers;
 pub mod update_histories;
+pub mod webhook_deliveries;
+pub mod webhook_endpoints;
+pub mod webhook_subscriptions;
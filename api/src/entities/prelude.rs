@@ -1,12 +1,28 @@
 //! `SeaORM` Entity. Generated by sea-orm-codegen 0.11.0
 
 pub use super::{
-    collection_creators::Entity as CollectionCreators, collection_mints::Entity as CollectionMints,
-    collections::Entity as Collections, customer_wallets::Entity as CustomerWallets,
-    drops::Entity as Drops, metadata_json_attributes::Entity as MetadataJsonAttributes,
-    metadata_json_files::Entity as MetadataJsonFiles, metadata_jsons::Entity as MetadataJsons,
-    mint_creators::Entity as MintCreators, mint_histories::Entity as MintHistory,
-    nft_transfers::Entity as NftTransfers, project_wallets::Entity as ProjectWallets,
+    collection_creators::Entity as CollectionCreators,
+    collection_exports::Entity as CollectionExports,
+    collection_mint_rarities::Entity as CollectionMintRarities,
+    collection_mint_update_errors::Entity as CollectionMintUpdateErrors,
+    collection_mint_updates::Entity as CollectionMintUpdates,
+    collection_mints::Entity as CollectionMints, collections::Entity as Collections,
+    customer_wallets::Entity as CustomerWallets,
+    drop_manifest_errors::Entity as DropManifestErrors, drop_manifests::Entity as DropManifests,
+    drops::Entity as Drops, holder_snapshot_mints::Entity as HolderSnapshotMints,
+    holder_snapshots::Entity as HolderSnapshots,
+    import_mint_failures::Entity as ImportMintFailures, imports::Entity as Imports,
+    media_assets::Entity as MediaAssets,
+    metadata_json_attributes::Entity as MetadataJsonAttributes,
+    metadata_json_files::Entity as MetadataJsonFiles,
+    metadata_json_uploads::Entity as MetadataJsonUploads,
+    metadata_json_versions::Entity as MetadataJsonVersions,
+    metadata_jsons::Entity as MetadataJsons, mint_creators::Entity as MintCreators,
+    mint_histories::Entity as MintHistory, nft_transfers::Entity as NftTransfers,
+    project_wallets::Entity as ProjectWallets, retry_histories::Entity as RetryHistories,
     switch_collection_histories::Entity as SwitchCollectionHistories,
-    transfer_charges::Entity as TransferCharges, update_histories::Entity as UpdateHistories,
+    transfer_charges::Entity as TransferCharges, transfers::Entity as Transfers,
+    update_histories::Entity as UpdateHistories, webhook_deliveries::Entity as WebhookDeliveries,
+    webhook_endpoints::Entity as WebhookEndpoints,
+    webhook_subscriptions::Entity as WebhookSubscriptions,
 };
@@ -20,3 +20,19 @@ pub enum Relation {}
 impl ActiveModelBehavior for ActiveModel {
     hub_core::before_save_evm_addrs!(wallet_address);
 }
+
+impl Entity {
+    pub fn find_by_project_and_address(project_id: Uuid, address: String) -> Select<Self> {
+        let address = if address.starts_with("0x") {
+            address.to_lowercase()
+        } else {
+            address
+        };
+
+        Self::find().filter(
+            Column::ProjectId
+                .eq(project_id)
+                .and(Column::WalletAddress.eq(address)),
+        )
+    }
+}
@@ -6,7 +6,17 @@ use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
 #[derive(
-    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Enum, Copy, Serialize, Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    EnumIter,
+    DeriveActiveEnum,
+    Enum,
+    Copy,
+    Serialize,
+    Deserialize,
 )]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "blockchain")]
 pub enum Blockchain {
@@ -28,7 +38,7 @@ impl From<Blockchain> for credits::Blockchain {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Enum, Copy)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EnumIter, DeriveActiveEnum, Enum, Copy)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "creation_status")]
 pub enum CreationStatus {
     #[sea_orm(string_value = "blocked")]
@@ -68,3 +78,105 @@ pub enum DropType {
     #[sea_orm(string_value = "open")]
     Open,
 }
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Enum, Copy, Serialize, Deserialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "export_format")]
+pub enum ExportFormat {
+    /// A header row followed by one row per mint. Attributes are serialized as a JSON object.
+    #[sea_orm(string_value = "csv")]
+    Csv,
+    /// An array of mint objects.
+    #[sea_orm(string_value = "json")]
+    Json,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Enum,
+    Copy,
+    Serialize,
+    Deserialize,
+    Default,
+)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "attribute_value_type"
+)]
+pub enum AttributeValueType {
+    /// The value is text.
+    #[default]
+    #[sea_orm(string_value = "string")]
+    String,
+    /// The value is an integer or decimal number.
+    #[sea_orm(string_value = "number")]
+    Number,
+    /// The value is `true` or `false`.
+    #[sea_orm(string_value = "boolean")]
+    Boolean,
+    /// The value is a unix timestamp in seconds or an RFC 3339 date.
+    #[sea_orm(string_value = "date")]
+    Date,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    EnumIter,
+    DeriveActiveEnum,
+    Enum,
+    Copy,
+    Serialize,
+    Deserialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "webhook_event_type")]
+pub enum WebhookEventType {
+    /// A collection was created on chain.
+    #[sea_orm(string_value = "collection_created")]
+    CollectionCreated,
+    /// A drop was created on chain.
+    #[sea_orm(string_value = "drop_created")]
+    DropCreated,
+    /// A mint to a drop or collection was created on chain.
+    #[sea_orm(string_value = "mint_created")]
+    MintCreated,
+    /// A mint to a drop or collection failed.
+    #[sea_orm(string_value = "mint_failed")]
+    MintFailed,
+    /// A transfer of an NFT completed.
+    #[sea_orm(string_value = "transfer_completed")]
+    TransferCompleted,
+    /// An update of an NFT completed.
+    #[sea_orm(string_value = "update_completed")]
+    UpdateCompleted,
+}
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Enum, Copy, Serialize, Deserialize,
+)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "webhook_delivery_status"
+)]
+pub enum WebhookDeliveryStatus {
+    /// The delivery has not been accepted by the endpoint yet and will be retried.
+    #[sea_orm(string_value = "pending")]
+    Pending,
+    /// The endpoint responded with a 2xx status.
+    #[sea_orm(string_value = "delivered")]
+    Delivered,
+    /// Every attempt failed or the endpoint was disabled.
+    #[sea_orm(string_value = "failed")]
+    Failed,
+}
@@ -17,6 +17,8 @@ pub struct Model {
     pub credit_deduction_id: Uuid,
     pub created_by: Uuid,
     pub created_at: DateTime,
+    /// The bulk update of the collection that created the update, if any.
+    pub collection_mint_update_id: Option<Uuid>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -1,3 +1,5 @@
+use std::time::Instant;
+
 use hub_core::{
     chrono::{NaiveDateTime, TimeZone, Utc},
     credits::{CreditsClient, TransactionId},
@@ -8,25 +10,28 @@ use hub_core::{
     uuid::{self, Uuid},
 };
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, EntityTrait, JoinType, QueryFilter, QuerySelect, RelationTrait,
-    Set, TransactionTrait,
+    prelude::DateTimeWithTimeZone,
+    sea_query::{Expr, OnConflict},
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, JoinType, QueryFilter,
+    QuerySelect, RelationTrait, Set, TransactionTrait,
 };
 
 use crate::{
     db::Connection,
     entities::{
         collection_creators, collection_mints, collections, customer_wallets, drops,
-        metadata_json_attributes, metadata_json_files, metadata_jsons, mint_creators,
-        mint_histories, nft_transfers,
+        import_mint_failures, imports, metadata_json_attributes, metadata_json_files,
+        metadata_jsons, mint_creators, mint_histories, nft_transfers,
         prelude::{
-            CollectionMints, Collections, Drops, MintHistory, SwitchCollectionHistories,
-            UpdateHistories,
+            CollectionMints, Collections, Drops, ImportMintFailures, Imports, MintHistory,
+            SwitchCollectionHistories, Transfers, UpdateHistories,
         },
         project_wallets,
-        sea_orm_active_enums::{Blockchain, CreationStatus},
-        switch_collection_histories, transfer_charges, update_histories,
+        sea_orm_active_enums::{Blockchain, CreationStatus, WebhookEventType},
+        switch_collection_histories, transfer_charges, transfers, update_histories,
     },
-    metrics::Metrics,
+    metrics::{blockchain_label, Metrics},
+    objects,
     proto::{
         nft_events::Event as NftEvent,
         polygon_nft_events::Event as PolygonNftEvents,
@@ -40,6 +45,7 @@ use crate::{
         NftEventKey, NftEvents, SolanaCollectionPayload, SolanaCompletedMintTransaction,
         SolanaCompletedTransferTransaction, SolanaMintPayload, SolanaNftEventKey, TreasuryEventKey,
     },
+    webhooks::Webhooks,
     Actions, Services,
 };
 
@@ -69,6 +75,8 @@ pub enum ProcessorErrorKind {
     DbMissingUpdateHistory,
     #[error("No associated switch collection history found in database")]
     DbMissingSwitchCollectionHistory,
+    #[error("No associated import found in database")]
+    DbMissingImport,
 
     #[error("Database record contains no deduction ID")]
     RecordMissingDeductionId,
@@ -115,6 +123,7 @@ pub struct Processor {
     pub credits: CreditsClient<Actions>,
     pub producer: Producer<NftEvents>,
     pub metrics: Metrics,
+    pub webhooks: Webhooks,
 }
 
 #[derive(Clone)]
@@ -154,22 +163,59 @@ impl Processor {
         credits: CreditsClient<Actions>,
         producer: Producer<NftEvents>,
         metrics: Metrics,
+        webhooks: Webhooks,
     ) -> Self {
         Self {
             db,
             credits,
             producer,
             metrics,
+            webhooks,
+        }
+    }
+
+    /// Sends a webhook for the event to the endpoints of the project subscribed to it.
+    /// The event has already been handled, so failing to record the webhook is logged rather than failing the message.
+    async fn emit_webhook(
+        &self,
+        project_id: Uuid,
+        event_type: WebhookEventType,
+        data: serde_json::Value,
+    ) {
+        if let Err(e) = self
+            .webhooks
+            .emit(self.db.get(), project_id, event_type, data)
+            .await
+        {
+            error!("failed to emit {event_type:?} webhook for project {project_id}: {e:?}");
         }
     }
 
-    #[allow(clippy::too_many_lines)]
     /// Processes incoming messages related to different services like Treasury and Solana.
-    /// Routes each message to the corresponding handler based on the type of service and the specific event.
+    /// Records the outcome and duration of each message by topic and event variant.
 
     /// # Errors
     /// - Returns an error wrapped in `ProcessorError` if any of the operations inside the function fail.
     pub async fn process(&self, msg: Services) -> Result<()> {
+        let (topic, variant, blockchain) = event_labels(&msg);
+        let started_at = Instant::now();
+
+        let result = self.route(msg).await;
+
+        self.metrics.record_event(
+            topic,
+            variant,
+            blockchain,
+            result.is_ok(),
+            started_at.elapsed(),
+        );
+
+        result.map_err(ProcessorError::new)
+    }
+
+    #[allow(clippy::too_many_lines)]
+    /// Routes each message to the corresponding handler based on the type of service and the specific event.
+    async fn route(&self, msg: Services) -> ProcessResult<()> {
         match msg {
             Services::Treasury(TreasuryEventKey { id, .. }, e) => match e.event {
                 Some(TreasuryEvent::ProjectWalletCreated(payload)) => {
@@ -313,7 +359,6 @@ impl Processor {
                 None | Some(_) => Ok(()),
             },
         }
-        .map_err(ProcessorError::new)
     }
 
     async fn index_collection(
@@ -342,8 +387,12 @@ impl Processor {
             image,
         } = metadata.ok_or(ProcessorErrorKind::MissingCollectionMetadata)?;
 
+        let id = Uuid::from_str(&id)?;
+        let conn = self.db.get();
+        let txn = conn.begin().await?;
+
         let collection_am = collections::ActiveModel {
-            id: Set(Uuid::from_str(&id)?),
+            id: Set(id),
             blockchain: Set(Blockchain::Solana),
             supply: Set(supply.map(Into::into)),
             project_id: Set(Uuid::from_str(&project_id)?),
@@ -358,10 +407,22 @@ impl Processor {
             created_at: Set(Utc::now().into()),
         };
 
-        collection_am.insert(self.db.get()).await?;
+        Collections::insert(collection_am)
+            .on_conflict(
+                OnConflict::column(collections::Column::Id)
+                    .update_columns([
+                        collections::Column::Supply,
+                        collections::Column::CreationStatus,
+                        collections::Column::Address,
+                        collections::Column::SellerFeeBasisPoints,
+                    ])
+                    .clone(),
+            )
+            .exec(&txn)
+            .await?;
 
         let metadata_json = metadata_jsons::ActiveModel {
-            id: Set(id.parse()?),
+            id: Set(id),
             name: Set(name),
             uri: Set(Some(uri)),
             symbol: Set(symbol),
@@ -370,12 +431,23 @@ impl Processor {
             animation_url: Set(None),
             external_url: Set(None),
             identifier: Set(Some(String::new())),
+            content_hash: Set(None),
+            ingestion_error: Set(None),
+            background_color: Set(None),
+            youtube_url: Set(None),
+            document: Set(None),
         };
 
-        let json_model = metadata_json.insert(self.db.get()).await?;
+        let json_model = upsert_metadata_json(&txn, metadata_json).await?;
+
+        collection_creators::Entity::delete_many()
+            .filter(collection_creators::Column::CollectionId.eq(id))
+            .exec(&txn)
+            .await?;
+
         for creator in creators {
             let collection_creator = collection_creators::ActiveModel {
-                collection_id: Set(id.parse()?),
+                collection_id: Set(id),
                 address: Set(creator.address),
                 verified: Set(creator.verified),
                 share: Set(creator
@@ -383,10 +455,22 @@ impl Processor {
                     .try_into()
                     .map_err(ProcessorErrorKind::InvalidCreatorShare)?),
             };
-            collection_creator.insert(self.db.get()).await?;
+            collection_creator.insert(&txn).await?;
+        }
+        index_attributes(&txn, json_model.id, attributes).await?;
+        index_files(&txn, json_model.id, files).await?;
+
+        txn.commit().await?;
+
+        if let Some(import) = Imports::find_pending_by_collection(id).one(conn).await? {
+            let mut import_am: imports::ActiveModel = import.into();
+            import_am.mints_expected = Set(supply.map(Into::into));
+            import_am.updated_at = Set(Utc::now().into());
+
+            let import = import_am.update(conn).await?;
+
+            self.complete_import(import).await?;
         }
-        index_attributes(&self.db, json_model.id, attributes).await?;
-        index_files(&self.db, json_model.id, files).await?;
 
         Ok(())
     }
@@ -397,8 +481,50 @@ impl Processor {
         created_by: String,
         payload: SolanaMintPayload,
     ) -> ProcessResult<()> {
+        let collection_id = Uuid::from_str(&payload.collection_id)?;
+
+        let import = Imports::find_pending_by_collection(collection_id)
+            .one(self.db.get())
+            .await?;
+        let import_id = import.map(|import| import.id);
+        let mint_address = payload.mint_address.clone();
+
+        let res = self
+            .upsert_imported_mint(id, collection_id, created_by, import_id, payload)
+            .await;
+
+        let Some(import_id) = import_id else {
+            return res.map(|_| ());
+        };
+
+        match res {
+            Ok(true) => {
+                self.record_imported_mint(import_id, mint_address, None)
+                    .await
+            },
+            // the event was redelivered after the mint was counted
+            Ok(false) => Ok(()),
+            // the failure is recorded on the import instead of retrying the event
+            Err(e) => {
+                error!("failed to index mint {mint_address} of import {import_id}: {e}");
+
+                self.record_imported_mint(import_id, mint_address, Some(&e))
+                    .await
+            },
+        }
+    }
+
+    /// Inserts or updates an imported mint. A mint already indexed for the collection is matched by its address and keeps its ID, histories and transfers.
+    /// Returns false if the mint was already indexed by the import.
+    async fn upsert_imported_mint(
+        &self,
+        id: String,
+        collection_id: Uuid,
+        created_by: String,
+        import_id: Option<Uuid>,
+        payload: SolanaMintPayload,
+    ) -> ProcessResult<bool> {
         let SolanaMintPayload {
-            collection_id,
             mint_address,
             owner,
             seller_fee_basis_points,
@@ -418,29 +544,58 @@ impl Processor {
             image,
         } = metadata.ok_or(ProcessorErrorKind::MissingCollectionMetadata)?;
 
-        let id = id.parse()?;
+        let seller_fee_basis_points = seller_fee_basis_points
+            .try_into()
+            .map_err(ProcessorErrorKind::InvalidSellerFee)?;
 
-        let mint_am = collection_mints::ActiveModel {
-            id: Set(id),
-            collection_id: Set(collection_id.parse()?),
-            address: Set(Some(mint_address)),
-            owner: Set(Some(owner)),
-            creation_status: Set(CreationStatus::Created),
-            created_by: Set(created_by.parse()?),
-            created_at: Set(Utc::now().into()),
-            signature: Set(None),
-            edition: Set(-1),
-            seller_fee_basis_points: Set(seller_fee_basis_points
-                .try_into()
-                .map_err(ProcessorErrorKind::InvalidSellerFee)?),
-            credits_deduction_id: Set(None),
-            compressed: Set(Some(compressed)),
-        };
+        let txn = self.db.get().begin().await?;
 
-        let mint_model = mint_am.insert(self.db.get()).await?;
+        let mint = CollectionMints::find()
+            .filter(
+                collection_mints::Column::CollectionId
+                    .eq(collection_id)
+                    .and(collection_mints::Column::Address.eq(mint_address.clone())),
+            )
+            .one(&txn)
+            .await?;
+
+        let indexed = !mint
+            .as_ref()
+            .is_some_and(|mint| mint.import_id == import_id);
+
+        let mint_model = if let Some(mint) = mint {
+            let mut mint_am: collection_mints::ActiveModel = mint.into();
+            mint_am.owner = Set(Some(owner));
+            mint_am.creation_status = Set(CreationStatus::Created);
+            mint_am.seller_fee_basis_points = Set(seller_fee_basis_points);
+            mint_am.compressed = Set(Some(compressed));
+            mint_am.import_id = Set(import_id);
+            mint_am.removed_at = Set(None);
+
+            mint_am.update(&txn).await?
+        } else {
+            let mint_am = collection_mints::ActiveModel {
+                id: Set(id.parse()?),
+                collection_id: Set(collection_id),
+                address: Set(Some(mint_address)),
+                owner: Set(Some(owner)),
+                creation_status: Set(CreationStatus::Created),
+                created_by: Set(created_by.parse()?),
+                created_at: Set(Utc::now().into()),
+                signature: Set(None),
+                edition: Set(-1),
+                seller_fee_basis_points: Set(seller_fee_basis_points),
+                credits_deduction_id: Set(None),
+                compressed: Set(Some(compressed)),
+                import_id: Set(import_id),
+                removed_at: Set(None),
+            };
+
+            mint_am.insert(&txn).await?
+        };
 
         let metadata_json = metadata_jsons::ActiveModel {
-            id: Set(id),
+            id: Set(mint_model.id),
             name: Set(name),
             uri: Set(Some(uri)),
             symbol: Set(symbol),
@@ -449,9 +604,19 @@ impl Processor {
             animation_url: Set(None),
             external_url: Set(None),
             identifier: Set(Some(String::new())),
+            content_hash: Set(None),
+            ingestion_error: Set(None),
+            background_color: Set(None),
+            youtube_url: Set(None),
+            document: Set(None),
         };
 
-        let json_model = metadata_json.insert(self.db.get()).await?;
+        let json_model = upsert_metadata_json(&txn, metadata_json).await?;
+
+        mint_creators::Entity::delete_many()
+            .filter(mint_creators::Column::CollectionMintId.eq(mint_model.id))
+            .exec(&txn)
+            .await?;
 
         for creator in creators {
             let mint_creator_am = mint_creators::ActiveModel {
@@ -463,10 +628,84 @@ impl Processor {
                     .try_into()
                     .map_err(ProcessorErrorKind::InvalidCreatorShare)?),
             };
-            mint_creator_am.insert(self.db.get()).await?;
+            mint_creator_am.insert(&txn).await?;
+        }
+        index_attributes(&txn, json_model.id, attributes).await?;
+        index_files(&txn, json_model.id, files).await?;
+
+        txn.commit().await?;
+
+        Ok(indexed)
+    }
+
+    /// Counts the mint on the import. A failed mint is also recorded by address so completing the import does not mark it as removed.
+    /// A failure is counted once per mint address, so redelivered events do not count it again.
+    async fn record_imported_mint(
+        &self,
+        import_id: Uuid,
+        mint_address: String,
+        error: Option<&ProcessorErrorKind>,
+    ) -> ProcessResult<()> {
+        let conn = self.db.get();
+        let now: DateTimeWithTimeZone = Utc::now().into();
+        let txn = conn.begin().await?;
+
+        if let Some(e) = error {
+            let failure = import_mint_failures::ActiveModel {
+                import_id: Set(import_id),
+                mint_address: Set(mint_address),
+                error: Set(e.to_string()),
+                created_at: Set(now),
+            };
+
+            let inserted = ImportMintFailures::insert(failure)
+                .on_conflict(
+                    OnConflict::columns([
+                        import_mint_failures::Column::ImportId,
+                        import_mint_failures::Column::MintAddress,
+                    ])
+                    .do_nothing()
+                    .clone(),
+                )
+                .exec_without_returning(&txn)
+                .await?;
+
+            if inserted == 0 {
+                return Ok(());
+            }
         }
-        index_attributes(&self.db, json_model.id, attributes).await?;
-        index_files(&self.db, json_model.id, files).await?;
+
+        let update = Imports::update_many()
+            .col_expr(imports::Column::UpdatedAt, Expr::value(now))
+            .filter(imports::Column::Id.eq(import_id));
+
+        let update = match error {
+            None => update.col_expr(
+                imports::Column::MintsIndexed,
+                Expr::col(imports::Column::MintsIndexed).add(1),
+            ),
+            Some(e) => update
+                .col_expr(
+                    imports::Column::MintsFailed,
+                    Expr::col(imports::Column::MintsFailed).add(1),
+                )
+                .col_expr(imports::Column::LastError, Expr::value(e.to_string())),
+        };
+
+        update.exec(&txn).await?;
+
+        txn.commit().await?;
+
+        let import = Imports::find_by_id(import_id)
+            .one(conn)
+            .await?
+            .ok_or(ProcessorErrorKind::DbMissingImport)?;
+
+        self.complete_import(import).await
+    }
+
+    async fn complete_import(&self, import: imports::Model) -> ProcessResult<()> {
+        complete_import(self.db.get(), &import).await?;
 
         Ok(())
     }
@@ -639,8 +878,22 @@ impl Processor {
             )
             .await?;
 
-        collection_active_model.update(conn).await?;
-        drops_active_model.update(conn).await?;
+        let collection_model = collection_active_model.update(conn).await?;
+        let drop_model = drops_active_model.update(conn).await?;
+
+        if drop_model.creation_status == CreationStatus::Created {
+            self.emit_webhook(
+                drop_model.project_id,
+                WebhookEventType::DropCreated,
+                serde_json::json!({
+                    "dropId": drop_model.id,
+                    "collectionId": collection_model.id,
+                    "address": collection_model.address,
+                    "signature": collection_model.signature,
+                }),
+            )
+            .await;
+        }
 
         Ok(())
     }
@@ -689,7 +942,20 @@ impl Processor {
             )
             .await?;
 
-        collection_active_model.update(conn).await?;
+        let collection_model = collection_active_model.update(conn).await?;
+
+        if collection_model.creation_status == CreationStatus::Created {
+            self.emit_webhook(
+                collection_model.project_id,
+                WebhookEventType::CollectionCreated,
+                serde_json::json!({
+                    "collectionId": collection_model.id,
+                    "address": collection_model.address,
+                    "signature": collection_model.signature,
+                }),
+            )
+            .await;
+        }
 
         Ok(())
     }
@@ -760,9 +1026,12 @@ impl Processor {
             )
             .await?;
 
-        collection_mint_active_model.update(conn).await?;
+        let collection_mint = collection_mint_active_model.update(conn).await?;
         mint_history_am.update(conn).await?;
 
+        self.emit_mint_webhook(drop.project_id, Some(drop.id), &collection_mint)
+            .await;
+
         Ok(())
     }
 
@@ -813,12 +1082,10 @@ impl Processor {
         let elapsed = now
             .signed_duration_since(collection_mint.created_at)
             .num_milliseconds();
-        self.metrics
-            .mint_duration_ms_bucket
-            .record(elapsed, &[KeyValue::new(
-                "status",
-                creation_status.as_str_name(),
-            )]);
+        self.metrics.mint_duration_ms_bucket.record(elapsed, &[
+            KeyValue::new("status", creation_status.as_str_name()),
+            KeyValue::new("blockchain", blockchain_label(Some(collection.blockchain))),
+        ]);
 
         self.producer
             .send(
@@ -836,12 +1103,42 @@ impl Processor {
             )
             .await?;
 
-        collection_mint_active_model.update(conn).await?;
+        let collection_mint = collection_mint_active_model.update(conn).await?;
         mint_history_am.update(conn).await?;
 
+        self.emit_mint_webhook(collection.project_id, None, &collection_mint)
+            .await;
+
         Ok(())
     }
 
+    /// Sends a `MINT_CREATED` or `MINT_FAILED` webhook for a mint to a drop or collection.
+    async fn emit_mint_webhook(
+        &self,
+        project_id: Uuid,
+        drop_id: Option<Uuid>,
+        collection_mint: &collection_mints::Model,
+    ) {
+        let event_type = if collection_mint.creation_status == CreationStatus::Created {
+            WebhookEventType::MintCreated
+        } else {
+            WebhookEventType::MintFailed
+        };
+
+        self.emit_webhook(
+            project_id,
+            event_type,
+            serde_json::json!({
+                "mintId": collection_mint.id,
+                "collectionId": collection_mint.collection_id,
+                "dropId": drop_id,
+                "address": collection_mint.address,
+                "signature": collection_mint.signature,
+            }),
+        )
+        .await;
+    }
+
     async fn mint_transferred(&self, id: String, payload: TransferResult) -> ProcessResult<()> {
         let conn = self.db.get();
         let transfer_id = Uuid::from_str(&id)?;
@@ -852,7 +1149,7 @@ impl Processor {
             .await?
             .ok_or(ProcessorErrorKind::DbMissingTransferCharge)?;
 
-        if let TransferResult::Success(_) = payload {
+        if let TransferResult::Success(_) = &payload {
             let deduction_id = transfer_charge
                 .credits_deduction_id
                 .ok_or(ProcessorErrorKind::RecordMissingDeductionId)?;
@@ -862,6 +1159,36 @@ impl Processor {
                 .await?;
         }
 
+        // transfers submitted before transfer tracking was added have no transfer record
+        if let Some(transfer) = Transfers::find_by_id(transfer_id).one(conn).await? {
+            let mut transfer_am: transfers::ActiveModel = transfer.into();
+
+            if let TransferResult::Success(signature) = payload {
+                transfer_am.signature = Set(Some(signature));
+                transfer_am.status = Set(CreationStatus::Created);
+            } else {
+                transfer_am.status = Set(CreationStatus::Failed);
+            }
+
+            transfer_am.updated_at = Set(Utc::now().into());
+            let transfer = transfer_am.update(conn).await?;
+
+            if transfer.status == CreationStatus::Created {
+                self.emit_collection_mint_webhook(
+                    transfer.collection_mint_id,
+                    WebhookEventType::TransferCompleted,
+                    serde_json::json!({
+                        "transferId": transfer.id,
+                        "mintId": transfer.collection_mint_id,
+                        "sender": transfer.sender,
+                        "recipient": transfer.recipient,
+                        "signature": transfer.signature,
+                    }),
+                )
+                .await;
+            }
+        }
+
         Ok(())
     }
 
@@ -884,11 +1211,45 @@ impl Processor {
             update_history_am.status = Set(CreationStatus::Failed);
         }
 
-        update_history_am.update(self.db.get()).await?;
+        let update_history = update_history_am.update(self.db.get()).await?;
+
+        if update_history.status == CreationStatus::Created {
+            self.emit_collection_mint_webhook(
+                update_history.mint_id,
+                WebhookEventType::UpdateCompleted,
+                serde_json::json!({
+                    "updateId": update_history.id,
+                    "mintId": update_history.mint_id,
+                    "signature": update_history.txn_signature,
+                }),
+            )
+            .await;
+        }
 
         Ok(())
     }
 
+    /// Sends a webhook for an event of a mint to the project of its collection.
+    async fn emit_collection_mint_webhook(
+        &self,
+        collection_mint_id: Uuid,
+        event_type: WebhookEventType,
+        data: serde_json::Value,
+    ) {
+        let collection = collection_mints::Entity::find_by_id_with_collection(collection_mint_id)
+            .one(self.db.get())
+            .await;
+
+        match collection {
+            Ok(Some((_, Some(collection)))) => {
+                self.emit_webhook(collection.project_id, event_type, data)
+                    .await;
+            },
+            Ok(_) => error!("no collection found for {event_type:?} webhook of mint {collection_mint_id}"),
+            Err(e) => error!("failed to load collection for {event_type:?} webhook of mint {collection_mint_id}: {e}"),
+        }
+    }
+
     async fn switch_collection_submitted(
         &self,
         id: String,
@@ -987,26 +1348,58 @@ impl From<PolygonTransactionResult> for TransferResult {
     }
 }
 
+async fn upsert_metadata_json(
+    db: &impl ConnectionTrait,
+    metadata_json: metadata_jsons::ActiveModel,
+) -> ProcessResult<metadata_jsons::Model> {
+    let metadata_json = metadata_jsons::Entity::insert(metadata_json)
+        .on_conflict(
+            OnConflict::column(metadata_jsons::Column::Id)
+                .update_columns([
+                    metadata_jsons::Column::Name,
+                    metadata_jsons::Column::Uri,
+                    metadata_jsons::Column::Symbol,
+                    metadata_jsons::Column::Description,
+                    metadata_jsons::Column::Image,
+                    metadata_jsons::Column::Document,
+                ])
+                .clone(),
+        )
+        .exec_with_returning(db)
+        .await?;
+
+    Ok(metadata_json)
+}
+
 async fn index_attributes(
-    db: &Connection,
+    db: &impl ConnectionTrait,
     json_id: Uuid,
     attributes: Vec<Attribute>,
 ) -> ProcessResult<()> {
+    metadata_json_attributes::Entity::delete_many()
+        .filter(metadata_json_attributes::Column::MetadataJsonId.eq(json_id))
+        .exec(db)
+        .await?;
+
     for attr in attributes {
-        let attribute = metadata_json_attributes::ActiveModel {
-            metadata_json_id: Set(json_id),
-            trait_type: Set(attr.trait_type),
-            value: Set(attr.value),
-            ..Default::default()
-        };
+        let attribute = objects::Attribute::inferred(attr.trait_type, attr.value);
 
-        attribute.insert(db.get()).await?;
+        attribute.active_model(json_id).insert(db).await?;
     }
 
     Ok(())
 }
 
-async fn index_files(db: &Connection, json_id: Uuid, files: Vec<File>) -> ProcessResult<()> {
+async fn index_files(
+    db: &impl ConnectionTrait,
+    json_id: Uuid,
+    files: Vec<File>,
+) -> ProcessResult<()> {
+    metadata_json_files::Entity::delete_many()
+        .filter(metadata_json_files::Column::MetadataJsonId.eq(json_id))
+        .exec(db)
+        .await?;
+
     for file in files {
         let file_am = metadata_json_files::ActiveModel {
             metadata_json_id: Set(json_id),
@@ -1015,8 +1408,190 @@ async fn index_files(db: &Connection, json_id: Uuid, files: Vec<File>) -> Proces
             ..Default::default()
         };
 
-        file_am.insert(db.get()).await?;
+        file_am.insert(db).await?;
     }
 
     Ok(())
 }
+
+/// Returns the topic, event variant and blockchain a message is recorded under
+fn event_labels(msg: &Services) -> (&'static str, &'static str, Option<Blockchain>) {
+    match msg {
+        Services::Treasury(_, e) => {
+            let (variant, blockchain) = treasury_variant(e.event.as_ref());
+
+            ("hub-treasuries", variant, blockchain)
+        },
+        Services::Solana(_, e) => (
+            "hub-nfts-solana",
+            solana_variant(e.event.as_ref()),
+            Some(Blockchain::Solana),
+        ),
+        Services::Polygon(_, e) => (
+            "hub-nfts-polygon",
+            polygon_variant(e.event.as_ref()),
+            Some(Blockchain::Polygon),
+        ),
+    }
+}
+
+/// Returns the name of a treasury event handled by the processor and the blockchain it belongs to.
+/// Events the processor ignores are recorded as `Other`.
+fn treasury_variant(event: Option<&TreasuryEvent>) -> (&'static str, Option<Blockchain>) {
+    match event {
+        Some(TreasuryEvent::CustomerWalletCreated(_)) => ("CustomerWalletCreated", None),
+        Some(TreasuryEvent::PolygonCreateDropTxnSubmitted(_)) => {
+            ("PolygonCreateDropTxnSubmitted", Some(Blockchain::Polygon))
+        },
+        Some(TreasuryEvent::PolygonMintDropSubmitted(_)) => {
+            ("PolygonMintDropSubmitted", Some(Blockchain::Polygon))
+        },
+        Some(TreasuryEvent::PolygonRetryCreateDropSubmitted(_)) => {
+            ("PolygonRetryCreateDropSubmitted", Some(Blockchain::Polygon))
+        },
+        Some(TreasuryEvent::PolygonRetryMintDropSubmitted(_)) => {
+            ("PolygonRetryMintDropSubmitted", Some(Blockchain::Polygon))
+        },
+        Some(TreasuryEvent::PolygonTransferAssetSubmitted(_)) => {
+            ("PolygonTransferAssetSubmitted", Some(Blockchain::Polygon))
+        },
+        Some(TreasuryEvent::ProjectWalletCreated(_)) => ("ProjectWalletCreated", None),
+        Some(_) => ("Other", None),
+        None => ("None", None),
+    }
+}
+
+/// Returns the name of a Solana event handled by the processor. Events the processor ignores are recorded as `Other`.
+fn solana_variant(event: Option<&SolanaNftsEvent>) -> &'static str {
+    match event {
+        Some(SolanaNftsEvent::CreateCollectionFailed(_)) => "CreateCollectionFailed",
+        Some(SolanaNftsEvent::CreateCollectionSubmitted(_)) => "CreateCollectionSubmitted",
+        Some(SolanaNftsEvent::CreateEditionDropFailed(_)) => "CreateEditionDropFailed",
+        Some(SolanaNftsEvent::CreateEditionDropSubmitted(_)) => "CreateEditionDropSubmitted",
+        Some(SolanaNftsEvent::CreateOpenDropFailed(_)) => "CreateOpenDropFailed",
+        Some(SolanaNftsEvent::CreateOpenDropSubmitted(_)) => "CreateOpenDropSubmitted",
+        Some(SolanaNftsEvent::ImportedExternalCollection(_)) => "ImportedExternalCollection",
+        Some(SolanaNftsEvent::ImportedExternalMint(_)) => "ImportedExternalMint",
+        Some(SolanaNftsEvent::MintEditionDropFailed(_)) => "MintEditionDropFailed",
+        Some(SolanaNftsEvent::MintEditionDropSubmitted(_)) => "MintEditionDropSubmitted",
+        Some(SolanaNftsEvent::MintOpenDropFailed(_)) => "MintOpenDropFailed",
+        Some(SolanaNftsEvent::MintOpenDropSubmitted(_)) => "MintOpenDropSubmitted",
+        Some(SolanaNftsEvent::MintToCollectionFailed(_)) => "MintToCollectionFailed",
+        Some(SolanaNftsEvent::MintToCollectionSubmitted(_)) => "MintToCollectionSubmitted",
+        Some(SolanaNftsEvent::RetryCreateCollectionFailed(_)) => "RetryCreateCollectionFailed",
+        Some(SolanaNftsEvent::RetryCreateCollectionSubmitted(_)) => {
+            "RetryCreateCollectionSubmitted"
+        },
+        Some(SolanaNftsEvent::RetryCreateEditionDropFailed(_)) => "RetryCreateEditionDropFailed",
+        Some(SolanaNftsEvent::RetryCreateEditionDropSubmitted(_)) => {
+            "RetryCreateEditionDropSubmitted"
+        },
+        Some(SolanaNftsEvent::RetryCreateOpenDropFailed(_)) => "RetryCreateOpenDropFailed",
+        Some(SolanaNftsEvent::RetryCreateOpenDropSubmitted(_)) => "RetryCreateOpenDropSubmitted",
+        Some(SolanaNftsEvent::RetryMintEditionDropFailed(_)) => "RetryMintEditionDropFailed",
+        Some(SolanaNftsEvent::RetryMintEditionDropSubmitted(_)) => "RetryMintEditionDropSubmitted",
+        Some(SolanaNftsEvent::RetryMintOpenDropSubmitted(_)) => "RetryMintOpenDropSubmitted",
+        Some(SolanaNftsEvent::RetryMintToCollectionFailed(_)) => "RetryMintToCollectionFailed",
+        Some(SolanaNftsEvent::RetryMintToCollectionSubmitted(_)) => {
+            "RetryMintToCollectionSubmitted"
+        },
+        Some(SolanaNftsEvent::RetryUpdateMintFailed(_)) => "RetryUpdateMintFailed",
+        Some(SolanaNftsEvent::RetryUpdateMintSubmitted(_)) => "RetryUpdateMintSubmitted",
+        Some(SolanaNftsEvent::SwitchMintCollectionFailed(_)) => "SwitchMintCollectionFailed",
+        Some(SolanaNftsEvent::SwitchMintCollectionSubmitted(_)) => "SwitchMintCollectionSubmitted",
+        Some(SolanaNftsEvent::TransferAssetFailed(_)) => "TransferAssetFailed",
+        Some(SolanaNftsEvent::TransferAssetSubmitted(_)) => "TransferAssetSubmitted",
+        Some(SolanaNftsEvent::UpdateCollectionMintFailed(_)) => "UpdateCollectionMintFailed",
+        Some(SolanaNftsEvent::UpdateCollectionMintSubmitted(_)) => "UpdateCollectionMintSubmitted",
+        Some(SolanaNftsEvent::UpdateMintOwner(_)) => "UpdateMintOwner",
+        Some(_) => "Other",
+        None => "None",
+    }
+}
+
+/// Returns the name of a Polygon event handled by the processor. Events the processor ignores are recorded as `Other`.
+fn polygon_variant(event: Option<&PolygonNftEvents>) -> &'static str {
+    match event {
+        Some(PolygonNftEvents::UpdateMintsOwner(_)) => "UpdateMintsOwner",
+        Some(_) => "Other",
+        None => "None",
+    }
+}
+
+/// Matches mints that were not indexed by the import. Mints indexed before imports were tracked have no import ID and are matched as well.
+/// Mints that failed to index during the import are excluded since their absence is not a removal.
+const NOT_SEEN_BY_IMPORT_FILTER: &str = "collection_mints.import_id IS DISTINCT FROM ?
+    AND NOT EXISTS (SELECT 1 FROM import_mint_failures
+        WHERE import_mint_failures.import_id = ?
+            AND import_mint_failures.mint_address = collection_mints.address)";
+
+/// Completes the import once every expected mint has been processed and marks mints of the collection that were not seen again as removed.
+/// Returns `false` if the import is still expecting mints or was already completed.
+///
+/// # Errors
+/// Returns an error if a database query fails.
+pub async fn complete_import<C>(
+    db: &C,
+    import: &imports::Model,
+) -> std::result::Result<bool, sea_orm::DbErr>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let Some(mints_expected) = import.mints_expected else {
+        return Ok(false);
+    };
+
+    if import.mints_indexed + import.mints_failed < mints_expected {
+        return Ok(false);
+    }
+
+    let txn = db.begin().await?;
+    let now: DateTimeWithTimeZone = Utc::now().into();
+
+    let completed = Imports::update_many()
+        .set(imports::ActiveModel {
+            status: Set(CreationStatus::Created),
+            completed_at: Set(Some(now)),
+            updated_at: Set(now),
+            ..Default::default()
+        })
+        .filter(
+            imports::Column::Id
+                .eq(import.id)
+                .and(imports::Column::Status.eq(CreationStatus::Pending)),
+        )
+        .exec(&txn)
+        .await?;
+
+    // another handler already completed the import
+    if completed.rows_affected == 0 {
+        return Ok(false);
+    }
+
+    let removed = CollectionMints::update_many()
+        .col_expr(collection_mints::Column::RemovedAt, Expr::value(now))
+        .filter(
+            collection_mints::Column::CollectionId
+                .eq(import.collection_id)
+                .and(collection_mints::Column::RemovedAt.is_null()),
+        )
+        .filter(Expr::cust_with_values(
+            NOT_SEEN_BY_IMPORT_FILTER,
+            [import.id, import.id],
+        ))
+        .exec(&txn)
+        .await?;
+
+    Imports::update_many()
+        .col_expr(
+            imports::Column::MintsRemoved,
+            Expr::value(i64::try_from(removed.rows_affected).unwrap_or(i64::MAX)),
+        )
+        .filter(imports::Column::Id.eq(import.id))
+        .exec(&txn)
+        .await?;
+
+    txn.commit().await?;
+
+    Ok(true)
+}
@@ -7,17 +7,32 @@ use hub_core::{
 use poem::{
     handler,
     http::StatusCode,
-    web::{Data, Html},
+    web::{Data, Html, Json},
     IntoResponse,
 };
 
-use crate::{AppContext, AppState, Balance, Metrics, OrganizationId, UserID};
+use crate::{
+    readiness::{CheckStatus, Readiness},
+    AppContext, AppState, Balance, Metrics, OrganizationId, UserID,
+};
 
 #[handler]
 pub fn health() -> StatusCode {
     StatusCode::OK
 }
 
+#[handler]
+pub async fn ready(Data(readiness): Data<&Readiness>) -> impl IntoResponse {
+    let report = readiness.check().await;
+
+    let status = match report.status {
+        CheckStatus::Up => StatusCode::OK,
+        CheckStatus::Down => StatusCode::SERVICE_UNAVAILABLE,
+    };
+
+    Json(report).with_status(status)
+}
+
 #[handler]
 pub fn playground() -> impl IntoResponse {
     Html(playground_source(GraphQLPlaygroundConfig::new("/graphql")))
@@ -37,6 +52,7 @@ pub async fn graphql_handler(
         user_id,
         organization,
         balance,
+        state.metrics.clone(),
     );
 
     Ok(state
@@ -49,7 +65,9 @@ pub async fn graphql_handler(
                 .data(state.solana.clone())
                 .data(state.polygon.clone())
                 .data(state.asset_proxy.clone())
-                .data(state.metadata_json_upload_job_queue.clone()),
+                .data(state.metadata_json_upload_job_queue.clone())
+                .data(state.webhooks.clone())
+                .data(state.metrics.clone()),
         )
         .await
         .into())
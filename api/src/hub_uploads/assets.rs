@@ -0,0 +1,233 @@
+use hub_core::{anyhow::Result, prelude::*, thiserror};
+use reqwest::{header::CONTENT_TYPE, StatusCode};
+
+use super::content_hash;
+use crate::outbound;
+
+/// The time allowed for downloading a single asset
+const FETCH_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How an asset is referenced by a metadata json, which decides the content types it may have
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetKind {
+    /// The `image` of a metadata json
+    Image,
+    /// The `animation_url` or a file in `properties.files`
+    Media,
+}
+
+impl AssetKind {
+    /// Returns true if an asset of the content type may be referenced this way
+    #[must_use]
+    pub fn accepts(self, content_type: &str) -> bool {
+        match self {
+            Self::Image => content_type.starts_with("image/"),
+            Self::Media => {
+                ["image/", "video/", "audio/", "model/"]
+                    .iter()
+                    .any(|prefix| content_type.starts_with(prefix))
+                    || content_type == "text/html"
+            },
+        }
+    }
+}
+
+/// An asset that can never be ingested because it is missing, not public or fails validation.
+/// Retrying the download would fail the same way.
+#[derive(Debug, thiserror::Error)]
+#[error("{0}")]
+pub struct InvalidAsset(String);
+
+fn invalid(message: String) -> Error {
+    InvalidAsset(message).into()
+}
+
+/// A downloaded asset that passed validation
+#[derive(Debug, Clone)]
+pub struct FetchedAsset {
+    pub file_name: String,
+    pub content_type: String,
+    pub content_hash: String,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub contents: Vec<u8>,
+}
+
+/// Downloads and validates the media referenced by metadata jsons before it is re-hosted
+#[derive(Debug, Clone)]
+pub struct AssetIngestion {
+    http: reqwest::Client,
+    max_bytes: u64,
+    max_dimension: u32,
+    ipfs_gateway_url: String,
+}
+
+impl AssetIngestion {
+    /// Returns the asset ingestion step
+    ///
+    /// # Errors
+    /// if the http client fails to build
+    pub fn new(max_bytes: u64, max_dimension: u32, ipfs_gateway_url: &str) -> Result<Self> {
+        let http = outbound::client_builder()
+            .timeout(FETCH_TIMEOUT)
+            .build()
+            .context("failed to build asset http client")?;
+
+        Ok(Self {
+            http,
+            max_bytes,
+            max_dimension,
+            ipfs_gateway_url: ipfs_gateway_url.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Returns the url an asset is downloaded from. `ipfs://` uris are resolved through the IPFS gateway.
+    ///
+    /// # Errors
+    /// if the uri is not a valid url or its scheme is not supported
+    pub fn resolve(&self, uri: &str) -> Result<Url> {
+        let url = Url::parse(uri).with_context(|| format!("invalid asset url {uri}"))?;
+
+        match url.scheme() {
+            "http" | "https" => Ok(url),
+            "ipfs" => {
+                let path = uri
+                    .trim_start_matches("ipfs://")
+                    .trim_start_matches("ipfs/");
+
+                Url::parse(&format!("{}/ipfs/{path}", self.ipfs_gateway_url))
+                    .with_context(|| format!("invalid asset url {uri}"))
+            },
+            scheme => bail!("unsupported scheme {scheme} of asset url {uri}"),
+        }
+    }
+
+    /// Downloads an asset and checks its content type, size and, for raster images, dimensions
+    ///
+    /// # Errors
+    /// if the download fails, or with an [`InvalidAsset`] if the asset is not found, not public or not valid for its kind
+    pub async fn fetch(&self, uri: &str, kind: AssetKind) -> Result<FetchedAsset> {
+        let url = self.resolve(uri).map_err(|e| invalid(e.to_string()))?;
+
+        outbound::check_url(&url).map_err(|e| invalid(format!("asset {uri}: {e}")))?;
+
+        let response = self.http.get(url.clone()).send().await.map_err(|e| {
+            if outbound::is_not_public(&e) {
+                invalid(format!("asset {uri} does not point to a public address"))
+            } else {
+                Error::new(e).context(format!("failed to download asset {uri}"))
+            }
+        })?;
+
+        let status = response.status();
+
+        if status.is_client_error()
+            && status != StatusCode::REQUEST_TIMEOUT
+            && status != StatusCode::TOO_MANY_REQUESTS
+        {
+            return Err(invalid(format!(
+                "asset {uri} could not be downloaded: {status}"
+            )));
+        }
+
+        let mut response = response
+            .error_for_status()
+            .with_context(|| format!("failed to download asset {uri}"))?;
+
+        if response
+            .content_length()
+            .is_some_and(|length| length > self.max_bytes)
+        {
+            return Err(invalid(format!(
+                "asset {uri} is larger than {} bytes",
+                self.max_bytes
+            )));
+        }
+
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(';').next())
+            .map(|value| value.trim().to_lowercase())
+            .unwrap_or_default();
+
+        if !kind.accepts(&content_type) {
+            return Err(invalid(format!(
+                "asset {uri} has unsupported content type {content_type:?}"
+            )));
+        }
+
+        let mut contents = Vec::new();
+
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .with_context(|| format!("failed to download asset {uri}"))?
+        {
+            contents.extend_from_slice(&chunk);
+
+            if u64::try_from(contents.len()).unwrap_or(u64::MAX) > self.max_bytes {
+                return Err(invalid(format!(
+                    "asset {uri} is larger than {} bytes",
+                    self.max_bytes
+                )));
+            }
+        }
+
+        if contents.is_empty() {
+            return Err(invalid(format!("asset {uri} is empty")));
+        }
+
+        let (width, height) =
+            if content_type.starts_with("image/") && content_type != "image/svg+xml" {
+                let size = imagesize::blob_size(&contents)
+                    .map_err(|_| invalid(format!("asset {uri} is not a valid image")))?;
+                let width = u32::try_from(size.width).unwrap_or(u32::MAX);
+                let height = u32::try_from(size.height).unwrap_or(u32::MAX);
+
+                if width == 0 || height == 0 {
+                    return Err(invalid(format!("asset {uri} has no dimensions")));
+                }
+
+                if width > self.max_dimension || height > self.max_dimension {
+                    return Err(invalid(format!(
+                        "asset {uri} is {width}x{height}, larger than {0}x{0}",
+                        self.max_dimension
+                    )));
+                }
+
+                (Some(width), Some(height))
+            } else {
+                (None, None)
+            };
+
+        Ok(FetchedAsset {
+            file_name: file_name(&url, &content_type),
+            content_type,
+            content_hash: content_hash(&contents),
+            width,
+            height,
+            contents,
+        })
+    }
+}
+
+/// Returns the last path segment of the url, or a name with an extension matching the content type
+fn file_name(url: &Url, content_type: &str) -> String {
+    url.path_segments()
+        .and_then(Iterator::last)
+        .filter(|segment| segment.contains('.'))
+        .map_or_else(
+            || {
+                let subtype = content_type
+                    .split('/')
+                    .nth(1)
+                    .and_then(|subtype| subtype.split('+').next())
+                    .unwrap_or("bin");
+
+                format!("asset.{subtype}")
+            },
+            ToString::to_string,
+        )
+}
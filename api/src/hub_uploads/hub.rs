@@ -0,0 +1,106 @@
+use std::path::Path;
+
+use hub_core::{anyhow::Result, prelude::*};
+use reqwest::multipart::{Form, Part};
+
+use super::{file_part, MetadataStorage, UploadResponse};
+
+/// Stores uploads with the hub-uploads service
+#[derive(Debug, Clone)]
+pub struct HubUploads {
+    http: reqwest::Client,
+    api_base_url: Url,
+}
+
+impl HubUploads {
+    /// Returns the hub-uploads client
+    ///
+    /// # Errors
+    /// if url parsing fails
+    pub fn new(hub_uploads_api_endpoint: String) -> Result<Self> {
+        let api_base_url = Url::parse(&hub_uploads_api_endpoint)
+            .context("failed to parse nft storage base url")?;
+
+        Ok(Self {
+            http: reqwest::Client::new(),
+            api_base_url,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl MetadataStorage for HubUploads {
+    async fn upload_json(&self, data: &serde_json::Value) -> Result<UploadResponse> {
+        let url = self.api_base_url.join("/uploads")?;
+
+        self.http
+            .post(url)
+            .json(data)
+            .send()
+            .await
+            .context("failed to send post request")?
+            .json()
+            .await
+            .context("failed to parse response")
+    }
+
+    async fn upload_file(
+        &self,
+        file_name: &str,
+        content_type: &str,
+        contents: Vec<u8>,
+    ) -> Result<UploadResponse> {
+        let url = self.api_base_url.join("/uploads")?;
+        let part = Part::bytes(contents)
+            .file_name(file_name.to_string())
+            .mime_str(content_type)?;
+
+        self.http
+            .post(url)
+            .multipart(Form::new().part("file", part))
+            .send()
+            .await
+            .context("failed to send post request")?
+            .json()
+            .await
+            .context("failed to parse response")
+    }
+
+    async fn upload_path(
+        &self,
+        file_name: &str,
+        content_type: &str,
+        path: &Path,
+    ) -> Result<UploadResponse> {
+        let url = self.api_base_url.join("/uploads")?;
+        let part = file_part(path, file_name, content_type).await?;
+
+        self.http
+            .post(url)
+            .multipart(Form::new().part("file", part))
+            .send()
+            .await
+            .context("failed to send post request")?
+            .json()
+            .await
+            .context("failed to parse response")
+    }
+
+    async fn ping(&self) -> Result<()> {
+        let url = self.api_base_url.join("/health")?;
+
+        self.http
+            .get(url)
+            .send()
+            .await
+            .context("failed to send health request")?
+            .error_for_status()
+            .context("hub-uploads is not healthy")?;
+
+        Ok(())
+    }
+
+    fn location(&self) -> String {
+        format!("hub-uploads:{}", self.api_base_url)
+    }
+}
@@ -0,0 +1,96 @@
+use std::path::Path;
+
+use hub_core::{anyhow::Result, prelude::*};
+use reqwest::multipart::{Form, Part};
+use serde::Deserialize;
+
+use super::{file_part, MetadataStorage, UploadResponse};
+
+/// Stores uploads by adding and pinning them with the HTTP API of an IPFS node
+#[derive(Debug, Clone)]
+pub struct Ipfs {
+    http: reqwest::Client,
+    add_url: Url,
+    gateway_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct AddResponse {
+    #[serde(rename = "Hash")]
+    hash: String,
+}
+
+impl Ipfs {
+    /// Returns the IPFS client
+    ///
+    /// # Errors
+    /// if url parsing fails
+    pub fn new(api_endpoint: String, gateway_url: &str) -> Result<Self> {
+        let mut add_url = Url::parse(&api_endpoint)
+            .context("failed to parse ipfs api url")?
+            .join("/api/v0/add")?;
+
+        add_url
+            .query_pairs_mut()
+            .append_pair("cid-version", "1")
+            .append_pair("pin", "true");
+
+        Ok(Self {
+            http: reqwest::Client::new(),
+            add_url,
+            gateway_url: gateway_url.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Adds and pins the file of the multipart part
+    async fn add(&self, part: Part) -> Result<UploadResponse> {
+        let AddResponse { hash } = self
+            .http
+            .post(self.add_url.clone())
+            .multipart(Form::new().part("file", part))
+            .send()
+            .await
+            .context("failed to send add request to ipfs")?
+            .error_for_status()
+            .context("ipfs rejected the file")?
+            .json()
+            .await
+            .context("failed to parse ipfs response")?;
+
+        Ok(UploadResponse {
+            uri: format!("{}/ipfs/{hash}", self.gateway_url),
+            cid: hash,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl MetadataStorage for Ipfs {
+    async fn upload_file(
+        &self,
+        file_name: &str,
+        content_type: &str,
+        contents: Vec<u8>,
+    ) -> Result<UploadResponse> {
+        let part = Part::bytes(contents)
+            .file_name(file_name.to_string())
+            .mime_str(content_type)?;
+
+        self.add(part).await
+    }
+
+    async fn upload_path(
+        &self,
+        file_name: &str,
+        content_type: &str,
+        path: &Path,
+    ) -> Result<UploadResponse> {
+        let part = file_part(path, file_name, content_type).await?;
+
+        self.add(part).await
+    }
+
+    fn location(&self) -> String {
+        format!("ipfs:{}", self.gateway_url)
+    }
+}
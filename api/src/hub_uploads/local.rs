@@ -0,0 +1,88 @@
+use std::path::{Path, PathBuf};
+
+use hub_core::{anyhow::Result, prelude::*, tokio};
+
+use super::{content_hash, content_hash_file, extension, MetadataStorage, UploadResponse};
+
+/// Stores uploads as files named by their content hash in a local directory.
+/// Meant for local development, with the directory optionally served by any static HTTP server.
+#[derive(Debug, Clone)]
+pub struct LocalStorage {
+    dir: PathBuf,
+    base_url: Url,
+}
+
+impl LocalStorage {
+    /// Returns the local storage, creating the directory if it does not exist
+    ///
+    /// # Errors
+    /// if the directory cannot be created or url parsing fails
+    pub fn new(dir: PathBuf, base_url: Option<String>) -> Result<Self> {
+        std::fs::create_dir_all(&dir).context("failed to create local storage directory")?;
+
+        let dir = dir
+            .canonicalize()
+            .context("failed to resolve local storage directory")?;
+
+        let base_url = match base_url {
+            Some(base_url) => Url::parse(&base_url).context("failed to parse local storage url")?,
+            None => Url::from_directory_path(&dir)
+                .map_err(|()| anyhow!("local storage directory must be an absolute path"))?,
+        };
+
+        Ok(Self { dir, base_url })
+    }
+
+    fn response(&self, name: &str, cid: String) -> Result<UploadResponse> {
+        let uri = if self.base_url.path().ends_with('/') {
+            self.base_url.join(name)?
+        } else {
+            Url::parse(&format!("{}/{name}", self.base_url))?
+        };
+
+        Ok(UploadResponse {
+            uri: uri.to_string(),
+            cid,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl MetadataStorage for LocalStorage {
+    async fn upload_file(
+        &self,
+        file_name: &str,
+        _content_type: &str,
+        contents: Vec<u8>,
+    ) -> Result<UploadResponse> {
+        let cid = content_hash(&contents);
+        let name = format!("{cid}{}", extension(file_name));
+        let path = self.dir.join(&name);
+
+        tokio::task::spawn_blocking(move || std::fs::write(path, contents))
+            .await?
+            .context("failed to write file to local storage")?;
+
+        self.response(&name, cid)
+    }
+
+    async fn upload_path(
+        &self,
+        file_name: &str,
+        _content_type: &str,
+        path: &Path,
+    ) -> Result<UploadResponse> {
+        let (cid, _) = content_hash_file(path).await?;
+        let name = format!("{cid}{}", extension(file_name));
+
+        tokio::fs::copy(path, self.dir.join(&name))
+            .await
+            .context("failed to copy file to local storage")?;
+
+        self.response(&name, cid)
+    }
+
+    fn location(&self) -> String {
+        format!("local:{}", self.base_url)
+    }
+}
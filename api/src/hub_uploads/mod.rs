@@ -0,0 +1,347 @@
+mod assets;
+mod hub;
+mod ipfs;
+mod local;
+mod s3;
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Instant,
+};
+
+use hub_core::{
+    anyhow::Result,
+    backon::{ExponentialBuilder, Retryable},
+    clap,
+    prelude::*,
+    tokio::{self, io::AsyncReadExt},
+};
+use reqwest::multipart::Part;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+pub use self::{
+    assets::{AssetIngestion, AssetKind, FetchedAsset, InvalidAsset},
+    hub::HubUploads,
+    ipfs::Ipfs,
+    local::LocalStorage,
+    s3::S3,
+};
+use crate::{entities::sea_orm_active_enums::Blockchain, metrics::Metrics};
+
+/// The backends metadata jsons and exports can be uploaded to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum StorageBackend {
+    /// The hub-uploads service
+    HubUploads,
+    /// A local directory, optionally served over HTTP
+    Local,
+    /// An S3-compatible bucket
+    S3,
+    /// The HTTP API of an IPFS node
+    Ipfs,
+}
+
+/// Arguments for establishing a nft storage connection
+#[derive(Debug, clap::Args)]
+pub struct HubUploadArgs {
+    /// The backend uploads are stored in
+    #[arg(long, env, value_enum, default_value_t = StorageBackend::HubUploads)]
+    pub metadata_storage: StorageBackend,
+
+    /// The base url of the hub-uploads service. Required by the `hub-uploads` backend.
+    #[arg(long, env)]
+    pub hub_uploads_api_endpoint: Option<String>,
+
+    /// The directory files are written to. Required by the `local` backend.
+    #[arg(long, env)]
+    pub local_storage_dir: Option<PathBuf>,
+    /// The base url the local storage directory is served at. Defaults to `file://` urls.
+    #[arg(long, env)]
+    pub local_storage_url: Option<String>,
+
+    /// The endpoint of the S3-compatible API. Required by the `s3` backend.
+    #[arg(long, env)]
+    pub s3_endpoint: Option<String>,
+    /// The bucket files are written to. Required by the `s3` backend.
+    #[arg(long, env)]
+    pub s3_bucket: Option<String>,
+    #[arg(long, env, default_value = "us-east-1")]
+    pub s3_region: String,
+    #[arg(long, env)]
+    pub s3_access_key_id: Option<String>,
+    #[arg(long, env)]
+    pub s3_secret_access_key: Option<String>,
+    /// The key prefix files are written under
+    #[arg(long, env)]
+    pub s3_prefix: Option<String>,
+    /// The base url the bucket is publicly served at. Defaults to the path-style url of the bucket.
+    #[arg(long, env)]
+    pub s3_public_url: Option<String>,
+
+    /// The base url of the IPFS node HTTP API. Required by the `ipfs` backend.
+    #[arg(long, env)]
+    pub ipfs_api_endpoint: Option<String>,
+    /// The gateway uploaded files are linked to
+    #[arg(long, env, default_value = "https://ipfs.io")]
+    pub ipfs_gateway_url: String,
+
+    /// Fetch, validate and re-host the media referenced by metadata jsons before uploading them
+    #[arg(long, env, default_value_t = false)]
+    pub ingest_assets: bool,
+    /// The largest asset accepted, in bytes
+    #[arg(long, env, default_value_t = 100 * 1024 * 1024)]
+    pub asset_max_bytes: u64,
+    /// The largest width or height of an image asset accepted, in pixels
+    #[arg(long, env, default_value_t = 8192)]
+    pub asset_max_dimension: u32,
+}
+
+/// A place uploaded metadata jsons and files are stored at
+#[async_trait::async_trait]
+pub trait MetadataStorage: Send + Sync + std::fmt::Debug {
+    /// Stores a json document and returns where it can be fetched from
+    ///
+    /// # Errors
+    /// If the document cannot be stored
+    async fn upload_json(&self, data: &serde_json::Value) -> Result<UploadResponse> {
+        self.upload_file(
+            "metadata.json",
+            "application/json",
+            serde_json::to_vec(data)?,
+        )
+        .await
+    }
+
+    /// Stores a file and returns where it can be fetched from
+    ///
+    /// # Errors
+    /// If the file cannot be stored
+    async fn upload_file(
+        &self,
+        file_name: &str,
+        content_type: &str,
+        contents: Vec<u8>,
+    ) -> Result<UploadResponse>;
+
+    /// Stores a file read from disk and returns where it can be fetched from.
+    /// The file is streamed rather than read into memory.
+    ///
+    /// # Errors
+    /// If the file cannot be read or stored
+    async fn upload_path(
+        &self,
+        file_name: &str,
+        content_type: &str,
+        path: &Path,
+    ) -> Result<UploadResponse>;
+
+    /// Checks the storage can be reached. Backends without a remote service are always reachable.
+    ///
+    /// # Errors
+    /// If the storage cannot be reached
+    async fn ping(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Identifies where uploads are stored, so a cached upload is only reused by the storage that holds it
+    fn location(&self) -> String;
+}
+
+#[derive(Debug, Clone)]
+pub struct HubUploadClient {
+    storage: Arc<dyn MetadataStorage>,
+    assets: Option<AssetIngestion>,
+    metrics: Metrics,
+}
+
+impl HubUploadClient {
+    /// Returns the client for the storage backend selected by the arguments
+    ///
+    /// # Errors
+    /// if a required argument of the backend is missing, http client fails to build or url parsing fails
+    pub fn new(args: HubUploadArgs, metrics: Metrics) -> Result<Self> {
+        let storage: Arc<dyn MetadataStorage> = match args.metadata_storage {
+            StorageBackend::HubUploads => Arc::new(HubUploads::new(
+                args.hub_uploads_api_endpoint
+                    .context("--hub-uploads-api-endpoint is required by the hub-uploads backend")?,
+            )?),
+            StorageBackend::Local => Arc::new(LocalStorage::new(
+                args.local_storage_dir
+                    .context("--local-storage-dir is required by the local backend")?,
+                args.local_storage_url,
+            )?),
+            StorageBackend::S3 => Arc::new(S3::new(
+                args.s3_endpoint
+                    .context("--s3-endpoint is required by the s3 backend")?,
+                args.s3_bucket
+                    .context("--s3-bucket is required by the s3 backend")?,
+                args.s3_region,
+                args.s3_access_key_id
+                    .context("--s3-access-key-id is required by the s3 backend")?,
+                args.s3_secret_access_key
+                    .context("--s3-secret-access-key is required by the s3 backend")?,
+                args.s3_prefix,
+                args.s3_public_url,
+            )?),
+            StorageBackend::Ipfs => Arc::new(Ipfs::new(
+                args.ipfs_api_endpoint
+                    .context("--ipfs-api-endpoint is required by the ipfs backend")?,
+                &args.ipfs_gateway_url,
+            )?),
+        };
+
+        let assets = args
+            .ingest_assets
+            .then(|| {
+                AssetIngestion::new(
+                    args.asset_max_bytes,
+                    args.asset_max_dimension,
+                    &args.ipfs_gateway_url,
+                )
+            })
+            .transpose()?;
+
+        Ok(Self {
+            storage,
+            assets,
+            metrics,
+        })
+    }
+
+    /// Returns the asset ingestion step if it is enabled
+    #[must_use]
+    pub fn assets(&self) -> Option<&AssetIngestion> {
+        self.assets.as_ref()
+    }
+
+    /// Identifies where uploads are stored. Cached uploads are keyed by it so switching storage uploads again.
+    #[must_use]
+    pub fn location(&self) -> String {
+        self.storage.location()
+    }
+
+    /// Checks the storage backend can be reached
+    ///
+    /// # Errors
+    /// If the storage cannot be reached
+    pub async fn ping(&self) -> Result<()> {
+        self.storage.ping().await
+    }
+
+    /// Uploads the json data of an asset on the blockchain and returns the response
+    ///
+    /// # Errors
+    /// If the upload fails
+    pub async fn upload(
+        &self,
+        data: &impl Serialize,
+        blockchain: Blockchain,
+    ) -> Result<UploadResponse> {
+        let data = serde_json::to_value(data)?;
+        let upload = || self.storage.upload_json(&data);
+
+        let started_at = Instant::now();
+        let response = upload
+            .retry(&backoff())
+            .notify(|_, _| self.metrics.record_upload_retry(blockchain))
+            .await;
+
+        self.metrics
+            .record_upload(blockchain, response.is_ok(), started_at.elapsed());
+
+        response
+    }
+
+    /// Uploads a file and returns the response
+    ///
+    /// # Errors
+    /// If the upload fails
+    pub async fn upload_file(
+        &self,
+        file_name: &str,
+        content_type: &str,
+        contents: Vec<u8>,
+    ) -> Result<UploadResponse> {
+        let upload = || {
+            self.storage
+                .upload_file(file_name, content_type, contents.clone())
+        };
+
+        upload.retry(&backoff()).await
+    }
+
+    /// Uploads a file read from disk without loading it into memory and returns the response
+    ///
+    /// # Errors
+    /// If the file cannot be read or the upload fails
+    pub async fn upload_path(
+        &self,
+        file_name: &str,
+        content_type: &str,
+        path: &Path,
+    ) -> Result<UploadResponse> {
+        let upload = || self.storage.upload_path(file_name, content_type, path);
+
+        upload.retry(&backoff()).await
+    }
+}
+
+fn backoff() -> ExponentialBuilder {
+    ExponentialBuilder::default()
+        .with_jitter()
+        .with_min_delay(Duration::from_millis(30))
+        .with_max_times(15)
+}
+
+/// Returns the hex encoded SHA-256 of the contents, used to name files by their content
+fn content_hash(contents: &[u8]) -> String {
+    hex::encode(Sha256::digest(contents))
+}
+
+/// Returns the hex encoded SHA-256 and the length of a file, reading it in chunks
+async fn content_hash_file(path: &Path) -> Result<(String, u64)> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0; 64 * 1024];
+    let mut len = 0;
+
+    loop {
+        let read = file.read(&mut buf).await?;
+
+        if read == 0 {
+            break;
+        }
+
+        hasher.update(&buf[..read]);
+        len += u64::try_from(read)?;
+    }
+
+    Ok((hex::encode(hasher.finalize()), len))
+}
+
+/// Returns a multipart part streaming the file at the path
+async fn file_part(path: &Path, file_name: &str, content_type: &str) -> Result<Part> {
+    let file = tokio::fs::File::open(path).await?;
+    let len = file.metadata().await?.len();
+
+    Ok(Part::stream_with_length(file, len)
+        .file_name(file_name.to_string())
+        .mime_str(content_type)?)
+}
+
+/// Returns the extension of a file name including the dot, or an empty string
+fn extension(file_name: &str) -> String {
+    std::path::Path::new(file_name)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| format!(".{extension}"))
+        .unwrap_or_default()
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct UploadResponse {
+    pub uri: String,
+    pub cid: String,
+}
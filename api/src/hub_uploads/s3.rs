@@ -0,0 +1,186 @@
+use std::path::Path;
+
+use hmac::{Hmac, Mac};
+use hub_core::{anyhow::Result, chrono::Utc, prelude::*, tokio};
+use reqwest::Body;
+use sha2::{Digest, Sha256};
+
+use super::{content_hash, content_hash_file, extension, MetadataStorage, UploadResponse};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Stores uploads in an S3-compatible bucket under keys named by their content hash.
+/// Requests use path-style urls and are signed with AWS Signature Version 4.
+#[derive(Debug, Clone)]
+pub struct S3 {
+    http: reqwest::Client,
+    endpoint: Url,
+    bucket: String,
+    region: String,
+    access_key_id: String,
+    secret_access_key: String,
+    prefix: Option<String>,
+    public_url: String,
+}
+
+impl S3 {
+    /// Returns the S3 client
+    ///
+    /// # Errors
+    /// if url parsing fails
+    pub fn new(
+        endpoint: String,
+        bucket: String,
+        region: String,
+        access_key_id: String,
+        secret_access_key: String,
+        prefix: Option<String>,
+        public_url: Option<String>,
+    ) -> Result<Self> {
+        let endpoint = Url::parse(&endpoint).context("failed to parse s3 endpoint")?;
+        let public_url = public_url.map_or_else(
+            || format!("{}/{bucket}", endpoint.as_str().trim_end_matches('/')),
+            |url| url.trim_end_matches('/').to_string(),
+        );
+        let prefix = prefix
+            .map(|prefix| prefix.trim_matches('/').to_string())
+            .filter(|prefix| !prefix.is_empty());
+
+        Ok(Self {
+            http: reqwest::Client::new(),
+            endpoint,
+            bucket,
+            region,
+            access_key_id,
+            secret_access_key,
+            prefix,
+            public_url,
+        })
+    }
+
+    fn key(&self, name: &str) -> String {
+        match &self.prefix {
+            Some(prefix) => format!("{prefix}/{name}"),
+            None => name.to_string(),
+        }
+    }
+
+    /// Puts the body under a key named by its content hash
+    async fn put(
+        &self,
+        file_name: &str,
+        content_type: &str,
+        cid: String,
+        len: u64,
+        body: Body,
+    ) -> Result<UploadResponse> {
+        let key = self.key(&format!("{cid}{}", extension(file_name)));
+
+        let mut url = self.endpoint.clone();
+        url.set_path(&format!(
+            "{}/{}/{key}",
+            url.path().trim_end_matches('/'),
+            self.bucket
+        ));
+
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{host}:{port}"),
+            (Some(host), None) => host.to_string(),
+            (None, _) => bail!("s3 endpoint has no host"),
+        };
+
+        let payload_hash = cid.clone();
+        let amz_date = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+        let authorization = self.authorization(&host, url.path(), &payload_hash, &amz_date);
+
+        self.http
+            .put(url)
+            .header("content-type", content_type)
+            .header("content-length", len)
+            .header("x-amz-content-sha256", payload_hash)
+            .header("x-amz-date", amz_date)
+            .header("authorization", authorization)
+            .body(body)
+            .send()
+            .await
+            .context("failed to send put request to s3")?
+            .error_for_status()
+            .context("s3 rejected the file")?;
+
+        Ok(UploadResponse {
+            uri: format!("{}/{key}", self.public_url),
+            cid,
+        })
+    }
+
+    /// Returns the `Authorization` header of a signed `PUT` request for the path.
+    fn authorization(&self, host: &str, path: &str, payload_hash: &str, amz_date: &str) -> String {
+        let date = &amz_date[..8];
+        let scope = format!("{date}/{}/s3/aws4_request", self.region);
+        let signed_headers = "host;x-amz-content-sha256;x-amz-date";
+
+        let canonical_request = format!(
+            "PUT\n{path}\n\nhost:{host}\nx-amz-content-sha256:{payload_hash}\nx-amz-date:{amz_date}\n\n{signed_headers}\n{payload_hash}"
+        );
+
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{amz_date}\n{scope}\n{}",
+            hex::encode(Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let signing_key = [self.region.as_str(), "s3", "aws4_request"].iter().fold(
+            hmac(
+                format!("AWS4{}", self.secret_access_key).as_bytes(),
+                date.as_bytes(),
+            ),
+            |key, part| hmac(&key, part.as_bytes()),
+        );
+
+        let signature = hex::encode(hmac(&signing_key, string_to_sign.as_bytes()));
+
+        format!(
+            "AWS4-HMAC-SHA256 Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}",
+            self.access_key_id
+        )
+    }
+}
+
+fn hmac(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = HmacSha256::new_from_slice(key).expect("hmac accepts keys of any length");
+    mac.update(data);
+
+    mac.finalize().into_bytes().to_vec()
+}
+
+#[async_trait::async_trait]
+impl MetadataStorage for S3 {
+    async fn upload_file(
+        &self,
+        file_name: &str,
+        content_type: &str,
+        contents: Vec<u8>,
+    ) -> Result<UploadResponse> {
+        let cid = content_hash(&contents);
+        let len = u64::try_from(contents.len())?;
+
+        self.put(file_name, content_type, cid, len, contents.into())
+            .await
+    }
+
+    async fn upload_path(
+        &self,
+        file_name: &str,
+        content_type: &str,
+        path: &Path,
+    ) -> Result<UploadResponse> {
+        let (cid, len) = content_hash_file(path).await?;
+        let file = tokio::fs::File::open(path).await?;
+
+        self.put(file_name, content_type, cid, len, file.into())
+            .await
+    }
+
+    fn location(&self) -> String {
+        format!("s3:{}/{}", self.public_url, self.key(""))
+    }
+}
@@ -13,7 +13,10 @@ pub mod hub_uploads;
 pub mod metrics;
 pub mod mutations;
 pub mod objects;
+pub mod outbound;
 pub mod queries;
+pub mod readiness;
+pub mod webhooks;
 
 use async_graphql::{
     dataloader::DataLoader,
@@ -23,13 +26,18 @@ use async_graphql::{
 use background_worker::job_queue::JobQueue;
 use blockchains::{polygon::Polygon, solana::Solana};
 use dataloaders::{
-    CollectionDropLoader, CollectionLoader, CollectionMintHistoriesLoader, CollectionMintLoader,
-    CollectionMintMintHistoryLoader, CollectionMintTransfersLoader, CollectionMintsLoader,
-    CollectionMintsOwnerLoader, CollectionSupplyLoader, CollectionTotalMintsLoader, CreatorsLoader,
-    DropLoader, DropMintHistoryLoader, HoldersLoader, MetadataJsonAttributesLoader,
-    MetadataJsonLoader, MintCreatorsLoader, MinterMintHistoryLoader, ProjectCollectionLoader,
-    ProjectCollectionsLoader, ProjectDropsLoader, QueuedMintsLoader, SwitchCollectionHistoryLoader,
-    UpdateMintHistoryLoader,
+    CollectionAnalyticsLoader, CollectionDropLoader, CollectionExportLoader, CollectionLoader,
+    CollectionMintHistoriesLoader, CollectionMintLoader, CollectionMintMintHistoryLoader,
+    CollectionMintRarityLoader, CollectionMintTransfersLoader, CollectionMintUpdateErrorsLoader,
+    CollectionMintUpdateLoader, CollectionMintsLoader, CollectionSupplyLoader,
+    CollectionTotalMintsLoader, CollectionTraitsLoader, CreatorsLoader, DropLoader,
+    DropManifestErrorsLoader, DropManifestLoader, DropMintHistoryLoader, HolderMintsLoader,
+    HolderSnapshotHoldersLoader, HolderSnapshotLoader, HoldersLoader, ImportLoader, Instrumented,
+    MetadataJsonAttributesLoader, MetadataJsonLoader, MetadataJsonVersionsLoader,
+    MintCreatorsLoader, MinterMintHistoryLoader, OwnedCollectionsLoader, OwnedMintsLoader,
+    PendingTransfersLoader, ProjectCollectionLoader, ProjectCollectionsLoader, ProjectDropsLoader,
+    ProjectUsageLoader, QueuedMintsLoader, SwitchCollectionHistoryLoader, TransferBatchLoader,
+    TransferLoader, UpdateMintHistoryLoader, WebhookSubscriptionsLoader,
 };
 use db::Connection;
 use hub_core::{
@@ -43,11 +51,12 @@ use hub_core::{
     tokio,
     uuid::Uuid,
 };
-use metrics::Metrics;
+use metrics::{Metrics, MutationMetrics};
 use mutations::Mutation;
 use poem::{async_trait, FromRequest, Request, RequestBody};
 use queries::Query;
 use redis::Client as Redis;
+use webhooks::Webhooks;
 
 #[allow(clippy::pedantic)]
 pub mod proto {
@@ -118,6 +127,12 @@ pub struct Args {
     #[command(flatten)]
     pub hub_uploads: hub_uploads::HubUploadArgs,
 
+    #[command(flatten)]
+    pub webhooks: webhooks::WebhookArgs,
+
+    #[command(flatten)]
+    pub readiness: readiness::ReadinessArgs,
+
     #[arg(long, env)]
     pub redis_url: String,
 
@@ -250,6 +265,8 @@ pub struct AppState {
     pub asset_proxy: AssetProxy,
     pub metadata_json_upload_job_queue: JobQueue,
     pub redis: Redis,
+    pub webhooks: Webhooks,
+    pub metrics: Metrics,
 }
 
 impl AppState {
@@ -265,6 +282,8 @@ impl AppState {
         asset_proxy: AssetProxy,
         metadata_json_upload_job_queue: JobQueue,
         redis: Redis,
+        webhooks: Webhooks,
+        metrics: Metrics,
     ) -> Self {
         Self {
             schema,
@@ -276,6 +295,8 @@ impl AppState {
             asset_proxy,
             metadata_json_upload_job_queue,
             redis,
+            webhooks,
+            metrics,
         }
     }
 }
@@ -286,30 +307,50 @@ pub struct AppContext {
     organization_id: OrganizationId,
     balance: Balance,
     redis: Redis,
-    project_drops_loader: DataLoader<ProjectDropsLoader>,
-    project_collections_loader: DataLoader<ProjectCollectionsLoader>,
-    project_collection_loader: DataLoader<ProjectCollectionLoader>,
-    collection_loader: DataLoader<CollectionLoader>,
-    metadata_json_loader: DataLoader<MetadataJsonLoader>,
-    metadata_json_attributes_loader: DataLoader<MetadataJsonAttributesLoader>,
-    collection_mints_loader: DataLoader<CollectionMintsLoader>,
-    single_collection_mint_loader: DataLoader<CollectionMintLoader>,
-    collection_mints_owner_loader: DataLoader<CollectionMintsOwnerLoader>,
-    collection_drop_loader: DataLoader<CollectionDropLoader>,
-    drop_loader: DataLoader<DropLoader>,
-    creators_loader: DataLoader<CreatorsLoader>,
-    holders_loader: DataLoader<HoldersLoader>,
-    collection_mint_history_loader: DataLoader<CollectionMintHistoriesLoader>,
-    drop_mint_history_loader: DataLoader<DropMintHistoryLoader>,
-    minter_mint_history_loader: DataLoader<MinterMintHistoryLoader>,
-    update_mint_history_loader: DataLoader<UpdateMintHistoryLoader>,
-    mint_creators_loader: DataLoader<MintCreatorsLoader>,
-    collection_mint_mint_history_loader: DataLoader<CollectionMintMintHistoryLoader>,
-    collection_mint_transfers_loader: DataLoader<CollectionMintTransfersLoader>,
-    switch_collection_history_loader: DataLoader<SwitchCollectionHistoryLoader>,
-    queued_mints_loader: DataLoader<QueuedMintsLoader>,
-    collection_total_mints_loader: DataLoader<CollectionTotalMintsLoader>,
-    collection_supply_loader: DataLoader<CollectionSupplyLoader>,
+    project_drops_loader: DataLoader<Instrumented<ProjectDropsLoader>>,
+    project_collections_loader: DataLoader<Instrumented<ProjectCollectionsLoader>>,
+    project_collection_loader: DataLoader<Instrumented<ProjectCollectionLoader>>,
+    collection_loader: DataLoader<Instrumented<CollectionLoader>>,
+    metadata_json_loader: DataLoader<Instrumented<MetadataJsonLoader>>,
+    metadata_json_attributes_loader: DataLoader<Instrumented<MetadataJsonAttributesLoader>>,
+    collection_mints_loader: DataLoader<Instrumented<CollectionMintsLoader>>,
+    single_collection_mint_loader: DataLoader<Instrumented<CollectionMintLoader>>,
+    collection_drop_loader: DataLoader<Instrumented<CollectionDropLoader>>,
+    drop_loader: DataLoader<Instrumented<DropLoader>>,
+    creators_loader: DataLoader<Instrumented<CreatorsLoader>>,
+    holders_loader: DataLoader<Instrumented<HoldersLoader>>,
+    holder_mints_loader: DataLoader<Instrumented<HolderMintsLoader>>,
+    collection_mint_history_loader: DataLoader<Instrumented<CollectionMintHistoriesLoader>>,
+    drop_mint_history_loader: DataLoader<Instrumented<DropMintHistoryLoader>>,
+    minter_mint_history_loader: DataLoader<Instrumented<MinterMintHistoryLoader>>,
+    update_mint_history_loader: DataLoader<Instrumented<UpdateMintHistoryLoader>>,
+    mint_creators_loader: DataLoader<Instrumented<MintCreatorsLoader>>,
+    collection_mint_mint_history_loader: DataLoader<Instrumented<CollectionMintMintHistoryLoader>>,
+    collection_mint_transfers_loader: DataLoader<Instrumented<CollectionMintTransfersLoader>>,
+    switch_collection_history_loader: DataLoader<Instrumented<SwitchCollectionHistoryLoader>>,
+    queued_mints_loader: DataLoader<Instrumented<QueuedMintsLoader>>,
+    collection_total_mints_loader: DataLoader<Instrumented<CollectionTotalMintsLoader>>,
+    collection_supply_loader: DataLoader<Instrumented<CollectionSupplyLoader>>,
+    import_loader: DataLoader<Instrumented<ImportLoader>>,
+    collection_export_loader: DataLoader<Instrumented<CollectionExportLoader>>,
+    drop_manifest_loader: DataLoader<Instrumented<DropManifestLoader>>,
+    drop_manifest_errors_loader: DataLoader<Instrumented<DropManifestErrorsLoader>>,
+    collection_mint_update_loader: DataLoader<Instrumented<CollectionMintUpdateLoader>>,
+    collection_mint_update_errors_loader:
+        DataLoader<Instrumented<CollectionMintUpdateErrorsLoader>>,
+    metadata_json_versions_loader: DataLoader<Instrumented<MetadataJsonVersionsLoader>>,
+    transfer_batch_loader: DataLoader<Instrumented<TransferBatchLoader>>,
+    transfer_loader: DataLoader<Instrumented<TransferLoader>>,
+    pending_transfers_loader: DataLoader<Instrumented<PendingTransfersLoader>>,
+    holder_snapshot_loader: DataLoader<Instrumented<HolderSnapshotLoader>>,
+    holder_snapshot_holders_loader: DataLoader<Instrumented<HolderSnapshotHoldersLoader>>,
+    collection_analytics_loader: DataLoader<Instrumented<CollectionAnalyticsLoader>>,
+    project_usage_loader: DataLoader<Instrumented<ProjectUsageLoader>>,
+    collection_traits_loader: DataLoader<Instrumented<CollectionTraitsLoader>>,
+    collection_mint_rarity_loader: DataLoader<Instrumented<CollectionMintRarityLoader>>,
+    webhook_subscriptions_loader: DataLoader<Instrumented<WebhookSubscriptionsLoader>>,
+    owned_mints_loader: DataLoader<Instrumented<OwnedMintsLoader>>,
+    owned_collections_loader: DataLoader<Instrumented<OwnedCollectionsLoader>>,
 }
 
 impl AppContext {
@@ -321,54 +362,208 @@ impl AppContext {
         user_id: UserID,
         organization_id: OrganizationId,
         balance: Balance,
+        metrics: Metrics,
     ) -> Self {
-        let project_drops_loader =
-            DataLoader::new(ProjectDropsLoader::new(db.clone()), tokio::spawn);
-        let collection_loader = DataLoader::new(CollectionLoader::new(db.clone()), tokio::spawn);
-        let project_collections_loader =
-            DataLoader::new(ProjectCollectionsLoader::new(db.clone()), tokio::spawn);
-        let project_collection_loader =
-            DataLoader::new(ProjectCollectionLoader::new(db.clone()), tokio::spawn);
-        let metadata_json_loader =
-            DataLoader::new(MetadataJsonLoader::new(db.clone()), tokio::spawn);
-        let metadata_json_attributes_loader =
-            DataLoader::new(MetadataJsonAttributesLoader::new(db.clone()), tokio::spawn);
-        let collection_mints_loader =
-            DataLoader::new(CollectionMintsLoader::new(db.clone()), tokio::spawn);
-        let collection_mints_owner_loader =
-            DataLoader::new(CollectionMintsOwnerLoader::new(db.clone()), tokio::spawn);
-        let collection_drop_loader: DataLoader<_> =
-            DataLoader::new(CollectionDropLoader::new(db.clone()), tokio::spawn);
-        let drop_loader = DataLoader::new(DropLoader::new(db.clone()), tokio::spawn);
-        let creators_loader = DataLoader::new(CreatorsLoader::new(db.clone()), tokio::spawn);
-        let holders_loader = DataLoader::new(HoldersLoader::new(db.clone()), tokio::spawn);
-        let collection_mint_history_loader: DataLoader<CollectionMintHistoriesLoader> =
-            DataLoader::new(CollectionMintHistoriesLoader::new(db.clone()), tokio::spawn);
-        let drop_mint_history_loader =
-            DataLoader::new(DropMintHistoryLoader::new(db.clone()), tokio::spawn);
-        let single_collection_mint_loader =
-            DataLoader::new(CollectionMintLoader::new(db.clone()), tokio::spawn);
-        let minter_mint_history_loader =
-            DataLoader::new(MinterMintHistoryLoader::new(db.clone()), tokio::spawn);
-        let update_mint_history_loader =
-            DataLoader::new(UpdateMintHistoryLoader::new(db.clone()), tokio::spawn);
-        let mint_creators_loader =
-            DataLoader::new(MintCreatorsLoader::new(db.clone()), tokio::spawn);
+        let project_drops_loader = DataLoader::new(
+            Instrumented::new(ProjectDropsLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let collection_loader = DataLoader::new(
+            Instrumented::new(CollectionLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let project_collections_loader = DataLoader::new(
+            Instrumented::new(ProjectCollectionsLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let project_collection_loader = DataLoader::new(
+            Instrumented::new(ProjectCollectionLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let metadata_json_loader = DataLoader::new(
+            Instrumented::new(MetadataJsonLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let metadata_json_attributes_loader = DataLoader::new(
+            Instrumented::new(
+                MetadataJsonAttributesLoader::new(db.clone()),
+                metrics.clone(),
+            ),
+            tokio::spawn,
+        );
+        let collection_mints_loader = DataLoader::new(
+            Instrumented::new(CollectionMintsLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let collection_drop_loader = DataLoader::new(
+            Instrumented::new(CollectionDropLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let drop_loader = DataLoader::new(
+            Instrumented::new(DropLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let creators_loader = DataLoader::new(
+            Instrumented::new(CreatorsLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let holders_loader = DataLoader::new(
+            Instrumented::new(HoldersLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let holder_mints_loader = DataLoader::new(
+            Instrumented::new(HolderMintsLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let collection_mint_history_loader = DataLoader::new(
+            Instrumented::new(
+                CollectionMintHistoriesLoader::new(db.clone()),
+                metrics.clone(),
+            ),
+            tokio::spawn,
+        );
+        let drop_mint_history_loader = DataLoader::new(
+            Instrumented::new(DropMintHistoryLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let single_collection_mint_loader = DataLoader::new(
+            Instrumented::new(CollectionMintLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let minter_mint_history_loader = DataLoader::new(
+            Instrumented::new(MinterMintHistoryLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let update_mint_history_loader = DataLoader::new(
+            Instrumented::new(UpdateMintHistoryLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let mint_creators_loader = DataLoader::new(
+            Instrumented::new(MintCreatorsLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
         let collection_mint_mint_history_loader = DataLoader::new(
-            CollectionMintMintHistoryLoader::new(db.clone()),
+            Instrumented::new(
+                CollectionMintMintHistoryLoader::new(db.clone()),
+                metrics.clone(),
+            ),
+            tokio::spawn,
+        );
+        let collection_mint_transfers_loader = DataLoader::new(
+            Instrumented::new(
+                CollectionMintTransfersLoader::new(db.clone()),
+                metrics.clone(),
+            ),
+            tokio::spawn,
+        );
+        let switch_collection_history_loader = DataLoader::new(
+            Instrumented::new(
+                SwitchCollectionHistoryLoader::new(db.clone()),
+                metrics.clone(),
+            ),
+            tokio::spawn,
+        );
+        let queued_mints_loader = DataLoader::new(
+            Instrumented::new(QueuedMintsLoader::new(db.clone()), metrics.clone()),
             tokio::spawn,
         );
-        let collection_mint_transfers_loader =
-            DataLoader::new(CollectionMintTransfersLoader::new(db.clone()), tokio::spawn);
-        let switch_collection_history_loader =
-            DataLoader::new(SwitchCollectionHistoryLoader::new(db.clone()), tokio::spawn);
-        let queued_mints_loader = DataLoader::new(QueuedMintsLoader::new(db.clone()), tokio::spawn);
         let collection_total_mints_loader = DataLoader::new(
-            CollectionTotalMintsLoader::new(db.clone(), redis.clone()),
+            Instrumented::new(
+                CollectionTotalMintsLoader::new(db.clone(), redis.clone()),
+                metrics.clone(),
+            ),
             tokio::spawn,
         );
         let collection_supply_loader = DataLoader::new(
-            CollectionSupplyLoader::new(db.clone(), redis.clone()),
+            Instrumented::new(
+                CollectionSupplyLoader::new(db.clone(), redis.clone()),
+                metrics.clone(),
+            ),
+            tokio::spawn,
+        );
+        let import_loader = DataLoader::new(
+            Instrumented::new(ImportLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let collection_export_loader = DataLoader::new(
+            Instrumented::new(CollectionExportLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let drop_manifest_loader = DataLoader::new(
+            Instrumented::new(DropManifestLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let drop_manifest_errors_loader = DataLoader::new(
+            Instrumented::new(DropManifestErrorsLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let collection_mint_update_loader = DataLoader::new(
+            Instrumented::new(CollectionMintUpdateLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let collection_mint_update_errors_loader = DataLoader::new(
+            Instrumented::new(
+                CollectionMintUpdateErrorsLoader::new(db.clone()),
+                metrics.clone(),
+            ),
+            tokio::spawn,
+        );
+        let metadata_json_versions_loader = DataLoader::new(
+            Instrumented::new(MetadataJsonVersionsLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let transfer_batch_loader = DataLoader::new(
+            Instrumented::new(TransferBatchLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let transfer_loader = DataLoader::new(
+            Instrumented::new(TransferLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let pending_transfers_loader = DataLoader::new(
+            Instrumented::new(PendingTransfersLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let holder_snapshot_loader = DataLoader::new(
+            Instrumented::new(HolderSnapshotLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let holder_snapshot_holders_loader = DataLoader::new(
+            Instrumented::new(
+                HolderSnapshotHoldersLoader::new(db.clone()),
+                metrics.clone(),
+            ),
+            tokio::spawn,
+        );
+        let collection_analytics_loader = DataLoader::new(
+            Instrumented::new(
+                CollectionAnalyticsLoader::new(db.clone(), redis.clone()),
+                metrics.clone(),
+            ),
+            tokio::spawn,
+        );
+        let project_usage_loader = DataLoader::new(
+            Instrumented::new(ProjectUsageLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let collection_traits_loader = DataLoader::new(
+            Instrumented::new(CollectionTraitsLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let collection_mint_rarity_loader = DataLoader::new(
+            Instrumented::new(CollectionMintRarityLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let webhook_subscriptions_loader = DataLoader::new(
+            Instrumented::new(WebhookSubscriptionsLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let owned_mints_loader = DataLoader::new(
+            Instrumented::new(OwnedMintsLoader::new(db.clone()), metrics.clone()),
+            tokio::spawn,
+        );
+        let owned_collections_loader = DataLoader::new(
+            Instrumented::new(OwnedCollectionsLoader::new(db.clone()), metrics.clone()),
             tokio::spawn,
         );
 
@@ -386,11 +581,11 @@ impl AppContext {
             metadata_json_attributes_loader,
             collection_mints_loader,
             single_collection_mint_loader,
-            collection_mints_owner_loader,
             collection_drop_loader,
             drop_loader,
             creators_loader,
             holders_loader,
+            holder_mints_loader,
             collection_mint_history_loader,
             drop_mint_history_loader,
             minter_mint_history_loader,
@@ -402,16 +597,36 @@ impl AppContext {
             queued_mints_loader,
             collection_total_mints_loader,
             collection_supply_loader,
+            import_loader,
+            collection_export_loader,
+            drop_manifest_loader,
+            drop_manifest_errors_loader,
+            collection_mint_update_loader,
+            collection_mint_update_errors_loader,
+            metadata_json_versions_loader,
+            transfer_batch_loader,
+            transfer_loader,
+            pending_transfers_loader,
+            holder_snapshot_loader,
+            holder_snapshot_holders_loader,
+            collection_analytics_loader,
+            project_usage_loader,
+            collection_traits_loader,
+            collection_mint_rarity_loader,
+            webhook_subscriptions_loader,
+            owned_mints_loader,
+            owned_collections_loader,
         }
     }
 }
 
 /// Builds the GraphQL Schema, attaching the Database to the context
 #[must_use]
-pub fn build_schema() -> AppSchema {
+pub fn build_schema(metrics: Metrics) -> AppSchema {
     Schema::build(Query::default(), Mutation::default(), EmptySubscription)
         .extension(ApolloTracing)
         .extension(Logger)
+        .extension(MutationMetrics(metrics))
         .enable_federation()
         .finish()
 }
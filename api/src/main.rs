@@ -3,17 +3,24 @@
 use holaplex_hub_nfts::{
     background_worker::{
         job_queue::JobQueue,
-        tasks::{MetadataJsonUploadContext, MetadataJsonUploadTask},
+        tasks::{
+            CollectionExportTask, CollectionMintsUpdateContext, CollectionMintsUpdateTask,
+            CollectionRarityTask, DropManifestContext, DropManifestTask, MetadataJsonUploadContext,
+            MetadataJsonUploadTask, WebhookDeliveryTask,
+        },
         worker::Worker,
     },
     blockchains::{polygon::Polygon, solana::Solana},
     build_schema,
     db::Connection,
     events,
-    handlers::{graphql_handler, health, metrics_handler, playground},
+    handlers::{graphql_handler, health, metrics_handler, playground, ready},
     hub_uploads::HubUploadClient,
     metrics::Metrics,
-    proto, Actions, AppState, Args, Services, Subcommand,
+    proto,
+    readiness::{Readiness, ReadinessArgs},
+    webhooks::{WebhookArgs, Webhooks},
+    Actions, AppState, Args, Services, Subcommand,
 };
 use hub_core::{prelude::*, tokio};
 use poem::{get, listener::TcpListener, middleware::AddData, post, EndpointExt, Route, Server};
@@ -29,13 +36,23 @@ pub fn main() {
             port,
             db,
             hub_uploads,
+            webhooks,
+            readiness,
             redis_url,
             command,
         } = args;
 
         match command {
-            None => serve(common, port, db, hub_uploads, redis_url),
-            Some(Subcommand::RetryJobs) => retry_jobs(common, redis_url, db, hub_uploads),
+            None => serve(
+                common,
+                port,
+                db,
+                hub_uploads,
+                webhooks,
+                readiness,
+                redis_url,
+            ),
+            Some(Subcommand::RetryJobs) => retry_jobs(common, redis_url, db, hub_uploads, webhooks),
         }
     });
 }
@@ -45,15 +62,17 @@ fn serve(
     port: u16,
     db: holaplex_hub_nfts::db::DbArgs,
     hub_uploads: holaplex_hub_nfts::hub_uploads::HubUploadArgs,
+    webhooks: WebhookArgs,
+    readiness: ReadinessArgs,
     redis_url: String,
 ) -> Result<()> {
     common.rt.block_on(async move {
         let connection = Connection::new(db)
             .await
             .context("failed to get database connection")?;
-        let hub_uploads = HubUploadClient::new(hub_uploads)?;
-        let credits = common.credits_cfg.build::<Actions>().await?;
         let metrics = Metrics::new()?;
+        let hub_uploads = HubUploadClient::new(hub_uploads, metrics.clone())?;
+        let credits = common.credits_cfg.build::<Actions>().await?;
 
         let producer = common
             .producer_cfg
@@ -61,29 +80,76 @@ fn serve(
             .build::<proto::NftEvents>()
             .await?;
 
+        let redis_client = RedisClient::open(redis_url)?;
+        let job_queue = JobQueue::new(redis_client.clone(), connection.clone());
+        let webhooks = Webhooks::new(webhooks, job_queue.clone())?;
+        let readiness = Readiness::new(
+            readiness,
+            connection.clone(),
+            redis_client.clone(),
+            common.producer_cfg.clone(),
+            hub_uploads.clone(),
+        );
+
         let event_processor = events::Processor::new(
             connection.clone(),
             credits.clone(),
             producer.clone(),
             metrics.clone(),
+            webhooks.clone(),
         );
 
         let solana = Solana::new(producer.clone());
         let polygon = Polygon::new(producer.clone());
 
-        let redis_client = RedisClient::open(redis_url)?;
-
         let metadata_json_upload_task_context =
-            MetadataJsonUploadContext::new(hub_uploads, solana.clone(), polygon.clone());
+            MetadataJsonUploadContext::new(hub_uploads.clone(), solana.clone(), polygon.clone());
 
-        let job_queue = JobQueue::new(redis_client.clone(), connection.clone());
         let worker = Worker::<MetadataJsonUploadContext, MetadataJsonUploadTask>::new(
             job_queue.clone(),
             connection.clone(),
             metadata_json_upload_task_context,
+            metrics.clone(),
         );
+        let rarity_worker = Worker::<(), CollectionRarityTask>::new(
+            job_queue.clone(),
+            connection.clone(),
+            (),
+            metrics.clone(),
+        );
+        let export_worker = Worker::<HubUploadClient, CollectionExportTask>::new(
+            job_queue.clone(),
+            connection.clone(),
+            hub_uploads,
+            metrics.clone(),
+        );
+        let manifest_worker = Worker::<DropManifestContext, DropManifestTask>::new(
+            job_queue.clone(),
+            connection.clone(),
+            DropManifestContext::new(job_queue.clone(), redis_client.clone())?,
+            metrics.clone(),
+        );
+        let bulk_update_worker =
+            Worker::<CollectionMintsUpdateContext, CollectionMintsUpdateTask>::new(
+                job_queue.clone(),
+                connection.clone(),
+                CollectionMintsUpdateContext::new(
+                    job_queue.clone(),
+                    credits.clone(),
+                    metrics.clone(),
+                ),
+                metrics.clone(),
+            );
+        let webhook_worker = Worker::<Webhooks, WebhookDeliveryTask>::new(
+            job_queue.clone(),
+            connection.clone(),
+            webhooks.clone(),
+            metrics.clone(),
+        );
+        let webhook_poller = webhooks.clone();
+        let webhook_poller_connection = connection.clone();
 
-        let schema = build_schema();
+        let schema = build_schema(metrics.clone());
 
         let state = AppState::new(
             schema,
@@ -95,11 +161,19 @@ fn serve(
             common.asset_proxy,
             job_queue.clone(),
             redis_client,
+            webhooks,
+            metrics.clone(),
         );
 
         let cons = common.consumer_cfg.build::<Services>().await?;
 
         tokio::spawn(async move { worker.start().await });
+        tokio::spawn(async move { rarity_worker.start().await });
+        tokio::spawn(async move { export_worker.start().await });
+        tokio::spawn(async move { manifest_worker.start().await });
+        tokio::spawn(async move { bulk_update_worker.start().await });
+        tokio::spawn(async move { webhook_worker.start().await });
+        tokio::spawn(async move { webhook_poller.poll(&webhook_poller_connection).await });
 
         tokio::spawn(async move {
             cons.consume(
@@ -122,6 +196,7 @@ fn serve(
                     )
                     .at("/playground", get(playground))
                     .at("/health", get(health))
+                    .at("/ready", get(ready).with(AddData::new(readiness)))
                     .at("/metrics", get(metrics_handler).with(AddData::new(metrics))),
             )
             .await
@@ -134,13 +209,16 @@ fn retry_jobs(
     redis_url: String,
     db: holaplex_hub_nfts::db::DbArgs,
     hub_uploads: holaplex_hub_nfts::hub_uploads::HubUploadArgs,
+    webhooks: WebhookArgs,
 ) -> Result<()> {
     common.rt.block_on(async move {
         let connection = Connection::new(db)
             .await
             .context("failed to get database connection")?;
         let redis_client = RedisClient::open(redis_url)?;
-        let hub_uploads = HubUploadClient::new(hub_uploads)?;
+        let metrics = Metrics::new()?;
+        let hub_uploads = HubUploadClient::new(hub_uploads, metrics.clone())?;
+        let credits = common.credits_cfg.build::<Actions>().await?;
 
         let producer = common
             .producer_cfg
@@ -152,16 +230,58 @@ fn retry_jobs(
         let polygon = Polygon::new(producer.clone());
 
         let metadata_json_upload_task_context =
-            MetadataJsonUploadContext::new(hub_uploads, solana.clone(), polygon.clone());
+            MetadataJsonUploadContext::new(hub_uploads.clone(), solana.clone(), polygon.clone());
 
-        let job_queue = JobQueue::new(redis_client, connection.clone());
+        let job_queue = JobQueue::new(redis_client.clone(), connection.clone());
+        let webhooks = Webhooks::new(webhooks, job_queue.clone())?;
         let worker = Worker::<MetadataJsonUploadContext, MetadataJsonUploadTask>::new(
             job_queue.clone(),
             connection.clone(),
             metadata_json_upload_task_context,
+            metrics.clone(),
+        );
+        let rarity_worker = Worker::<(), CollectionRarityTask>::new(
+            job_queue.clone(),
+            connection.clone(),
+            (),
+            metrics.clone(),
+        );
+        let export_worker = Worker::<HubUploadClient, CollectionExportTask>::new(
+            job_queue.clone(),
+            connection.clone(),
+            hub_uploads,
+            metrics.clone(),
+        );
+        let manifest_worker = Worker::<DropManifestContext, DropManifestTask>::new(
+            job_queue.clone(),
+            connection.clone(),
+            DropManifestContext::new(job_queue.clone(), redis_client.clone())?,
+            metrics.clone(),
+        );
+        let bulk_update_worker =
+            Worker::<CollectionMintsUpdateContext, CollectionMintsUpdateTask>::new(
+                job_queue.clone(),
+                connection.clone(),
+                CollectionMintsUpdateContext::new(
+                    job_queue.clone(),
+                    credits.clone(),
+                    metrics.clone(),
+                ),
+                metrics.clone(),
+            );
+        let webhook_worker = Worker::<Webhooks, WebhookDeliveryTask>::new(
+            job_queue.clone(),
+            connection.clone(),
+            webhooks,
+            metrics,
         );
 
         worker.retry().await?;
+        rarity_worker.retry().await?;
+        export_worker.retry().await?;
+        manifest_worker.retry().await?;
+        bulk_update_worker.retry().await?;
+        webhook_worker.retry().await?;
 
         Ok(())
     })
@@ -1,19 +1,55 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
+
+use async_graphql::{
+    extensions::{Extension, ExtensionContext, ExtensionFactory, NextResolve, ResolveInfo},
+    ServerResult, Value,
+};
 #[allow(clippy::wildcard_imports)]
 use hub_core::{
     anyhow::{anyhow, Result},
     metrics::*,
 };
 
+use crate::{entities::sea_orm_active_enums::Blockchain, Actions};
+
+/// The label value used when an operation is not tied to a blockchain
+const NO_BLOCKCHAIN: &str = "none";
+
 #[derive(Clone)]
 pub struct Metrics {
     pub registry: Registry,
     pub provider: MeterProvider,
     pub mint_duration_ms_bucket: Histogram<i64>,
+    pub mutations_counter: Counter<u64>,
+    pub mutation_duration_ms_bucket: Histogram<i64>,
+    pub credits_deductions_counter: Counter<u64>,
+    pub events_processed_counter: Counter<u64>,
+    pub events_failed_counter: Counter<u64>,
+    pub event_duration_ms_bucket: Histogram<i64>,
+    pub job_duration_ms_bucket: Histogram<i64>,
+    pub jobs_failed_counter: Counter<u64>,
+    pub upload_duration_ms_bucket: Histogram<i64>,
+    pub upload_retries_counter: Counter<u64>,
+    pub dataloader_batch_size_bucket: Histogram<u64>,
+    /// The last observed length of each job queue, keyed by task name and queue
+    job_queue_depths: Arc<RwLock<HashMap<(&'static str, &'static str), i64>>>,
+}
+
+impl fmt::Debug for Metrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Metrics").finish_non_exhaustive()
+    }
 }
 
 impl Metrics {
     /// Res
     /// # Errors
+    #[allow(clippy::too_many_lines)]
     pub fn new() -> Result<Self> {
         let registry = Registry::new();
         let exporter = hub_core::metrics::exporter()
@@ -38,10 +74,261 @@ impl Metrics {
             .with_description("Mint duration time in milliseconds.")
             .init();
 
+        let mutations_counter = meter
+            .u64_counter("mutations")
+            .with_description("GraphQL mutations executed by name and outcome.")
+            .init();
+
+        let mutation_duration_ms_bucket = meter
+            .i64_histogram("mutation.time")
+            .with_unit(Unit::new("ms"))
+            .with_description("GraphQL mutation duration time in milliseconds.")
+            .init();
+
+        let credits_deductions_counter = meter
+            .u64_counter("credits.deductions")
+            .with_description("Pending credit deductions submitted by action and outcome.")
+            .init();
+
+        let events_processed_counter = meter
+            .u64_counter("events.processed")
+            .with_description("Kafka events processed by topic and variant.")
+            .init();
+
+        let events_failed_counter = meter
+            .u64_counter("events.failed")
+            .with_description("Kafka events that failed to process by topic and variant.")
+            .init();
+
+        let event_duration_ms_bucket = meter
+            .i64_histogram("event.time")
+            .with_unit(Unit::new("ms"))
+            .with_description("Kafka event processing time in milliseconds.")
+            .init();
+
+        let job_duration_ms_bucket = meter
+            .i64_histogram("job.time")
+            .with_unit(Unit::new("ms"))
+            .with_description("Background job duration time in milliseconds.")
+            .init();
+
+        let jobs_failed_counter = meter
+            .u64_counter("jobs.failed")
+            .with_description("Background jobs that failed by task name.")
+            .init();
+
+        let upload_duration_ms_bucket = meter
+            .i64_histogram("upload.time")
+            .with_unit(Unit::new("ms"))
+            .with_description(
+                "Metadata json upload duration time in milliseconds, including retries.",
+            )
+            .init();
+
+        let upload_retries_counter = meter
+            .u64_counter("upload.retries")
+            .with_description("Metadata json upload attempts that were retried.")
+            .init();
+
+        let dataloader_batch_size_bucket = meter
+            .u64_histogram("dataloader.batch_size")
+            .with_description("Number of keys loaded per dataloader batch.")
+            .init();
+
+        let job_queue_depths: Arc<RwLock<HashMap<(&'static str, &'static str), i64>>> =
+            Arc::default();
+
+        let job_queue_depth_gauge = meter
+            .i64_observable_gauge("job_queue.depth")
+            .with_description("Number of jobs waiting in the queue of each background task.")
+            .init();
+
+        meter
+            .register_callback(&[job_queue_depth_gauge.as_any()], {
+                let job_queue_depths = job_queue_depths.clone();
+
+                move |observer| {
+                    let Ok(depths) = job_queue_depths.read() else {
+                        return;
+                    };
+
+                    for ((task, queue), depth) in depths.iter() {
+                        observer.observe_i64(&job_queue_depth_gauge, *depth, &[
+                            KeyValue::new("task", *task),
+                            KeyValue::new("queue", *queue),
+                        ]);
+                    }
+                }
+            })
+            .map_err(|e| anyhow!("Failed to register job queue depth callback: {}", e))?;
+
         Ok(Self {
             registry,
             provider,
             mint_duration_ms_bucket,
+            mutations_counter,
+            mutation_duration_ms_bucket,
+            credits_deductions_counter,
+            events_processed_counter,
+            events_failed_counter,
+            event_duration_ms_bucket,
+            job_duration_ms_bucket,
+            jobs_failed_counter,
+            upload_duration_ms_bucket,
+            upload_retries_counter,
+            dataloader_batch_size_bucket,
+            job_queue_depths,
         })
     }
+
+    /// Records a mutation resolved by the graphql schema
+    pub fn record_mutation(&self, name: &str, success: bool, elapsed: Duration) {
+        let attributes = [
+            KeyValue::new("name", name.to_string()),
+            KeyValue::new("outcome", outcome(success)),
+            KeyValue::new("blockchain", NO_BLOCKCHAIN),
+        ];
+
+        self.mutations_counter.add(1, &attributes);
+        self.mutation_duration_ms_bucket
+            .record(millis(elapsed), &attributes);
+    }
+
+    /// Records the submission of a pending credits deduction
+    pub fn record_credits_deduction(&self, action: Actions, blockchain: Blockchain, success: bool) {
+        self.credits_deductions_counter.add(1, &[
+            KeyValue::new("action", action.as_ref().to_string()),
+            KeyValue::new("outcome", outcome(success)),
+            KeyValue::new("blockchain", blockchain_label(Some(blockchain))),
+        ]);
+    }
+
+    /// Records a kafka event handled by the event processor
+    pub fn record_event(
+        &self,
+        topic: &'static str,
+        variant: &'static str,
+        blockchain: Option<Blockchain>,
+        success: bool,
+        elapsed: Duration,
+    ) {
+        let attributes = [
+            KeyValue::new("topic", topic),
+            KeyValue::new("variant", variant),
+            KeyValue::new("blockchain", blockchain_label(blockchain)),
+        ];
+
+        self.events_processed_counter.add(1, &attributes);
+        self.event_duration_ms_bucket
+            .record(millis(elapsed), &attributes);
+
+        if !success {
+            self.events_failed_counter.add(1, &attributes);
+        }
+    }
+
+    /// Records a background job processed by a worker
+    pub fn record_job(&self, task: &'static str, success: bool, elapsed: Duration) {
+        self.job_duration_ms_bucket.record(millis(elapsed), &[
+            KeyValue::new("task", task),
+            KeyValue::new("outcome", outcome(success)),
+            KeyValue::new("blockchain", NO_BLOCKCHAIN),
+        ]);
+
+        if !success {
+            self.jobs_failed_counter.add(1, &[
+                KeyValue::new("task", task),
+                KeyValue::new("blockchain", NO_BLOCKCHAIN),
+            ]);
+        }
+    }
+
+    /// Sets the last observed length of the queue of a background task
+    pub fn set_job_queue_depth(&self, task: &'static str, queue: &'static str, depth: i64) {
+        if let Ok(mut depths) = self.job_queue_depths.write() {
+            depths.insert((task, queue), depth);
+        }
+    }
+
+    /// Records an upload of a metadata json
+    pub fn record_upload(&self, blockchain: Blockchain, success: bool, elapsed: Duration) {
+        self.upload_duration_ms_bucket.record(millis(elapsed), &[
+            KeyValue::new("outcome", outcome(success)),
+            KeyValue::new("blockchain", blockchain_label(Some(blockchain))),
+        ]);
+    }
+
+    /// Records a retried attempt to upload a metadata json
+    pub fn record_upload_retry(&self, blockchain: Blockchain) {
+        self.upload_retries_counter.add(1, &[KeyValue::new(
+            "blockchain",
+            blockchain_label(Some(blockchain)),
+        )]);
+    }
+
+    /// Records the number of keys loaded in a single dataloader batch
+    pub fn record_dataloader_batch(&self, loader: &'static str, size: usize) {
+        self.dataloader_batch_size_bucket.record(size as u64, &[
+            KeyValue::new("loader", loader),
+            KeyValue::new("blockchain", NO_BLOCKCHAIN),
+        ]);
+    }
+}
+
+fn outcome(success: bool) -> &'static str {
+    if success {
+        "success"
+    } else {
+        "failure"
+    }
+}
+
+fn millis(elapsed: Duration) -> i64 {
+    i64::try_from(elapsed.as_millis()).unwrap_or(i64::MAX)
+}
+
+/// Schema extension recording the outcome and duration of every mutation
+pub struct MutationMetrics(pub Metrics);
+
+impl ExtensionFactory for MutationMetrics {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(MutationMetricsExtension(self.0.clone()))
+    }
+}
+
+struct MutationMetricsExtension(Metrics);
+
+#[async_trait::async_trait]
+impl Extension for MutationMetricsExtension {
+    async fn resolve(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        info: ResolveInfo<'_>,
+        next: NextResolve<'_>,
+    ) -> ServerResult<Option<Value>> {
+        if info.parent_type != "Mutation" {
+            return next.run(ctx, info).await;
+        }
+
+        let name = info.name.to_string();
+        let started_at = Instant::now();
+
+        let result = next.run(ctx, info).await;
+
+        self.0
+            .record_mutation(&name, result.is_ok(), started_at.elapsed());
+
+        result
+    }
+}
+
+/// Returns the value of the `blockchain` label for an operation
+#[must_use]
+pub fn blockchain_label(blockchain: Option<Blockchain>) -> &'static str {
+    match blockchain {
+        Some(Blockchain::Ethereum) => "ethereum",
+        Some(Blockchain::Polygon) => "polygon",
+        Some(Blockchain::Solana) => "solana",
+        None => NO_BLOCKCHAIN,
+    }
 }
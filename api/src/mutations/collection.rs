@@ -15,19 +15,29 @@ use crate::{
     background_worker::{
         job_queue::JobQueue,
         tasks::{
-            MetadataJsonUploadCaller, MetadataJsonUploadCreateCollection,
-            MetadataJsonUploadPatchCollection, MetadataJsonUploadTask,
+            CollectionMintsUpdateTask, MetadataJsonUploadCaller,
+            MetadataJsonUploadCreateCollection, MetadataJsonUploadPatchCollection,
+            MetadataJsonUploadTask,
         },
     },
     blockchains::{solana::Solana, CollectionEvent},
     entities::{
-        collection_creators, collection_mints, collections, metadata_jsons,
-        prelude::{CollectionCreators, CollectionMints, Collections, Drops, MetadataJsons},
-        project_wallets,
-        sea_orm_active_enums::{Blockchain, Blockchain as BlockchainEnum, CreationStatus},
+        collection_creators, collection_mint_updates, collections, imports, metadata_jsons,
+        prelude::{
+            CollectionCreators, CollectionMints, Collections, Drops, Imports, MetadataJsons,
+        },
+        project_wallets, retry_histories,
+        sea_orm_active_enums::{
+            AttributeValueType, Blockchain, Blockchain as BlockchainEnum, CreationStatus,
+        },
         switch_collection_histories,
     },
-    objects::{Collection as CollectionObject, CollectionMint, Creator, MetadataJsonInput},
+    events::complete_import,
+    metrics::Metrics,
+    objects::{
+        AttributeValue, Collection as CollectionObject, CollectionMint, Creator, MetadataJsonInput,
+        MetadataJsonPatchInput, MetadataStandard, NUMERIC_DISPLAY_TYPES,
+    },
     proto::{
         nft_events::Event as NftEvent, CollectionCreation, CollectionImport,
         CreationStatus as NftCreationStatus, Creator as ProtoCreator, MasterEdition,
@@ -66,6 +76,7 @@ impl Mutation {
 
         let conn = db.get();
         let credits = ctx.data::<CreditsClient<Actions>>()?;
+        let metrics = ctx.data::<Metrics>()?;
         let nfts_producer = ctx.data::<Producer<NftEvents>>()?;
         let metadata_json_upload_job_queue = ctx.data::<JobQueue>()?;
 
@@ -77,7 +88,7 @@ impl Mutation {
             validate_solana_creator_verification(&owner_address, &input.creators)?;
         }
 
-        let TransactionId(credits_deduction_id) = credits
+        let deduction = credits
             .submit_pending_deduction(
                 org_id,
                 user_id,
@@ -85,7 +96,14 @@ impl Mutation {
                 input.blockchain.into(),
                 balance,
             )
-            .await?;
+            .await;
+        metrics.record_credits_deduction(
+            Actions::CreateCollection,
+            input.blockchain,
+            deduction.is_ok(),
+        );
+
+        let TransactionId(credits_deduction_id) = deduction?;
 
         let tx = conn.begin().await?;
 
@@ -172,6 +190,7 @@ impl Mutation {
 
         let conn = db.get();
         let credits = ctx.data::<CreditsClient<Actions>>()?;
+        let metrics = ctx.data::<Metrics>()?;
         let solana = ctx.data::<Solana>()?;
 
         let collection = Collections::find()
@@ -199,7 +218,7 @@ impl Mutation {
 
         let owner_address = fetch_owner(conn, collection.project_id, collection.blockchain).await?;
 
-        let TransactionId(_) = credits
+        let deduction = credits
             .submit_pending_deduction(
                 org_id,
                 user_id,
@@ -207,7 +226,25 @@ impl Mutation {
                 collection.blockchain.into(),
                 balance,
             )
-            .await?;
+            .await;
+        metrics.record_credits_deduction(
+            Actions::RetryCollection,
+            collection.blockchain,
+            deduction.is_ok(),
+        );
+
+        let TransactionId(credits_deduction_id) = deduction?;
+
+        let retry_history_am = retry_histories::ActiveModel {
+            project_id: Set(collection.project_id),
+            collection_id: Set(collection.id),
+            action: Set(Actions::RetryCollection.as_ref().to_string()),
+            credits_deduction_id: Set(credits_deduction_id),
+            created_by: Set(user_id),
+            ..Default::default()
+        };
+
+        retry_history_am.insert(conn).await?;
 
         let event_key = NftEventKey {
             id: collection.id.to_string(),
@@ -250,6 +287,8 @@ impl Mutation {
     }
 
     /// This mutation imports a Solana collection. See the [guide](https://docs.holaplex.com/hub/Guides/import-collection) for importing instructions.
+    /// Re-importing a collection updates it in place. Existing mints keep their IDs and histories and mints no longer in the collection are marked as removed.
+    /// A collection cannot be imported again while an import of it is pending.
     pub async fn import_solana_collection(
         &self,
         ctx: &Context<'_>,
@@ -272,36 +311,28 @@ impl Mutation {
             .one(conn)
             .await?;
 
-        if let Some(collection) = collection.clone() {
-            let txn = conn.begin().await?;
-
-            let mints = CollectionMints::find()
-                .filter(collection_mints::Column::CollectionId.eq(collection.id))
-                .all(&txn)
-                .await?;
-
-            let collection_json_response =
-                MetadataJsons::find_by_id(collection.id).one(&txn).await?;
-
-            if let Some(collection_json) = collection_json_response {
-                collection_json.delete(&txn).await?;
-            }
-
-            let mint_ids = mints.iter().map(|m| m.id).collect::<Vec<_>>();
+        let collection_id = collection.map_or_else(Uuid::new_v4, |c| c.id);
 
-            let mint_jsons = MetadataJsons::find()
-                .filter(metadata_jsons::Column::Id.is_in(mint_ids))
-                .all(&txn)
-                .await?;
-
-            for json in mint_jsons {
-                json.delete(&txn).await?;
-            }
+        if Imports::find_pending_by_collection(collection_id)
+            .one(conn)
+            .await?
+            .is_some()
+        {
+            return Err(Error::new(
+                "An import of this collection is already pending",
+            ));
+        }
 
-            collection.delete(&txn).await?;
+        let import = imports::ActiveModel {
+            project_id: Set(input.project),
+            collection_id: Set(collection_id),
+            collection_address: Set(input.collection.clone()),
+            status: Set(CreationStatus::Pending),
+            created_by: Set(user_id),
+            ..Default::default()
+        };
 
-            txn.commit().await?;
-        }
+        let import = import.insert(conn).await?;
 
         nfts_producer
             .send(
@@ -313,7 +344,7 @@ impl Mutation {
                     )),
                 }),
                 Some(&NftEventKey {
-                    id: collection.map_or(Uuid::new_v4(), |c| c.id).to_string(),
+                    id: collection_id.to_string(),
                     project_id: input.project.to_string(),
                     user_id: user_id.to_string(),
                 }),
@@ -322,9 +353,55 @@ impl Mutation {
 
         Ok(ImportCollectionPayload {
             status: CreationStatus::Pending,
+            import,
         })
     }
 
+    /// Completes an import of a collection that does not report its size. Imports of sized collections complete on their own once every mint has been indexed.
+    /// Mints of the collection that were not indexed by the import are marked as removed.
+    /// # Errors
+    /// If the import is not found, is no longer pending or expects a known number of mints, the mutation will result in an error.
+    pub async fn complete_collection_import(
+        &self,
+        ctx: &Context<'_>,
+        input: CompleteCollectionImportInput,
+    ) -> Result<CompleteCollectionImportPayload> {
+        let AppContext { db, .. } = ctx.data::<AppContext>()?;
+        let conn = db.get();
+
+        let import = Imports::find_by_id(input.import)
+            .one(conn)
+            .await?
+            .ok_or(Error::new("Import not found"))?;
+
+        if import.status != CreationStatus::Pending {
+            return Err(Error::new("Import is not in progress"));
+        }
+
+        if import.mints_expected.is_some() {
+            return Err(Error::new(
+                "Import completes once its expected mints are indexed",
+            ));
+        }
+
+        let mints_processed = import.mints_indexed + import.mints_failed;
+
+        let mut import_am: imports::ActiveModel = import.into();
+        import_am.mints_expected = Set(Some(mints_processed));
+        import_am.updated_at = Set(Utc::now().into());
+
+        let import = import_am.update(conn).await?;
+
+        complete_import(conn, &import).await?;
+
+        let import = Imports::find_by_id(import.id)
+            .one(conn)
+            .await?
+            .ok_or(Error::new("Import not found"))?;
+
+        Ok(CompleteCollectionImportPayload { import })
+    }
+
     /// Update a collection attributes or creators.
     pub async fn patch_collection(
         &self,
@@ -489,6 +566,7 @@ impl Mutation {
             ..
         } = ctx.data::<AppContext>()?;
         let credits = ctx.data::<CreditsClient<Actions>>()?;
+        let metrics = ctx.data::<Metrics>()?;
         let solana = ctx.data::<Solana>()?;
         let conn = db.get();
 
@@ -533,7 +611,7 @@ impl Mutation {
             ));
         }
 
-        let TransactionId(deduction_id) = credits
+        let deduction = credits
             .submit_pending_deduction(
                 org_id,
                 user_id,
@@ -541,7 +619,14 @@ impl Mutation {
                 collection.blockchain.into(),
                 balance,
             )
-            .await?;
+            .await;
+        metrics.record_credits_deduction(
+            Actions::UpdateMint,
+            collection.blockchain,
+            deduction.is_ok(),
+        );
+
+        let TransactionId(deduction_id) = deduction?;
 
         match collection.blockchain {
             Blockchain::Solana => {
@@ -581,6 +666,108 @@ impl Mutation {
             _ => Err(Error::new("Blockchain not supported")),
         }
     }
+
+    /// Applies a metadata json patch to every mint of a collection. The patch is either a JSON merge patch or a list of attributes to add, remove or replace.
+    /// Mints are updated in the background at a limited rate, each with its own update history. Poll `collectionMintsUpdate` with the returned ID to follow the progress and the mints that were not updated.
+    /// # Errors
+    /// If the collection is not found, is not on Solana, already has an update in progress, or the patch is invalid, the mutation will result in an error.
+    pub async fn update_collection_mints(
+        &self,
+        ctx: &Context<'_>,
+        input: UpdateCollectionMintsInput,
+    ) -> Result<UpdateCollectionMintsPayload> {
+        let AppContext {
+            db,
+            user_id,
+            organization_id,
+            balance,
+            ..
+        } = ctx.data::<AppContext>()?;
+        let job_queue = ctx.data::<JobQueue>()?;
+        let conn = db.get();
+
+        let user_id = user_id.0.ok_or(Error::new("X-USER-ID header not found"))?;
+        let org_id = organization_id
+            .0
+            .ok_or(Error::new("X-ORGANIZATION-ID header not found"))?;
+        let balance = balance
+            .0
+            .ok_or(Error::new("X-CREDIT-BALANCE header not found"))?;
+
+        let collection = Collections::find_by_id(input.collection)
+            .one(conn)
+            .await?
+            .ok_or(Error::new("Collection not found"))?;
+
+        if collection.blockchain != BlockchainEnum::Solana {
+            return Err(Error::new("Blockchain not supported"));
+        }
+
+        input.patch.validate().map_err(Error::new)?;
+
+        let in_progress = collection_mint_updates::Entity::find()
+            .filter(collection_mint_updates::Column::CollectionId.eq(collection.id))
+            .filter(collection_mint_updates::Column::Status.eq(CreationStatus::Pending))
+            .one(conn)
+            .await?;
+
+        if in_progress.is_some() {
+            return Err(Error::new(
+                "Collection already has a mints update in progress",
+            ));
+        }
+
+        let update_am = collection_mint_updates::ActiveModel {
+            collection_id: Set(collection.id),
+            patch: Set(serde_json::to_value(&input.patch)?),
+            status: Set(CreationStatus::Pending),
+            organization_id: Set(org_id),
+            balance: Set(balance.try_into()?),
+            created_by: Set(user_id),
+            ..Default::default()
+        };
+
+        let update = update_am.insert(conn).await?;
+
+        job_queue
+            .enqueue(CollectionMintsUpdateTask::new(update.id))
+            .await?;
+
+        Ok(UpdateCollectionMintsPayload { update })
+    }
+
+    /// Cancels a collection mints update. Mints already updated keep their new metadata json.
+    /// # Errors
+    /// If the update is not found or has already completed, the mutation will result in an error.
+    pub async fn cancel_collection_mints_update(
+        &self,
+        ctx: &Context<'_>,
+        input: CancelCollectionMintsUpdateInput,
+    ) -> Result<CancelCollectionMintsUpdatePayload> {
+        let AppContext { db, .. } = ctx.data::<AppContext>()?;
+        let conn = db.get();
+
+        let update = collection_mint_updates::Entity::find_by_id(input.id)
+            .one(conn)
+            .await?
+            .ok_or(Error::new("Collection mints update not found"))?;
+
+        if !matches!(
+            update.status,
+            CreationStatus::Pending | CreationStatus::Failed
+        ) {
+            return Err(Error::new("Collection mints update is not in progress"));
+        }
+
+        let mut update_am: collection_mint_updates::ActiveModel = update.into();
+
+        update_am.status = Set(CreationStatus::Canceled);
+        update_am.updated_at = Set(Utc::now().into());
+
+        let update = update_am.update(conn).await?;
+
+        Ok(CancelCollectionMintsUpdatePayload { update })
+    }
 }
 
 ///  Fetches the owner's wallet address for a given project and blockchain.
@@ -734,7 +921,7 @@ pub fn validate_evm_address(address: &str) -> Result<()> {
     Ok(())
 }
 
-/// Validates the JSON metadata input for the NFT drop.
+/// Validates the JSON metadata input for the NFT drop against the metadata standard of the blockchain.
 /// # Returns
 /// - Ok(()) if all JSON fields are valid.
 ///
@@ -755,10 +942,15 @@ pub fn validate_json(blockchain: BlockchainEnum, json: &MetadataJsonInput) -> Re
 
     Url::from_str(&json.image).map_err(|_| Error::new("Invalid image url"))?;
 
-    if blockchain != BlockchainEnum::Solana {
-        return Ok(());
+    validate_attribute_values(json)?;
+
+    match MetadataStandard::from(blockchain) {
+        MetadataStandard::Metaplex => validate_metaplex_json(json),
+        MetadataStandard::Erc721 | MetadataStandard::Erc1155 => validate_erc_json(json),
     }
+}
 
+fn validate_metaplex_json(json: &MetadataJsonInput) -> Result<()> {
     if json.name.chars().count() > 32 {
         return Err(Error::new("Name must be less than 32 characters"));
     }
@@ -767,6 +959,116 @@ pub fn validate_json(blockchain: BlockchainEnum, json: &MetadataJsonInput) -> Re
         return Err(Error::new("Symbol must be less than 10 characters"));
     }
 
+    if json.background_color.is_some() {
+        return Err(Error::new(
+            "Background color is not supported by the Metaplex standard",
+        ));
+    }
+
+    if json.youtube_url.is_some() {
+        return Err(Error::new(
+            "Youtube url is not supported by the Metaplex standard",
+        ));
+    }
+
+    if json
+        .attributes
+        .iter()
+        .any(|attribute| attribute.display_type.is_some())
+    {
+        return Err(Error::new(
+            "Attribute display type is not supported by the Metaplex standard",
+        ));
+    }
+
+    if json
+        .attributes
+        .iter()
+        .any(|attribute| attribute.max_value.is_some())
+    {
+        return Err(Error::new(
+            "Attribute max value is not supported by the Metaplex standard",
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_erc_json(json: &MetadataJsonInput) -> Result<()> {
+    if let Some(background_color) = &json.background_color {
+        if background_color.len() != 6 || !background_color.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(Error::new(
+                "Background color must be a six-character hexadecimal without a pre-pended #",
+            ));
+        }
+    }
+
+    json.youtube_url
+        .as_ref()
+        .map(|youtube_url| Url::from_str(youtube_url))
+        .transpose()
+        .map_err(|_| Error::new("Invalid youtube url"))?;
+
+    for attribute in &json.attributes {
+        if let Some(max_value) = attribute.max_value {
+            if attribute.value_type() != AttributeValueType::Number {
+                return Err(Error::new(format!(
+                    "Attribute {} must be a number to have a max value",
+                    attribute.trait_type
+                )));
+            }
+
+            if let Some(AttributeValue::Number(value)) = attribute.typed_value() {
+                if !max_value.is_finite() || value > max_value {
+                    return Err(Error::new(format!(
+                        "Attribute {} must not be greater than its max value",
+                        attribute.trait_type
+                    )));
+                }
+            }
+        }
+
+        let Some(display_type) = attribute.display_type.as_deref() else {
+            continue;
+        };
+
+        if !NUMERIC_DISPLAY_TYPES.contains(&display_type) {
+            return Err(Error::new(format!(
+                "Attribute {} has an unsupported display type {display_type}",
+                attribute.trait_type
+            )));
+        }
+
+        let expected = if display_type == "date" {
+            AttributeValueType::Date
+        } else {
+            AttributeValueType::Number
+        };
+
+        if attribute.value_type() != expected {
+            return Err(Error::new(format!(
+                "Attribute {} must be a {expected:?} for display type {display_type}",
+                attribute.trait_type
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates that the value of every attribute parses as its type.
+fn validate_attribute_values(json: &MetadataJsonInput) -> Result<()> {
+    for attribute in &json.attributes {
+        if attribute.typed_value().is_none() {
+            return Err(Error::new(format!(
+                "Attribute {} has an invalid {:?} value {}",
+                attribute.trait_type,
+                attribute.value_type(),
+                attribute.value
+            )));
+        }
+    }
+
     Ok(())
 }
 
@@ -813,6 +1115,22 @@ pub struct ImportCollectionInput {
 pub struct ImportCollectionPayload {
     /// The status of the collection import.
     status: CreationStatus,
+    /// The import record. Use `importStatus` with its ID to follow the progress of the import.
+    import: imports::Model,
+}
+
+/// Input object for completing a collection import.
+#[derive(Debug, Clone, Serialize, Deserialize, InputObject)]
+pub struct CompleteCollectionImportInput {
+    /// The ID of the import.
+    pub import: Uuid,
+}
+
+/// Represents the result of a successful complete collection import mutation.
+#[derive(Debug, Clone, SimpleObject)]
+pub struct CompleteCollectionImportPayload {
+    /// The completed import.
+    import: imports::Model,
 }
 
 /// Input object for switching a mint's collection.
@@ -827,3 +1145,213 @@ pub struct SwitchCollectionInput {
 pub struct SwitchCollectionPayload {
     collection_mint: CollectionMint,
 }
+
+/// Input object for updating the metadata json of every mint of a collection.
+#[derive(Debug, Clone, InputObject)]
+pub struct UpdateCollectionMintsInput {
+    /// The ID of the collection.
+    pub collection: Uuid,
+    /// The patch applied to the metadata json of each mint.
+    pub patch: MetadataJsonPatchInput,
+}
+
+/// Represents the result of a successful update collection mints mutation.
+#[derive(Debug, Clone, SimpleObject)]
+pub struct UpdateCollectionMintsPayload {
+    /// The queued update. Use `collectionMintsUpdate` with its ID to follow the progress.
+    update: collection_mint_updates::Model,
+}
+
+/// Input object for canceling a collection mints update.
+#[derive(Debug, Clone, InputObject)]
+pub struct CancelCollectionMintsUpdateInput {
+    /// The ID of the collection mints update.
+    pub id: Uuid,
+}
+
+/// Represents the result of a successful cancel collection mints update mutation.
+#[derive(Debug, Clone, SimpleObject)]
+pub struct CancelCollectionMintsUpdatePayload {
+    /// The canceled update.
+    update: collection_mint_updates::Model,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::objects::Attribute;
+
+    const SOLANA_ADDRESS: &str = "So11111111111111111111111111111111111111112";
+    const EVM_ADDRESS: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+
+    fn creator(address: &str, share: u8) -> Creator {
+        Creator {
+            address: address.to_string(),
+            verified: None,
+            share,
+        }
+    }
+
+    fn metadata_json() -> MetadataJsonInput {
+        MetadataJsonInput {
+            name: "Name".to_string(),
+            symbol: "SYM".to_string(),
+            description: "Description".to_string(),
+            image: "https://example.com/image.png".to_string(),
+            animation_url: None,
+            collection: None,
+            attributes: Vec::new(),
+            external_url: None,
+            properties: None,
+            background_color: None,
+            youtube_url: None,
+        }
+    }
+
+    fn attribute(value: &str, display_type: Option<&str>) -> Attribute {
+        Attribute {
+            trait_type: "trait".to_string(),
+            value: value.to_string(),
+            value_type: None,
+            display_type: display_type.map(ToString::to_string),
+            max_value: None,
+        }
+    }
+
+    #[test]
+    fn validate_creators_requires_shares_summing_to_100() {
+        let partial = vec![creator(SOLANA_ADDRESS, 50)];
+        let full = vec![creator(SOLANA_ADDRESS, 100)];
+
+        assert!(validate_creators(BlockchainEnum::Solana, &partial).is_err());
+        assert!(validate_creators(BlockchainEnum::Solana, &full).is_ok());
+    }
+
+    #[test]
+    fn validate_creators_allows_at_most_five_solana_creators() {
+        let creators = vec![
+            creator(SOLANA_ADDRESS, 20),
+            creator(SOLANA_ADDRESS, 20),
+            creator(SOLANA_ADDRESS, 20),
+            creator(SOLANA_ADDRESS, 20),
+            creator(SOLANA_ADDRESS, 10),
+            creator(SOLANA_ADDRESS, 10),
+        ];
+
+        assert!(validate_creators(BlockchainEnum::Solana, &creators).is_err());
+    }
+
+    #[test]
+    fn validate_creators_checks_addresses_for_the_blockchain() {
+        let solana = vec![creator(SOLANA_ADDRESS, 100)];
+        let evm = vec![creator(EVM_ADDRESS, 100)];
+
+        assert!(validate_creators(BlockchainEnum::Solana, &evm).is_err());
+        assert!(validate_creators(BlockchainEnum::Polygon, &evm).is_ok());
+        assert!(validate_creators(BlockchainEnum::Polygon, &solana).is_err());
+    }
+
+    #[test]
+    fn validate_creators_allows_one_polygon_creator() {
+        let creators = vec![creator(EVM_ADDRESS, 50), creator(EVM_ADDRESS, 50)];
+
+        assert!(validate_creators(BlockchainEnum::Polygon, &creators).is_err());
+    }
+
+    #[test]
+    fn validate_creators_rejects_ethereum() {
+        let creators = vec![creator(EVM_ADDRESS, 100)];
+
+        assert!(validate_creators(BlockchainEnum::Ethereum, &creators).is_err());
+    }
+
+    #[test]
+    fn validate_json_requires_valid_urls() {
+        let mut json = metadata_json();
+        json.image = "not a url".to_string();
+
+        assert!(validate_json(BlockchainEnum::Solana, &json).is_err());
+
+        let mut json = metadata_json();
+        json.external_url = Some("not a url".to_string());
+
+        assert!(validate_json(BlockchainEnum::Polygon, &json).is_err());
+    }
+
+    #[test]
+    fn validate_json_limits_metaplex_name_and_symbol() {
+        let mut json = metadata_json();
+        json.name = "a".repeat(33);
+
+        assert!(validate_json(BlockchainEnum::Solana, &json).is_err());
+        assert!(validate_json(BlockchainEnum::Polygon, &json).is_ok());
+
+        let mut json = metadata_json();
+        json.symbol = "a".repeat(11);
+
+        assert!(validate_json(BlockchainEnum::Solana, &json).is_err());
+    }
+
+    #[test]
+    fn validate_json_rejects_erc_fields_for_metaplex() {
+        let mut json = metadata_json();
+        json.background_color = Some("ffffff".to_string());
+
+        assert!(validate_json(BlockchainEnum::Solana, &json).is_err());
+        assert!(validate_json(BlockchainEnum::Polygon, &json).is_ok());
+
+        let mut json = metadata_json();
+        json.attributes = vec![attribute("1", Some("number"))];
+
+        assert!(validate_json(BlockchainEnum::Solana, &json).is_err());
+        assert!(validate_json(BlockchainEnum::Polygon, &json).is_ok());
+    }
+
+    #[test]
+    fn validate_json_requires_hexadecimal_erc_background_color() {
+        for background_color in ["#ffffff", "fff", "gggggg"] {
+            let mut json = metadata_json();
+            json.background_color = Some(background_color.to_string());
+
+            assert!(validate_json(BlockchainEnum::Polygon, &json).is_err());
+        }
+    }
+
+    #[test]
+    fn validate_json_checks_erc_display_types() {
+        let mut json = metadata_json();
+        json.attributes = vec![attribute("1", Some("stars"))];
+
+        assert!(validate_json(BlockchainEnum::Polygon, &json).is_err());
+
+        json.attributes = vec![attribute("fast", Some("number"))];
+
+        assert!(validate_json(BlockchainEnum::Polygon, &json).is_err());
+
+        json.attributes = vec![attribute("2023-11-01T00:00:00Z", Some("date"))];
+
+        assert!(validate_json(BlockchainEnum::Polygon, &json).is_ok());
+    }
+
+    #[test]
+    fn validate_json_checks_erc_max_values() {
+        let mut json = metadata_json();
+        let mut within = attribute("5", Some("number"));
+        within.max_value = Some(10.0);
+        json.attributes = vec![within];
+
+        assert!(validate_json(BlockchainEnum::Polygon, &json).is_ok());
+
+        let mut above = attribute("11", Some("number"));
+        above.max_value = Some(10.0);
+        json.attributes = vec![above];
+
+        assert!(validate_json(BlockchainEnum::Polygon, &json).is_err());
+
+        let mut string = attribute("fast", None);
+        string.max_value = Some(10.0);
+        json.attributes = vec![string];
+
+        assert!(validate_json(BlockchainEnum::Polygon, &json).is_err());
+    }
+}
@@ -20,9 +20,10 @@ use crate::{
     entities::{
         collection_creators, collections, drops, metadata_jsons,
         prelude::{CollectionCreators, Collections, Drops, MetadataJsons},
-        project_wallets,
+        project_wallets, retry_histories,
         sea_orm_active_enums::{Blockchain as BlockchainEnum, CreationStatus, DropType},
     },
+    metrics::Metrics,
     objects::{Creator, Drop, MetadataJsonInput},
     proto::{
         self, nft_events::Event as NftEvent, CreationStatus as NftCreationStatus, EditionInfo,
@@ -62,6 +63,7 @@ impl Mutation {
 
         let conn = db.get();
         let credits = ctx.data::<CreditsClient<Actions>>()?;
+        let metrics = ctx.data::<Metrics>()?;
         let nfts_producer = ctx.data::<Producer<NftEvents>>()?;
         let metadata_json_upload_job_queue = ctx.data::<JobQueue>()?;
 
@@ -77,7 +79,7 @@ impl Mutation {
             validate_solana_creator_verification(&owner_address, &input.creators)?;
         }
 
-        let TransactionId(credits_deduction_id) = credits
+        let deduction = credits
             .submit_pending_deduction(
                 org_id,
                 user_id,
@@ -85,7 +87,10 @@ impl Mutation {
                 input.blockchain.into(),
                 balance,
             )
-            .await?;
+            .await;
+        metrics.record_credits_deduction(Actions::CreateDrop, input.blockchain, deduction.is_ok());
+
+        let TransactionId(credits_deduction_id) = deduction?;
 
         let seller_fee_basis_points = input.seller_fee_basis_points.unwrap_or_default();
 
@@ -195,6 +200,7 @@ impl Mutation {
 
         let conn = db.get();
         let credits = ctx.data::<CreditsClient<Actions>>()?;
+        let metrics = ctx.data::<Metrics>()?;
         let solana = ctx.data::<Solana>()?;
         let polygon = ctx.data::<Polygon>()?;
 
@@ -225,7 +231,7 @@ impl Mutation {
 
         let owner_address = fetch_owner(conn, drop.project_id, collection.blockchain).await?;
 
-        let TransactionId(_) = credits
+        let deduction = credits
             .submit_pending_deduction(
                 org_id,
                 user_id,
@@ -233,7 +239,25 @@ impl Mutation {
                 collection.blockchain.into(),
                 balance,
             )
-            .await?;
+            .await;
+        metrics.record_credits_deduction(
+            Actions::RetryDrop,
+            collection.blockchain,
+            deduction.is_ok(),
+        );
+
+        let TransactionId(credits_deduction_id) = deduction?;
+
+        let retry_history_am = retry_histories::ActiveModel {
+            project_id: Set(drop.project_id),
+            collection_id: Set(collection.id),
+            action: Set(Actions::RetryDrop.as_ref().to_string()),
+            credits_deduction_id: Set(credits_deduction_id),
+            created_by: Set(user_id),
+            ..Default::default()
+        };
+
+        retry_history_am.insert(conn).await?;
 
         let event_key = NftEventKey {
             id: collection.id.to_string(),
@@ -0,0 +1,125 @@
+use async_graphql::{Context, Error, InputObject, Object, Result, SimpleObject};
+use sea_orm::{prelude::*, Set};
+
+use crate::{
+    background_worker::{job_queue::JobQueue, tasks::CollectionExportTask},
+    db::Connection,
+    entities::{
+        collection_exports, collections, drops,
+        sea_orm_active_enums::{CreationStatus, ExportFormat},
+    },
+    AppContext, UserID,
+};
+
+#[derive(Default)]
+pub struct Mutation;
+
+#[Object(name = "ExportMutation")]
+impl Mutation {
+    /// Exports every mint of a collection with its address, owner, edition, status, metadata URI and attributes.
+    /// The file is written and uploaded in the background. Poll `collectionExport` with the returned ID until its status is `CREATED` to get the URI of the file.
+    /// # Errors
+    /// If the collection is not found or the export cannot be queued, the mutation will result in an error.
+    pub async fn export_collection(
+        &self,
+        ctx: &Context<'_>,
+        input: ExportCollectionInput,
+    ) -> Result<ExportCollectionPayload> {
+        let AppContext { db, user_id, .. } = ctx.data::<AppContext>()?;
+        let job_queue = ctx.data::<JobQueue>()?;
+        let UserID(id) = user_id;
+        let user_id = id.ok_or(Error::new("X-USER-ID header not found"))?;
+
+        let collection = collections::Entity::find_by_id(input.collection)
+            .one(db.get())
+            .await?
+            .ok_or(Error::new("Collection not found"))?;
+
+        let export =
+            queue_export(db, job_queue, collection.id, None, input.format, user_id).await?;
+
+        Ok(ExportCollectionPayload { export })
+    }
+
+    /// Exports every mint of the collection of a drop. See `exportCollection`.
+    /// # Errors
+    /// If the drop is not found or the export cannot be queued, the mutation will result in an error.
+    pub async fn export_drop(
+        &self,
+        ctx: &Context<'_>,
+        input: ExportDropInput,
+    ) -> Result<ExportCollectionPayload> {
+        let AppContext { db, user_id, .. } = ctx.data::<AppContext>()?;
+        let job_queue = ctx.data::<JobQueue>()?;
+        let UserID(id) = user_id;
+        let user_id = id.ok_or(Error::new("X-USER-ID header not found"))?;
+
+        let drop = drops::Entity::find_by_id(input.drop)
+            .one(db.get())
+            .await?
+            .ok_or(Error::new("Drop not found"))?;
+
+        let export = queue_export(
+            db,
+            job_queue,
+            drop.collection_id,
+            Some(drop.id),
+            input.format,
+            user_id,
+        )
+        .await?;
+
+        Ok(ExportCollectionPayload { export })
+    }
+}
+
+async fn queue_export(
+    db: &Connection,
+    job_queue: &JobQueue,
+    collection_id: Uuid,
+    drop_id: Option<Uuid>,
+    format: ExportFormat,
+    user_id: Uuid,
+) -> Result<collection_exports::Model> {
+    let export_am = collection_exports::ActiveModel {
+        collection_id: Set(collection_id),
+        drop_id: Set(drop_id),
+        format: Set(format),
+        status: Set(CreationStatus::Pending),
+        created_by: Set(user_id),
+        ..Default::default()
+    };
+
+    let export = export_am.insert(db.get()).await?;
+
+    job_queue
+        .enqueue(CollectionExportTask::new(export.id))
+        .await?;
+
+    Ok(export)
+}
+
+/// Input object for exporting a collection.
+#[derive(Debug, Clone, InputObject)]
+pub struct ExportCollectionInput {
+    /// The ID of the collection to export.
+    pub collection: Uuid,
+    /// The format of the exported file.
+    pub format: ExportFormat,
+}
+
+/// Input object for exporting the collection of a drop.
+#[derive(Debug, Clone, InputObject)]
+pub struct ExportDropInput {
+    /// The ID of the drop to export.
+    pub drop: Uuid,
+    /// The format of the exported file.
+    pub format: ExportFormat,
+}
+
+/// Represents the result of a successful export collection or export drop mutation.
+#[derive(Debug, Clone, SimpleObject)]
+pub struct ExportCollectionPayload {
+    /// The queued export. Its `uri` is set once the status is `CREATED`.
+    pub export: collection_exports::Model,
+}
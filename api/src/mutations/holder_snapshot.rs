@@ -0,0 +1,100 @@
+use async_graphql::{Context, Error, InputObject, Object, Result, SimpleObject};
+use hub_core::chrono::{DateTime, Utc};
+use sea_orm::{prelude::*, Set, TransactionTrait};
+
+use crate::{
+    entities::{collections, holder_snapshot_mints, holder_snapshots},
+    objects::holder_snapshot::owners_at,
+    AppContext, UserID,
+};
+
+/// The number of snapshot rows written per insert statement.
+const INSERT_CHUNK_SIZE: usize = 1000;
+
+#[derive(Default)]
+pub struct Mutation;
+
+#[Object(name = "HolderSnapshotMutation")]
+impl Mutation {
+    /// Saves the holders of a collection at a point in time under a name.
+    /// The snapshot can be looked up later with `savedHolderSnapshot` and exported as CSV or JSON, for example to target an airdrop.
+    /// # Errors
+    /// If the collection is not found or the snapshot cannot be saved to the database, the mutation will result in an error.
+    pub async fn create_holder_snapshot(
+        &self,
+        ctx: &Context<'_>,
+        input: CreateHolderSnapshotInput,
+    ) -> Result<CreateHolderSnapshotPayload> {
+        let AppContext { db, user_id, .. } = ctx.data::<AppContext>()?;
+        let UserID(id) = user_id;
+        let user_id = id.ok_or(Error::new("X-USER-ID header not found"))?;
+
+        let conn = db.get();
+
+        let name = input.name.trim().to_string();
+
+        if name.is_empty() {
+            return Err(Error::new("Snapshot name must not be empty"));
+        }
+
+        let collection = collections::Entity::find_by_id(input.collection)
+            .one(conn)
+            .await?
+            .ok_or(Error::new("Collection not found"))?;
+
+        let taken_at = input.at.unwrap_or_else(Utc::now);
+        let owners = owners_at(conn, collection.id, taken_at).await?;
+
+        let tx = conn.begin().await?;
+
+        let snapshot_am = holder_snapshots::ActiveModel {
+            collection_id: Set(collection.id),
+            name: Set(name),
+            taken_at: Set(taken_at.into()),
+            created_by: Set(user_id),
+            ..Default::default()
+        };
+
+        let snapshot = snapshot_am.insert(&tx).await?;
+
+        for chunk in owners.chunks(INSERT_CHUNK_SIZE) {
+            let snapshot_mints =
+                chunk
+                    .iter()
+                    .map(|(mint, owner)| holder_snapshot_mints::ActiveModel {
+                        snapshot_id: Set(snapshot.id),
+                        collection_mint_id: Set(*mint),
+                        owner: Set(owner.clone()),
+                        ..Default::default()
+                    });
+
+            holder_snapshot_mints::Entity::insert_many(snapshot_mints)
+                .exec(&tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(CreateHolderSnapshotPayload {
+            holder_snapshot: snapshot,
+        })
+    }
+}
+
+/// Input object for saving a holder snapshot.
+#[derive(Debug, Clone, InputObject)]
+pub struct CreateHolderSnapshotInput {
+    /// The ID of the collection to snapshot.
+    pub collection: Uuid,
+    /// The name of the snapshot.
+    pub name: String,
+    /// The point in time to compute the holders for. Defaults to now.
+    pub at: Option<DateTime<Utc>>,
+}
+
+/// Represents the result of a successful create holder snapshot mutation.
+#[derive(Debug, Clone, SimpleObject)]
+pub struct CreateHolderSnapshotPayload {
+    /// The saved snapshot.
+    pub holder_snapshot: holder_snapshots::Model,
+}
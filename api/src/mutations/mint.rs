@@ -9,8 +9,9 @@ use hub_core::{
 use redis::AsyncCommands;
 use sea_orm::{
     prelude::*,
-    sea_query::{Func, SimpleExpr},
-    JoinType, Order, QueryOrder, QuerySelect, Set, TransactionTrait,
+    sea_query::{Func, Query, SimpleExpr},
+    DatabaseTransaction, JoinType, Order, QueryOrder, QuerySelect, Set, TransactionTrait,
+    TryIntoModel,
 };
 
 use super::collection::{
@@ -20,9 +21,9 @@ use crate::{
     background_worker::{
         job_queue::JobQueue,
         tasks::{
-            MetadataJsonUploadCaller, MetadataJsonUploadMintToCollection,
-            MetadataJsonUploadQueueMintToDrop, MetadataJsonUploadTask,
-            MetadataJsonUploadUpdateMint,
+            check_manifest_url, CollectionRarityTask, DropManifestTask, MetadataJsonUploadCaller,
+            MetadataJsonUploadMintToCollection, MetadataJsonUploadQueueMintToDrop,
+            MetadataJsonUploadTask, MetadataJsonUploadUpdateMint,
         },
     },
     blockchains::{
@@ -31,13 +32,16 @@ use crate::{
         CollectionEvent, DropEvent,
     },
     entities::{
-        collection_creators, collection_mints, collections, drops, metadata_json_attributes,
-        metadata_json_files, metadata_jsons, mint_creators, mint_histories,
+        collection_creators, collection_mints, collections, drop_manifests, drops,
+        metadata_json_attributes, metadata_json_files, metadata_json_versions, metadata_jsons,
+        mint_creators, mint_histories,
         prelude::{CollectionCreators, CollectionMints, Collections},
+        retry_histories,
         sea_orm_active_enums::{Blockchain as BlockchainEnum, CreationStatus},
         update_histories,
     },
-    objects::{CollectionMint, Creator, MetadataJsonInput},
+    metrics::Metrics,
+    objects::{canonical_hash, CollectionMint, Creator, MetadataJsonInput},
     proto::{
         self, nft_events::Event as NftEvent, CreationStatus as NftCreationStatus, MetaplexMetadata,
         MintCollectionCreation, MintCreation, MintOpenDropTransaction, NftEventKey, NftEvents,
@@ -68,6 +72,7 @@ impl Mutation {
             ..
         } = ctx.data::<AppContext>()?;
         let credits = ctx.data::<CreditsClient<Actions>>()?;
+        let metrics = ctx.data::<Metrics>()?;
         let conn = db.get();
         let mut redis_conn = redis.get_async_connection().await?;
         let solana = ctx.data::<Solana>()?;
@@ -107,7 +112,7 @@ impl Mutation {
 
         let owner_address = fetch_owner(conn, collection.project_id, collection.blockchain).await?;
 
-        let TransactionId(credits_deduction_id) = credits
+        let deduction = credits
             .submit_pending_deduction(
                 org_id,
                 user_id,
@@ -115,7 +120,14 @@ impl Mutation {
                 collection.blockchain.into(),
                 balance,
             )
-            .await?;
+            .await;
+        metrics.record_credits_deduction(
+            Actions::MintEdition,
+            collection.blockchain,
+            deduction.is_ok(),
+        );
+
+        let TransactionId(credits_deduction_id) = deduction?;
 
         let tx = conn.begin().await?;
 
@@ -213,6 +225,12 @@ impl Mutation {
                     metadata_json_id: Set(collection_mint_metadata_json.id),
                     trait_type: Set(attribute.trait_type),
                     value: Set(attribute.value),
+                    value_type: Set(attribute.value_type),
+                    number_value: Set(attribute.number_value),
+                    boolean_value: Set(attribute.boolean_value),
+                    date_value: Set(attribute.date_value),
+                    display_type: Set(attribute.display_type),
+                    max_value: Set(attribute.max_value),
                     ..Default::default()
                 })
                 .collect();
@@ -303,6 +321,7 @@ impl Mutation {
             ..
         } = ctx.data::<AppContext>()?;
         let credits = ctx.data::<CreditsClient<Actions>>()?;
+        let metrics = ctx.data::<Metrics>()?;
         let conn = db.get();
         let solana = ctx.data::<Solana>()?;
         let polygon = ctx.data::<Polygon>()?;
@@ -350,7 +369,7 @@ impl Mutation {
 
         let owner_address = fetch_owner(conn, project_id, collection.blockchain).await?;
 
-        let TransactionId(_) = credits
+        let deduction = credits
             .submit_pending_deduction(
                 org_id,
                 user_id,
@@ -358,7 +377,26 @@ impl Mutation {
                 collection.blockchain.into(),
                 balance,
             )
-            .await?;
+            .await;
+        metrics.record_credits_deduction(
+            Actions::RetryMint,
+            collection.blockchain,
+            deduction.is_ok(),
+        );
+
+        let TransactionId(credits_deduction_id) = deduction?;
+
+        let retry_history_am = retry_histories::ActiveModel {
+            project_id: Set(project_id),
+            collection_id: Set(collection.id),
+            collection_mint_id: Set(Some(collection_mint_model.id)),
+            action: Set(Actions::RetryMint.as_ref().to_string()),
+            credits_deduction_id: Set(credits_deduction_id),
+            created_by: Set(user_id),
+            ..Default::default()
+        };
+
+        retry_history_am.insert(conn).await?;
 
         let event_key = NftEventKey {
             id: collection_mint_model.id.to_string(),
@@ -421,6 +459,7 @@ impl Mutation {
             ..
         } = ctx.data::<AppContext>()?;
         let credits = ctx.data::<CreditsClient<Actions>>()?;
+        let metrics = ctx.data::<Metrics>()?;
         let conn = db.get();
         let mut redis_conn = redis.get_async_connection().await?;
         let nfts_producer = ctx.data::<Producer<NftEvents>>()?;
@@ -464,7 +503,7 @@ impl Mutation {
             Actions::Mint
         };
 
-        let TransactionId(credits_deduction_id) = credits
+        let deduction = credits
             .submit_pending_deduction(
                 org_id,
                 user_id,
@@ -472,7 +511,10 @@ impl Mutation {
                 collection.blockchain.into(),
                 balance,
             )
-            .await?;
+            .await;
+        metrics.record_credits_deduction(action, collection.blockchain, deduction.is_ok());
+
+        let TransactionId(credits_deduction_id) = deduction?;
 
         let tx = conn.begin().await?;
 
@@ -535,6 +577,10 @@ impl Mutation {
             })
             .await?;
 
+        metadata_json_upload_job_queue
+            .enqueue_unless_queued(CollectionRarityTask::new(collection.id))
+            .await?;
+
         nfts_producer
             .send(
                 Some(&NftEvents {
@@ -572,6 +618,7 @@ impl Mutation {
             ..
         } = ctx.data::<AppContext>()?;
         let credits = ctx.data::<CreditsClient<Actions>>()?;
+        let metrics = ctx.data::<Metrics>()?;
         let conn = db.get();
         let metadata_json_upload_job_queue = ctx.data::<JobQueue>()?;
 
@@ -628,7 +675,7 @@ impl Mutation {
             })
             .collect::<Result<Vec<_>>>()?;
 
-        let TransactionId(deduction_id) = credits
+        let deduction = credits
             .submit_pending_deduction(
                 org_id,
                 user_id,
@@ -636,7 +683,14 @@ impl Mutation {
                 collection.blockchain.into(),
                 balance,
             )
-            .await?;
+            .await;
+        metrics.record_credits_deduction(
+            Actions::UpdateMint,
+            collection.blockchain,
+            deduction.is_ok(),
+        );
+
+        let TransactionId(deduction_id) = deduction?;
 
         let tx = conn.begin().await?;
 
@@ -669,6 +723,15 @@ impl Mutation {
             mint_am.update(&tx).await?;
         }
 
+        record_metadata_version(
+            &tx,
+            mint.id,
+            update_history.id,
+            &input.metadata_json,
+            user_id,
+        )
+        .await?;
+
         input.metadata_json.save(mint.id, &tx).await?;
 
         tx.commit().await?;
@@ -682,11 +745,131 @@ impl Mutation {
             })
             .await?;
 
+        metadata_json_upload_job_queue
+            .enqueue_unless_queued(CollectionRarityTask::new(collection.id))
+            .await?;
+
         Ok(UpdateMintPayload {
             collection_mint: mint.into(),
         })
     }
 
+    /// Republishes an earlier version of the metadata json of a mint. The version is recorded again as the latest version and uploaded like an update.
+    /// # Errors
+    /// If the mint or version is not found, the mint cannot be updated or the update fails to be queued, the mutation will result in an error.
+    pub async fn rollback_mint_metadata(
+        &self,
+        ctx: &Context<'_>,
+        input: RollbackMintMetadataInput,
+    ) -> Result<RollbackMintMetadataPayload> {
+        let AppContext {
+            db,
+            user_id,
+            organization_id,
+            balance,
+            ..
+        } = ctx.data::<AppContext>()?;
+        let credits = ctx.data::<CreditsClient<Actions>>()?;
+        let metrics = ctx.data::<Metrics>()?;
+        let conn = db.get();
+        let metadata_json_upload_job_queue = ctx.data::<JobQueue>()?;
+
+        let UserID(id) = user_id;
+        let OrganizationId(org) = organization_id;
+
+        let user_id = id.ok_or(Error::new("X-USER-ID header not found"))?;
+        let balance = balance
+            .0
+            .ok_or(Error::new("X-CREDIT-BALANCE header not found"))?;
+        let org_id = org.ok_or(Error::new("X-ORGANIZATION-ID header not found"))?;
+
+        let (mint, collection) = CollectionMints::find_by_id_with_collection(input.mint_id)
+            .one(conn)
+            .await?
+            .ok_or(Error::new("Mint not found"))?;
+
+        if mint.creation_status != CreationStatus::Created {
+            return Err(Error::new("Mint not created"));
+        }
+
+        if mint.edition > 0 {
+            return Err(Error::new("Mint is an edition and cannot be updated"));
+        }
+
+        if Some(true) == mint.compressed {
+            return Err(Error::new("Mint is compressed and cannot be updated"));
+        }
+
+        let collection = collection.ok_or(Error::new("Collection not found"))?;
+
+        let version = metadata_json_versions::Entity::find()
+            .filter(metadata_json_versions::Column::CollectionMintId.eq(mint.id))
+            .filter(metadata_json_versions::Column::Version.eq(input.version))
+            .one(conn)
+            .await?
+            .ok_or(Error::new("Metadata version not found"))?;
+
+        let metadata_json: MetadataJsonInput = serde_json::from_value(version.metadata_json)?;
+
+        validate_json(collection.blockchain, &metadata_json)?;
+
+        let deduction = credits
+            .submit_pending_deduction(
+                org_id,
+                user_id,
+                Actions::UpdateMint,
+                collection.blockchain.into(),
+                balance,
+            )
+            .await;
+        metrics.record_credits_deduction(
+            Actions::UpdateMint,
+            collection.blockchain,
+            deduction.is_ok(),
+        );
+
+        let TransactionId(deduction_id) = deduction?;
+
+        let tx = conn.begin().await?;
+
+        let update_history_am = update_histories::ActiveModel {
+            mint_id: Set(mint.id),
+            txn_signature: Set(None),
+            credit_deduction_id: Set(deduction_id),
+            created_by: Set(user_id),
+            status: Set(CreationStatus::Pending),
+            ..Default::default()
+        };
+
+        let update_history = update_history_am.insert(&tx).await?;
+
+        let metadata_version =
+            record_metadata_version(&tx, mint.id, update_history.id, &metadata_json, user_id)
+                .await?;
+
+        metadata_json.save(mint.id, &tx).await?;
+
+        tx.commit().await?;
+
+        metadata_json_upload_job_queue
+            .enqueue(MetadataJsonUploadTask {
+                caller: MetadataJsonUploadCaller::UpdateMint(MetadataJsonUploadUpdateMint {
+                    update_history_id: update_history.id,
+                }),
+                metadata_json,
+            })
+            .await?;
+
+        metadata_json_upload_job_queue
+            .enqueue_unless_queued(CollectionRarityTask::new(collection.id))
+            .await?;
+
+        Ok(RollbackMintMetadataPayload {
+            collection_mint: mint.into(),
+            metadata_version,
+        })
+    }
+
     /// This mutation retries updating a mint that failed by providing the ID of the `update_history`.
     /// # Errors
     /// If the mint cannot be saved to the database or fails to be emitted for submission to the desired blockchain, the mutation will result in an error.
@@ -766,6 +949,7 @@ impl Mutation {
             ..
         } = ctx.data::<AppContext>()?;
         let credits = ctx.data::<CreditsClient<Actions>>()?;
+        let metrics = ctx.data::<Metrics>()?;
         let conn = db.get();
         let solana = ctx.data::<Solana>()?;
 
@@ -816,7 +1000,7 @@ impl Mutation {
             .all(conn)
             .await?;
 
-        let TransactionId(_) = credits
+        let deduction = credits
             .submit_pending_deduction(
                 org_id,
                 user_id,
@@ -824,7 +1008,26 @@ impl Mutation {
                 collection.blockchain.into(),
                 balance,
             )
-            .await?;
+            .await;
+        metrics.record_credits_deduction(
+            Actions::RetryMint,
+            collection.blockchain,
+            deduction.is_ok(),
+        );
+
+        let TransactionId(credits_deduction_id) = deduction?;
+
+        let retry_history_am = retry_histories::ActiveModel {
+            project_id: Set(project_id),
+            collection_id: Set(collection.id),
+            collection_mint_id: Set(Some(collection_mint_model.id)),
+            action: Set(Actions::RetryMint.as_ref().to_string()),
+            credits_deduction_id: Set(credits_deduction_id),
+            created_by: Set(user_id),
+            ..Default::default()
+        };
+
+        retry_history_am.insert(conn).await?;
 
         let event_key = NftEventKey {
             id: collection_mint_model.id.to_string(),
@@ -946,6 +1149,10 @@ impl Mutation {
             })
             .await?;
 
+        metadata_json_upload_job_queue
+            .enqueue_unless_queued(CollectionRarityTask::new(drop.collection_id))
+            .await?;
+
         redis_conn
             .del(format!("collection:{}:supply", drop.collection_id))
             .await?;
@@ -971,6 +1178,250 @@ impl Mutation {
         })
     }
 
+    /// Queues a mint to a drop for every metadata json of a manifest. The manifest is either downloaded from `url` or given inline as `metadataJsons`.
+    /// Rows are validated and queued in the background. Poll `dropManifest` with the returned ID to follow the progress and the rows that were rejected.
+    /// # Errors
+    /// If the drop is not found, neither or both of `url` and `metadataJsons` are given, `url` is not an `https` url of a public host, or the manifest cannot be queued, the mutation will result in an error.
+    async fn queue_mints_to_drop_from_manifest(
+        &self,
+        ctx: &Context<'_>,
+        input: QueueMintsToDropFromManifestInput,
+    ) -> Result<QueueMintsToDropFromManifestPayload> {
+        let AppContext { db, user_id, .. } = ctx.data::<AppContext>()?;
+        let job_queue = ctx.data::<JobQueue>()?;
+
+        let conn = db.get();
+
+        let UserID(id) = user_id;
+        let user_id = id.ok_or(Error::new("X-USER-ID header not found"))?;
+
+        let drop = drops::Entity::find_by_id(input.drop)
+            .one(conn)
+            .await?
+            .ok_or(Error::new("drop not found"))?;
+
+        let (source_url, entries) = match (input.url, input.metadata_jsons) {
+            (Some(url), None) => {
+                check_manifest_url(&url)?;
+
+                (Some(url), None)
+            },
+            (None, Some(metadata_jsons)) => (None, Some(serde_json::to_value(metadata_jsons)?)),
+            _ => {
+                return Err(Error::new(
+                    "Exactly one of url or metadataJsons must be provided",
+                ));
+            },
+        };
+
+        let manifest_am = drop_manifests::ActiveModel {
+            drop_id: Set(drop.id),
+            source_url: Set(source_url),
+            entries: Set(entries),
+            status: Set(CreationStatus::Pending),
+            created_by: Set(user_id),
+            ..Default::default()
+        };
+
+        let manifest = manifest_am.insert(conn).await?;
+
+        job_queue
+            .enqueue(DropManifestTask::new(manifest.id))
+            .await?;
+
+        Ok(QueueMintsToDropFromManifestPayload { manifest })
+    }
+
+    /// Replaces the metadata json of a queued mint and uploads it again. The mint must not have been minted yet.
+    /// # Errors
+    /// If the mint is not found or not queued, the metadata json is invalid or the mint cannot be saved, the mutation will result in an error.
+    async fn update_queued_mint(
+        &self,
+        ctx: &Context<'_>,
+        input: UpdateQueuedMintInput,
+    ) -> Result<UpdateQueuedMintPayload> {
+        let AppContext { db, redis, .. } = ctx.data::<AppContext>()?;
+        let metadata_json_upload_job_queue = ctx.data::<JobQueue>()?;
+
+        let conn = db.get();
+        let mut redis_conn = redis.get_async_connection().await?;
+
+        let (mint, collection) = collection_mints::Entity::find_by_id_with_collection(input.id)
+            .one(conn)
+            .await?
+            .ok_or(Error::new("collection mint not found"))?;
+
+        if mint.creation_status != CreationStatus::Queued {
+            return Err(Error::new("mint is not queued"));
+        }
+
+        let collection = collection.ok_or(Error::new("collection not found"))?;
+
+        validate_json(collection.blockchain, &input.metadata_json)?;
+
+        let drop = drops::Entity::find()
+            .filter(drops::Column::CollectionId.eq(collection.id))
+            .one(conn)
+            .await?
+            .ok_or(Error::new("drop not found"))?;
+
+        let tx = conn.begin().await?;
+
+        let queued = collection_mints::Entity::update_many()
+            .set(collection_mints::ActiveModel {
+                creation_status: Set(CreationStatus::Queued),
+                ..Default::default()
+            })
+            .filter(
+                collection_mints::Column::Id
+                    .eq(mint.id)
+                    .and(collection_mints::Column::CreationStatus.eq(CreationStatus::Queued)),
+            )
+            .exec(&tx)
+            .await?;
+
+        if queued.rows_affected == 0 {
+            return Err(Error::new("mint is not queued"));
+        }
+
+        input.metadata_json.save(mint.id, &tx).await?;
+
+        tx.commit().await?;
+
+        metadata_json_upload_job_queue
+            .enqueue(MetadataJsonUploadTask {
+                caller: MetadataJsonUploadCaller::QueueMintToDrop(
+                    MetadataJsonUploadQueueMintToDrop {
+                        drop_id: drop.id,
+                        collection_mint_id: mint.id,
+                    },
+                ),
+                metadata_json: input.metadata_json,
+            })
+            .await?;
+
+        metadata_json_upload_job_queue
+            .enqueue_unless_queued(CollectionRarityTask::new(collection.id))
+            .await?;
+
+        redis_conn
+            .del(format!("collection:{}:supply", collection.id))
+            .await?;
+
+        Ok(UpdateQueuedMintPayload {
+            collection_mint: mint.into(),
+        })
+    }
+
+    /// Removes a queued mint and its metadata json from a drop. The mint must not have been minted yet.
+    /// # Errors
+    /// If the mint is not found or not queued, or the mint cannot be deleted, the mutation will result in an error.
+    async fn delete_queued_mint(
+        &self,
+        ctx: &Context<'_>,
+        input: DeleteQueuedMintInput,
+    ) -> Result<DeleteQueuedMintPayload> {
+        let AppContext { db, redis, .. } = ctx.data::<AppContext>()?;
+        let job_queue = ctx.data::<JobQueue>()?;
+
+        let conn = db.get();
+        let mut redis_conn = redis.get_async_connection().await?;
+
+        let mint = collection_mints::Entity::find_by_id(input.id)
+            .one(conn)
+            .await?
+            .ok_or(Error::new("collection mint not found"))?;
+
+        let tx = conn.begin().await?;
+
+        let deleted = collection_mints::Entity::delete_many()
+            .filter(
+                collection_mints::Column::Id
+                    .eq(mint.id)
+                    .and(collection_mints::Column::CreationStatus.eq(CreationStatus::Queued)),
+            )
+            .exec(&tx)
+            .await?;
+
+        if deleted.rows_affected == 0 {
+            return Err(Error::new("mint is not queued"));
+        }
+
+        metadata_jsons::Entity::delete_by_id(mint.id)
+            .exec(&tx)
+            .await?;
+
+        tx.commit().await?;
+
+        job_queue
+            .enqueue_unless_queued(CollectionRarityTask::new(mint.collection_id))
+            .await?;
+
+        redis_conn
+            .del(format!("collection:{}:supply", mint.collection_id))
+            .await?;
+
+        Ok(DeleteQueuedMintPayload { id: mint.id })
+    }
+
+    /// Removes every queued mint and their metadata jsons from a drop. Minted NFTs are kept.
+    /// # Errors
+    /// If the drop is not found or the mints cannot be deleted, the mutation will result in an error.
+    async fn clear_drop_queue(
+        &self,
+        ctx: &Context<'_>,
+        input: ClearDropQueueInput,
+    ) -> Result<ClearDropQueuePayload> {
+        let AppContext { db, redis, .. } = ctx.data::<AppContext>()?;
+        let job_queue = ctx.data::<JobQueue>()?;
+
+        let conn = db.get();
+        let mut redis_conn = redis.get_async_connection().await?;
+
+        let drop = drops::Entity::find_by_id(input.drop)
+            .one(conn)
+            .await?
+            .ok_or(Error::new("drop not found"))?;
+
+        let queued = collection_mints::Column::CollectionId
+            .eq(drop.collection_id)
+            .and(collection_mints::Column::CreationStatus.eq(CreationStatus::Queued));
+
+        let tx = conn.begin().await?;
+
+        metadata_jsons::Entity::delete_many()
+            .filter(
+                metadata_jsons::Column::Id.in_subquery(
+                    Query::select()
+                        .column(collection_mints::Column::Id)
+                        .from(collection_mints::Entity)
+                        .and_where(queued.clone())
+                        .to_owned(),
+                ),
+            )
+            .exec(&tx)
+            .await?;
+
+        let deleted = collection_mints::Entity::delete_many()
+            .filter(queued)
+            .exec(&tx)
+            .await?;
+
+        tx.commit().await?;
+
+        job_queue
+            .enqueue_unless_queued(CollectionRarityTask::new(drop.collection_id))
+            .await?;
+
+        redis_conn
+            .del(format!("collection:{}:supply", drop.collection_id))
+            .await?;
+
+        Ok(ClearDropQueuePayload {
+            deleted: deleted.rows_affected.try_into()?,
+        })
+    }
+
     /// This mutation mints a specific queued drop mint.
     async fn mint_queued(
         &self,
@@ -987,6 +1438,7 @@ impl Mutation {
         } = ctx.data::<AppContext>()?;
 
         let credits = ctx.data::<CreditsClient<Actions>>()?;
+        let metrics = ctx.data::<Metrics>()?;
         let nfts_producer = ctx.data::<Producer<NftEvents>>()?;
         let solana = ctx.data::<Solana>()?;
 
@@ -1049,7 +1501,7 @@ impl Mutation {
             Actions::Mint
         };
 
-        let TransactionId(deduction_id) = credits
+        let deduction = credits
             .submit_pending_deduction(
                 org_id,
                 user_id,
@@ -1057,10 +1509,14 @@ impl Mutation {
                 collection.blockchain.into(),
                 balance,
             )
-            .await?;
+            .await;
+        metrics.record_credits_deduction(action, collection.blockchain, deduction.is_ok());
+
+        let TransactionId(deduction_id) = deduction?;
 
         let tx = conn.begin().await?;
 
+        let mint_id = mint.id;
         let mut mint_am: collection_mints::ActiveModel = mint.into();
 
         mint_am.creation_status = Set(CreationStatus::Pending);
@@ -1069,7 +1525,7 @@ impl Mutation {
         mint_am.owner = Set(Some(input.recipient.clone()));
         mint_am.seller_fee_basis_points = Set(collection.seller_fee_basis_points);
 
-        let mint = mint_am.update(&tx).await?;
+        let mint = update_queued_mint_status(&tx, mint_id, mint_am).await?;
 
         let mint_history_am = mint_histories::ActiveModel {
             mint_id: Set(mint.id),
@@ -1152,6 +1608,7 @@ impl Mutation {
             ..
         } = ctx.data::<AppContext>()?;
         let credits = ctx.data::<CreditsClient<Actions>>()?;
+        let metrics = ctx.data::<Metrics>()?;
         let conn = db.get();
         let mut redis_conn = redis.get_async_connection().await?;
         let solana = ctx.data::<Solana>()?;
@@ -1208,7 +1665,7 @@ impl Mutation {
             Actions::Mint
         };
 
-        let TransactionId(deduction_id) = credits
+        let deduction = credits
             .submit_pending_deduction(
                 org_id,
                 user_id,
@@ -1216,10 +1673,14 @@ impl Mutation {
                 collection.blockchain.into(),
                 balance,
             )
-            .await?;
+            .await;
+        metrics.record_credits_deduction(action, collection.blockchain, deduction.is_ok());
+
+        let TransactionId(deduction_id) = deduction?;
 
         let tx = conn.begin().await?;
 
+        let mint_id = mint.id;
         let mut mint_am: collection_mints::ActiveModel = mint.into();
 
         mint_am.creation_status = Set(CreationStatus::Pending);
@@ -1228,7 +1689,7 @@ impl Mutation {
         mint_am.owner = Set(Some(input.recipient.clone()));
         mint_am.seller_fee_basis_points = Set(collection.seller_fee_basis_points);
 
-        let mint = mint_am.update(&tx).await?;
+        let mint = update_queued_mint_status(&tx, mint_id, mint_am).await?;
 
         let mint_history_am = mint_histories::ActiveModel {
             mint_id: Set(mint.id),
@@ -1315,6 +1776,7 @@ impl Mutation {
             ..
         } = ctx.data::<AppContext>()?;
         let credits = ctx.data::<CreditsClient<Actions>>()?;
+        let metrics = ctx.data::<Metrics>()?;
         let conn = db.get();
         let nfts_producer = ctx.data::<Producer<NftEvents>>()?;
 
@@ -1404,7 +1866,7 @@ impl Mutation {
                 .uri
                 .ok_or(Error::new("No metadata json uri found"))?;
 
-            let TransactionId(deduction_id) = credits
+            let deduction = credits
                 .submit_pending_deduction(
                     org_id,
                     user_id,
@@ -1412,10 +1874,14 @@ impl Mutation {
                     collection.blockchain.into(),
                     balance,
                 )
-                .await?;
+                .await;
+            metrics.record_credits_deduction(action, collection.blockchain, deduction.is_ok());
+
+            let TransactionId(deduction_id) = deduction?;
 
             let tx = conn.begin().await?;
 
+            let mint_id = mint.id;
             let mut mint_am: collection_mints::ActiveModel = mint.into();
 
             mint_am.creation_status = Set(CreationStatus::Pending);
@@ -1424,7 +1890,7 @@ impl Mutation {
             mint_am.owner = Set(Some(recipient.clone()));
             mint_am.seller_fee_basis_points = Set(collection.seller_fee_basis_points);
 
-            let mint = mint_am.update(&tx).await?;
+            let mint = update_queued_mint_status(&tx, mint_id, mint_am).await?;
 
             let mint_history_am = mint_histories::ActiveModel {
                 mint_id: Set(mint.id),
@@ -1543,6 +2009,31 @@ fn check_collection_status(collection_model: &collections::Model) -> Result<(),
     Ok(())
 }
 
+/// Saves a queued mint only if it is still queued, so two requests cannot both take the same mint off the queue.
+/// # Errors
+/// This function returns an error if the mint is no longer queued or cannot be saved
+async fn update_queued_mint_status(
+    tx: &DatabaseTransaction,
+    mint_id: Uuid,
+    mint_am: collection_mints::ActiveModel,
+) -> Result<collection_mints::Model> {
+    let updated = collection_mints::Entity::update_many()
+        .set(mint_am.clone())
+        .filter(
+            collection_mints::Column::Id
+                .eq(mint_id)
+                .and(collection_mints::Column::CreationStatus.eq(CreationStatus::Queued)),
+        )
+        .exec(tx)
+        .await?;
+
+    if updated.rows_affected == 0 {
+        return Err(Error::new("mint is not queued"));
+    }
+
+    Ok(mint_am.try_into_model()?)
+}
+
 /// Represents input data for `mint_edition` mutation with a UUID and recipient as fields
 #[derive(Debug, Clone, InputObject)]
 pub struct MintDropInput {
@@ -1614,6 +2105,84 @@ pub struct UpdateMintPayload {
     collection_mint: CollectionMint,
 }
 
+/// Input object for republishing an earlier metadata json version of a mint.
+#[derive(Debug, Clone, InputObject)]
+pub struct RollbackMintMetadataInput {
+    /// The ID of the mint.
+    pub mint_id: Uuid,
+    /// The number of the version to republish.
+    pub version: i32,
+}
+
+/// Represents the result of a successful rollback mint metadata mutation.
+#[derive(Debug, Clone, SimpleObject)]
+pub struct RollbackMintMetadataPayload {
+    /// The updated mint.
+    collection_mint: CollectionMint,
+    /// The new latest version, a copy of the republished version.
+    metadata_version: metadata_json_versions::Model,
+}
+
+/// Records the metadata json of an update as the next version of the mint.
+/// When the mint has no versions yet, its current metadata json is first recorded as version 1 so it can be rolled back to.
+/// Metadata jsons without a saved document cannot be restored in full, so they are not recorded and the update is version 1.
+/// Must be called before the new metadata json is saved.
+pub(crate) async fn record_metadata_version(
+    tx: &DatabaseTransaction,
+    mint_id: Uuid,
+    update_history_id: Uuid,
+    metadata_json: &MetadataJsonInput,
+    created_by: Uuid,
+) -> Result<metadata_json_versions::Model> {
+    let latest = metadata_json_versions::Entity::find()
+        .filter(metadata_json_versions::Column::CollectionMintId.eq(mint_id))
+        .order_by_desc(metadata_json_versions::Column::Version)
+        .one(tx)
+        .await?;
+
+    let next = if let Some(latest) = latest {
+        latest.version + 1
+    } else if let Some(metadata_jsons::Model {
+        document: Some(document),
+        uri,
+        identifier,
+        ..
+    }) = metadata_jsons::Entity::find_by_id(mint_id).one(tx).await?
+    {
+        let original_am = metadata_json_versions::ActiveModel {
+            collection_mint_id: Set(mint_id),
+            version: Set(1),
+            update_history_id: Set(None),
+            content_hash: Set(canonical_hash(&document)),
+            metadata_json: Set(document),
+            uri: Set(uri),
+            identifier: Set(identifier),
+            created_by: Set(created_by),
+            ..Default::default()
+        };
+
+        original_am.insert(tx).await?;
+
+        2
+    } else {
+        1
+    };
+
+    let version_am = metadata_json_versions::ActiveModel {
+        collection_mint_id: Set(mint_id),
+        version: Set(next),
+        update_history_id: Set(Some(update_history_id)),
+        metadata_json: Set(serde_json::to_value(metadata_json)?),
+        content_hash: Set(metadata_json.content_hash()?),
+        uri: Set(None),
+        identifier: Set(None),
+        created_by: Set(created_by),
+        ..Default::default()
+    };
+
+    version_am.insert(tx).await.map_err(Into::into)
+}
+
 /// Represents input data for `retry_mint_to_collection` mutation with an ID as a field of type UUID
 #[derive(Debug, Clone, InputObject)]
 pub struct RetryMintToCollectionInput {
@@ -1652,6 +2221,67 @@ pub struct QueueMintToDropPayload {
     collection_mint: CollectionMint,
 }
 
+/// Represents input data for `queue_mints_to_drop_from_manifest` mutation
+#[derive(Debug, Clone, InputObject)]
+pub struct QueueMintsToDropFromManifestInput {
+    /// The ID of the drop to queue the mints to.
+    drop: Uuid,
+    /// The URL of a JSON array of metadata jsons. Must be an `https` url of a public host.
+    url: Option<String>,
+    /// The metadata jsons of the mints.
+    metadata_jsons: Option<Vec<MetadataJsonInput>>,
+}
+
+/// Represents payload data for `queue_mints_to_drop_from_manifest` mutation
+#[derive(Debug, Clone, SimpleObject)]
+pub struct QueueMintsToDropFromManifestPayload {
+    /// The manifest being processed.
+    manifest: drop_manifests::Model,
+}
+
+/// Represents input data for `update_queued_mint` mutation
+#[derive(Debug, Clone, InputObject)]
+pub struct UpdateQueuedMintInput {
+    /// The ID of the queued mint.
+    id: Uuid,
+    /// The new metadata json of the mint.
+    metadata_json: MetadataJsonInput,
+}
+
+/// Represents payload data for `update_queued_mint` mutation
+#[derive(Debug, Clone, SimpleObject)]
+pub struct UpdateQueuedMintPayload {
+    collection_mint: CollectionMint,
+}
+
+/// Represents input data for `delete_queued_mint` mutation
+#[derive(Debug, Clone, InputObject)]
+pub struct DeleteQueuedMintInput {
+    /// The ID of the queued mint.
+    id: Uuid,
+}
+
+/// Represents payload data for `delete_queued_mint` mutation
+#[derive(Debug, Clone, SimpleObject)]
+pub struct DeleteQueuedMintPayload {
+    /// The ID of the deleted mint.
+    id: Uuid,
+}
+
+/// Represents input data for `clear_drop_queue` mutation
+#[derive(Debug, Clone, InputObject)]
+pub struct ClearDropQueueInput {
+    /// The ID of the drop.
+    drop: Uuid,
+}
+
+/// Represents payload data for `clear_drop_queue` mutation
+#[derive(Debug, Clone, SimpleObject)]
+pub struct ClearDropQueuePayload {
+    /// The number of queued mints deleted.
+    deleted: i64,
+}
+
 /// Represents input data for `mint_queued` mutation
 #[derive(Debug, Clone, InputObject)]
 pub struct MintQueuedInput {
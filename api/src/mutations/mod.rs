@@ -2,8 +2,11 @@
 #![allow(clippy::unused_async)]
 pub mod collection;
 pub mod drop;
+pub mod export;
+pub mod holder_snapshot;
 pub mod mint;
 pub mod transfer;
+pub mod webhook;
 
 // // Add your other ones here to create a unified Mutation object
 // // e.x. Mutation(OrganizationMutation, OtherMutation, OtherOtherMutation)
@@ -13,4 +16,7 @@ pub struct Mutation(
     mint::Mutation,
     transfer::Mutation,
     drop::Mutation,
+    holder_snapshot::Mutation,
+    export::Mutation,
+    webhook::Mutation,
 );
@@ -1,17 +1,23 @@
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+
 use async_graphql::{Context, Error, InputObject, Object, Result, SimpleObject};
-use hub_core::credits::{CreditsClient, TransactionId};
-use sea_orm::{prelude::*, Set};
+use hub_core::{
+    chrono::Utc,
+    credits::{CreditsClient, TransactionId},
+};
+use sea_orm::{prelude::*, Set, TransactionTrait};
 use serde::{Deserialize, Serialize};
 
 use super::collection::{validate_evm_address, validate_solana_address};
 use crate::{
     blockchains::{polygon::Polygon, solana::Solana, TransferEvent},
     entities::{
-        collection_mints,
-        prelude::CustomerWallets,
+        collection_mints, collections,
+        prelude::{CustomerWallets, ProjectWallets, Transfers},
         sea_orm_active_enums::{Blockchain, CreationStatus},
-        transfer_charges,
+        transfer_charges, transfers,
     },
+    metrics::Metrics,
     objects::CollectionMint,
     proto::{self, NftEventKey, TransferPolygonAsset},
     Actions, AppContext, OrganizationId, UserID,
@@ -24,6 +30,7 @@ pub struct Mutation;
 impl Mutation {
     /// Transfers an asset from one user to another on a supported blockchain network.
     /// The mutation supports transferring standard or compressed NFTs.
+    /// The sender can be a customer wallet or the treasury wallet of the project the NFT belongs to.
     /// The mutation is rejected if the wallet address is not managed by HUB.
     pub async fn transfer_asset(
         &self,
@@ -40,6 +47,42 @@ impl Mutation {
         let UserID(id) = user_id;
         let OrganizationId(org) = organization_id;
 
+        let user_id = id.ok_or(Error::new("X-USER-ID header not found"))?;
+        let org_id = org.ok_or(Error::new("X-ORGANIZATION-ID header not found"))?;
+        let balance = balance
+            .0
+            .ok_or(Error::new("X-CREDIT-BALANCE header not found"))?;
+
+        let transfer = input.prepare(db.get()).await?;
+
+        let transfer_model = transfer.submit(ctx, org_id, user_id, balance, None).await?;
+
+        Ok(TransferAssetPayload {
+            mint: transfer.mint.into(),
+            transfer: transfer_model,
+        })
+    }
+
+    /// Transfers a batch of assets in a single request.
+    /// Every transfer is validated before any of them is submitted, so an invalid recipient or an NFT not held by a HUB managed wallet rejects the whole batch.
+    /// The batch is also rejected when the credit balance does not cover the cost of every transfer.
+    /// Credits are deducted for each transfer and the transfers share a batch ID which can be used to follow their progress with the `transferBatch` query.
+    /// Transfers that fail to be submitted are returned in `errors` alongside the transfers that were submitted.
+    pub async fn transfer_assets_batched(
+        &self,
+        ctx: &Context<'_>,
+        input: TransferAssetsBatchedInput,
+    ) -> Result<TransferAssetsBatchedPayload> {
+        let AppContext {
+            db,
+            user_id,
+            organization_id,
+            balance,
+            ..
+        } = ctx.data::<AppContext>()?;
+        let UserID(id) = user_id;
+        let OrganizationId(org) = organization_id;
+
         let user_id = id.ok_or(Error::new("X-USER-ID header not found"))?;
         let org_id = org.ok_or(Error::new("X-ORGANIZATION-ID header not found"))?;
         let balance = balance
@@ -47,42 +90,205 @@ impl Mutation {
             .ok_or(Error::new("X-CREDIT-BALANCE header not found"))?;
 
         let conn = db.get();
+
+        if input.transfers.is_empty() {
+            return Err(Error::new("No transfers provided"));
+        }
+
+        let mut mint_ids = HashSet::new();
+        let mut transfers = Vec::with_capacity(input.transfers.len());
+
+        for transfer in input.transfers {
+            if !mint_ids.insert(transfer.id) {
+                return Err(Error::new(format!(
+                    "Mint {} is included more than once in the batch",
+                    transfer.id
+                )));
+            }
+
+            let id = transfer.id;
+            let transfer = transfer
+                .prepare(conn)
+                .await
+                .map_err(|e| Error::new(format!("Unable to transfer mint {id}: {}", e.message)))?;
+
+            transfers.push(transfer);
+        }
+
         let credits = ctx.data::<CreditsClient<Actions>>()?;
+        let mut costs = HashMap::new();
+        let mut total_cost = 0u64;
+
+        for transfer in &transfers {
+            let blockchain = transfer.collection.blockchain;
+
+            let cost = match costs.entry(blockchain) {
+                Entry::Occupied(entry) => *entry.get(),
+                Entry::Vacant(entry) => *entry.insert(transfer_cost(credits, blockchain)?),
+            };
 
-        let TransferAssetInput { id, recipient } = input.clone();
+            total_cost = total_cost.saturating_add(cost);
+        }
+
+        if total_cost > balance {
+            return Err(Error::new(format!(
+                "Insufficient credits: the batch costs {total_cost} credits and the balance is {balance}"
+            )));
+        }
+
+        let batch_id = Uuid::new_v4();
+        let mut mints = Vec::with_capacity(transfers.len());
+        let mut transfer_models = Vec::with_capacity(transfers.len());
+        let mut errors = Vec::new();
+
+        for transfer in transfers {
+            match transfer
+                .submit(ctx, org_id, user_id, balance, Some(batch_id))
+                .await
+            {
+                Ok(transfer_model) => {
+                    mints.push(transfer.mint.into());
+                    transfer_models.push(transfer_model);
+                },
+                Err(e) => errors.push(TransferAssetsBatchedError {
+                    mint: transfer.mint.id,
+                    message: e.message,
+                }),
+            }
+        }
+
+        if transfer_models.is_empty() {
+            let message = errors
+                .into_iter()
+                .next()
+                .map_or_else(String::new, |e| e.message);
+
+            return Err(Error::new(format!("Unable to submit batch: {message}")));
+        }
+
+        Ok(TransferAssetsBatchedPayload {
+            batch_id,
+            mints,
+            transfers: transfer_models,
+            errors,
+        })
+    }
+
+    /// Retries a transfer which failed by passing the ID of the transfer.
+    /// The original transfer record and credits deduction are reused so no additional credits are deducted.
+    /// # Errors
+    /// If the transfer has not failed, the NFT is no longer held by the sender or the transfer fails to be emitted for submission to the desired blockchain, the mutation will result in an error.
+    pub async fn retry_transfer_asset(
+        &self,
+        ctx: &Context<'_>,
+        input: RetryTransferAssetInput,
+    ) -> Result<RetryTransferAssetPayload> {
+        let AppContext { db, user_id, .. } = ctx.data::<AppContext>()?;
+        let UserID(id) = user_id;
+        let user_id = id.ok_or(Error::new("X-USER-ID header not found"))?;
+
+        let conn = db.get();
 
-        let (collection_mint_model, collection) =
-            collection_mints::Entity::find_by_id_with_collection(id)
+        let transfer = Transfers::find_by_id(input.id)
+            .one(conn)
+            .await?
+            .ok_or(Error::new("transfer not found"))?;
+
+        if transfer.status != CreationStatus::Failed {
+            return Err(Error::new("only failed transfers can be retried"));
+        }
+
+        let (mint, collection) =
+            collection_mints::Entity::find_by_id_with_collection(transfer.collection_mint_id)
                 .one(conn)
                 .await?
                 .ok_or(Error::new("mint not found"))?;
 
-        if collection_mint_model.creation_status != CreationStatus::Created {
-            return Err(Error::new("NFT is not minted"));
+        let collection = collection.ok_or(Error::new("collection not found"))?;
+
+        if mint.owner.as_ref() != Some(&transfer.sender) {
+            return Err(Error::new("NFT is no longer held by the sender wallet"));
         }
 
-        let collection = collection.ok_or(Error::new("collection not found"))?;
-        input.validate_recipient_address(collection.blockchain)?;
+        let tx = conn.begin().await?;
 
-        let owner_address = collection_mint_model
-            .owner
-            .clone()
-            .ok_or(Error::new("NFT is not owned by any wallet"))?;
+        // the status is checked again so concurrent retries emit the transfer once
+        let res = Transfers::update_many()
+            .set(transfers::ActiveModel {
+                status: Set(CreationStatus::Pending),
+                updated_at: Set(Utc::now().into()),
+                ..Default::default()
+            })
+            .filter(
+                transfers::Column::Id
+                    .eq(transfer.id)
+                    .and(transfers::Column::Status.eq(CreationStatus::Failed)),
+            )
+            .exec(&tx)
+            .await?;
 
-        CustomerWallets::find_by_address(owner_address.clone())
-            .one(conn)
+        if res.rows_affected == 0 {
+            return Err(Error::new("only failed transfers can be retried"));
+        }
+
+        let transfer = Transfers::find_by_id(transfer.id)
+            .one(&tx)
             .await?
-            .ok_or(Error::new("Sender wallet is not managed by HUB"))?;
+            .ok_or(Error::new("transfer not found"))?;
 
-        let TransactionId(credits_deduction_id) = credits
+        tx.commit().await?;
+
+        let event_key = NftEventKey {
+            id: transfer.id.to_string(),
+            user_id: user_id.to_string(),
+            project_id: collection.project_id.to_string(),
+        };
+
+        emit_transfer(ctx, &collection, event_key, &transfer).await?;
+
+        Ok(RetryTransferAssetPayload { transfer })
+    }
+}
+
+/// A transfer which passed validation and is ready to be submitted.
+struct Transfer {
+    mint: collection_mints::Model,
+    collection: collections::Model,
+    owner_address: String,
+    recipient_address: String,
+}
+
+impl Transfer {
+    /// Submits the pending credit deduction, records the transfer charge and transfer and emits the transfer event for the blockchain of the collection.
+    async fn submit(
+        &self,
+        ctx: &Context<'_>,
+        org_id: Uuid,
+        user_id: Uuid,
+        balance: u64,
+        batch_id: Option<Uuid>,
+    ) -> Result<transfers::Model> {
+        let AppContext { db, .. } = ctx.data::<AppContext>()?;
+        let credits = ctx.data::<CreditsClient<Actions>>()?;
+        let metrics = ctx.data::<Metrics>()?;
+        let conn = db.get();
+
+        let deduction = credits
             .submit_pending_deduction(
                 org_id,
                 user_id,
                 Actions::TransferAsset,
-                collection.blockchain.into(),
+                self.collection.blockchain.into(),
                 balance,
             )
-            .await?;
+            .await;
+        metrics.record_credits_deduction(
+            Actions::TransferAsset,
+            self.collection.blockchain,
+            deduction.is_ok(),
+        );
+
+        let TransactionId(credits_deduction_id) = deduction?;
 
         let transfer_charges_am = transfer_charges::ActiveModel {
             credits_deduction_id: Set(Some(credits_deduction_id)),
@@ -90,51 +296,109 @@ impl Mutation {
         };
 
         let transfer_charge_model = transfer_charges_am.insert(conn).await?;
+
+        let transfer_am = transfers::ActiveModel {
+            id: Set(transfer_charge_model.id),
+            collection_mint_id: Set(self.mint.id),
+            sender: Set(self.owner_address.clone()),
+            recipient: Set(self.recipient_address.clone()),
+            status: Set(CreationStatus::Pending),
+            credits_deduction_id: Set(credits_deduction_id),
+            batch_id: Set(batch_id),
+            created_by: Set(user_id),
+            ..Default::default()
+        };
+
+        let transfer_model = transfer_am.insert(conn).await?;
         let event_key = NftEventKey {
             id: transfer_charge_model.id.to_string(),
             user_id: user_id.to_string(),
-            project_id: collection.project_id.to_string(),
+            project_id: self.collection.project_id.to_string(),
         };
 
-        let collection_mint_id = collection_mint_model.id.to_string();
-        let recipient_address = recipient.to_string();
-
-        match collection.blockchain {
-            Blockchain::Solana => {
-                let solana = ctx.data::<Solana>()?;
-
-                solana
-                    .event()
-                    .transfer_asset(event_key, proto::TransferMetaplexAssetTransaction {
-                        recipient_address,
-                        owner_address,
-                        collection_mint_id,
-                    })
-                    .await?;
-            },
-            Blockchain::Polygon => {
-                let polygon = ctx.data::<Polygon>()?;
-                polygon
-                    .event()
-                    .transfer_asset(event_key, TransferPolygonAsset {
-                        collection_mint_id,
-                        owner_address,
-                        recipient_address,
-                        amount: 1,
-                    })
-                    .await?;
-            },
-            Blockchain::Ethereum => {
-                return Err(Error::new("blockchain not supported as this time"));
-            },
-        };
+        emit_transfer(ctx, &self.collection, event_key, &transfer_model).await?;
 
-        Ok(TransferAssetPayload {
-            mint: collection_mint_model.into(),
-        })
+        Ok(transfer_model)
     }
 }
 
+/// Returns the credits deducted for transferring an asset on the blockchain.
+fn transfer_cost(credits: &CreditsClient<Actions>, blockchain: Blockchain) -> Result<u64> {
+    credits
+        .get_cost(Actions::TransferAsset, blockchain.into())
+        .ok_or(Error::new("Transfer cost not found for the blockchain"))
+}
+
+/// Emits the transfer event for the blockchain of the collection.
+async fn emit_transfer(
+    ctx: &Context<'_>,
+    collection: &collections::Model,
+    event_key: NftEventKey,
+    transfer: &transfers::Model,
+) -> Result<()> {
+    let collection_mint_id = transfer.collection_mint_id.to_string();
+    let recipient_address = transfer.recipient.clone();
+    let owner_address = transfer.sender.clone();
+
+    match collection.blockchain {
+        Blockchain::Solana => {
+            let solana = ctx.data::<Solana>()?;
+
+            solana
+                .event()
+                .transfer_asset(event_key, proto::TransferMetaplexAssetTransaction {
+                    recipient_address,
+                    owner_address,
+                    collection_mint_id,
+                })
+                .await?;
+        },
+        Blockchain::Polygon => {
+            let polygon = ctx.data::<Polygon>()?;
+            polygon
+                .event()
+                .transfer_asset(event_key, TransferPolygonAsset {
+                    collection_mint_id,
+                    owner_address,
+                    recipient_address,
+                    amount: 1,
+                })
+                .await?;
+        },
+        Blockchain::Ethereum => {
+            return Err(Error::new("blockchain not supported as this time"));
+        },
+    };
+
+    Ok(())
+}
+
+/// Fetches the HUB managed wallet that holds the NFT and signs the transfer.
+/// The sender is either a customer wallet or the treasury wallet of the project the collection belongs to.
+/// # Returns
+/// - Returns a `Result<String>` containing the wallet address as stored by HUB so the treasury service signs with the matching key.
+/// # Errors
+/// - Returns an error if the owner is not a customer wallet or a wallet of the project.
+pub async fn fetch_sender(
+    conn: &DatabaseConnection,
+    project_id: Uuid,
+    owner_address: String,
+) -> Result<String> {
+    if let Some(customer_wallet) = CustomerWallets::find_by_address(owner_address.clone())
+        .one(conn)
+        .await?
+    {
+        return Ok(customer_wallet.address);
+    }
+
+    let project_wallet = ProjectWallets::find_by_project_and_address(project_id, owner_address)
+        .one(conn)
+        .await?
+        .ok_or(Error::new("Sender wallet is not managed by HUB"))?;
+
+    Ok(project_wallet.wallet_address)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, InputObject)]
 pub struct TransferAssetInput {
     pub id: Uuid,
@@ -149,9 +413,83 @@ impl TransferAssetInput {
             Blockchain::Solana => validate_solana_address(&self.recipient),
         }
     }
+
+    /// Checks the mint can be transferred to the recipient and resolves the wallet sending it.
+    async fn prepare(self, conn: &DatabaseConnection) -> Result<Transfer> {
+        let (mint, collection) = collection_mints::Entity::find_by_id_with_collection(self.id)
+            .one(conn)
+            .await?
+            .ok_or(Error::new("mint not found"))?;
+
+        if mint.creation_status != CreationStatus::Created {
+            return Err(Error::new("NFT is not minted"));
+        }
+
+        let collection = collection.ok_or(Error::new("collection not found"))?;
+        self.validate_recipient_address(collection.blockchain)?;
+
+        let owner_address = mint
+            .owner
+            .clone()
+            .ok_or(Error::new("NFT is not owned by any wallet"))?;
+
+        let owner_address = fetch_sender(conn, collection.project_id, owner_address).await?;
+
+        Ok(Transfer {
+            mint,
+            collection,
+            owner_address,
+            recipient_address: self.recipient,
+        })
+    }
 }
 
 #[derive(Debug, Clone, SimpleObject)]
 pub struct TransferAssetPayload {
     pub mint: CollectionMint,
+    /// The transfer record. Its status is updated once the transaction is submitted to the blockchain.
+    pub transfer: transfers::Model,
+}
+
+/// Input object for transferring a batch of assets.
+#[derive(Debug, Clone, Serialize, Deserialize, InputObject)]
+pub struct TransferAssetsBatchedInput {
+    /// The mints to transfer and the wallet address each of them is sent to.
+    pub transfers: Vec<TransferAssetInput>,
+}
+
+/// Represents the result of a successful batched transfer mutation.
+#[derive(Debug, Clone, SimpleObject)]
+pub struct TransferAssetsBatchedPayload {
+    /// The ID shared by the transfers of the batch.
+    pub batch_id: Uuid,
+    /// The mints being transferred.
+    pub mints: Vec<CollectionMint>,
+    /// The transfer records of the batch.
+    pub transfers: Vec<transfers::Model>,
+    /// The transfers of the batch that failed to be submitted.
+    pub errors: Vec<TransferAssetsBatchedError>,
+}
+
+/// A transfer of a batch that failed to be submitted.
+#[derive(Debug, Clone, SimpleObject)]
+pub struct TransferAssetsBatchedError {
+    /// The ID of the mint that was not transferred.
+    pub mint: Uuid,
+    /// The reason the transfer failed.
+    pub message: String,
+}
+
+/// Input object for retrying a failed transfer.
+#[derive(Debug, Clone, Serialize, Deserialize, InputObject)]
+pub struct RetryTransferAssetInput {
+    /// The ID of the failed transfer.
+    pub id: Uuid,
+}
+
+/// Represents the result of a successful retry transfer mutation.
+#[derive(Debug, Clone, SimpleObject)]
+pub struct RetryTransferAssetPayload {
+    /// The transfer being retried.
+    pub transfer: transfers::Model,
 }
@@ -0,0 +1,267 @@
+use std::collections::HashSet;
+
+use async_graphql::{Context, Error, InputObject, Object, Result, SimpleObject};
+use hub_core::chrono::Utc;
+use sea_orm::{prelude::*, Set, TransactionTrait};
+
+use crate::{
+    background_worker::{job_queue::JobQueue, tasks::WebhookDeliveryTask},
+    entities::{
+        sea_orm_active_enums::{WebhookDeliveryStatus, WebhookEventType},
+        webhook_deliveries, webhook_endpoints, webhook_subscriptions,
+    },
+    webhooks::{generate_secret, queued_until, Webhooks},
+    AppContext, UserID,
+};
+
+#[derive(Default)]
+pub struct Mutation;
+
+#[Object(name = "WebhookMutation")]
+impl Mutation {
+    /// Registers a URL of a project to be sent signed webhooks for the given events.
+    /// The returned `secret` signs every webhook sent to the endpoint and is not returned again.
+    /// # Errors
+    /// If the url is not a valid `https` url of a public host, no events are given or the endpoint cannot be saved, the mutation will result in an error.
+    pub async fn create_webhook_endpoint(
+        &self,
+        ctx: &Context<'_>,
+        input: CreateWebhookEndpointInput,
+    ) -> Result<CreateWebhookEndpointPayload> {
+        let AppContext { db, user_id, .. } = ctx.data::<AppContext>()?;
+        let webhooks = ctx.data::<Webhooks>()?;
+        let UserID(id) = user_id;
+        let user_id = id.ok_or(Error::new("X-USER-ID header not found"))?;
+
+        let url = webhooks
+            .validate_url(&input.url)
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        if input.events.is_empty() {
+            return Err(Error::new("At least one event must be subscribed to"));
+        }
+
+        let secret = generate_secret();
+
+        let tx = db.get().begin().await?;
+
+        let endpoint_am = webhook_endpoints::ActiveModel {
+            project_id: Set(input.project),
+            url: Set(url.to_string()),
+            secret: Set(secret.clone()),
+            description: Set(input.description),
+            active: Set(true),
+            created_by: Set(user_id),
+            ..Default::default()
+        };
+
+        let endpoint = endpoint_am.insert(&tx).await?;
+
+        subscribe(&tx, endpoint.id, input.events).await?;
+
+        tx.commit().await?;
+
+        Ok(CreateWebhookEndpointPayload { endpoint, secret })
+    }
+
+    /// Changes the url, description, events or active state of a webhook endpoint. Fields that are not given are left unchanged.
+    /// # Errors
+    /// If the endpoint is not found, the url is invalid, the events are empty or the endpoint cannot be saved, the mutation will result in an error.
+    pub async fn update_webhook_endpoint(
+        &self,
+        ctx: &Context<'_>,
+        input: UpdateWebhookEndpointInput,
+    ) -> Result<UpdateWebhookEndpointPayload> {
+        let AppContext { db, .. } = ctx.data::<AppContext>()?;
+        let webhooks = ctx.data::<Webhooks>()?;
+
+        let endpoint = webhook_endpoints::Entity::find_by_id(input.id)
+            .one(db.get())
+            .await?
+            .ok_or(Error::new("Webhook endpoint not found"))?;
+
+        let tx = db.get().begin().await?;
+
+        let mut endpoint_am: webhook_endpoints::ActiveModel = endpoint.into();
+
+        if let Some(url) = input.url {
+            let url = webhooks
+                .validate_url(&url)
+                .await
+                .map_err(|e| Error::new(e.to_string()))?;
+
+            endpoint_am.url = Set(url.to_string());
+        }
+
+        if let Some(description) = input.description {
+            endpoint_am.description = Set(Some(description));
+        }
+
+        if let Some(active) = input.active {
+            endpoint_am.active = Set(active);
+        }
+
+        if let Some(events) = input.events {
+            if events.is_empty() {
+                return Err(Error::new("At least one event must be subscribed to"));
+            }
+
+            webhook_subscriptions::Entity::delete_many()
+                .filter(webhook_subscriptions::Column::EndpointId.eq(input.id))
+                .exec(&tx)
+                .await?;
+
+            subscribe(&tx, input.id, events).await?;
+        }
+
+        endpoint_am.updated_at = Set(Utc::now().into());
+
+        let endpoint = endpoint_am.update(&tx).await?;
+
+        tx.commit().await?;
+
+        Ok(UpdateWebhookEndpointPayload { endpoint })
+    }
+
+    /// Deletes a webhook endpoint with its subscriptions and deliveries.
+    /// # Errors
+    /// If the endpoint is not found or cannot be deleted, the mutation will result in an error.
+    pub async fn delete_webhook_endpoint(
+        &self,
+        ctx: &Context<'_>,
+        input: DeleteWebhookEndpointInput,
+    ) -> Result<DeleteWebhookEndpointPayload> {
+        let AppContext { db, .. } = ctx.data::<AppContext>()?;
+
+        let result = webhook_endpoints::Entity::delete_by_id(input.id)
+            .exec(db.get())
+            .await?;
+
+        if result.rows_affected == 0 {
+            return Err(Error::new("Webhook endpoint not found"));
+        }
+
+        Ok(DeleteWebhookEndpointPayload { id: input.id })
+    }
+
+    /// Sends a webhook delivery again, e.g. after a failed delivery has been fixed on the endpoint. The delivery keeps its ID and payload.
+    /// # Errors
+    /// If the delivery is not found or cannot be queued, the mutation will result in an error.
+    pub async fn redeliver_webhook(
+        &self,
+        ctx: &Context<'_>,
+        input: RedeliverWebhookInput,
+    ) -> Result<RedeliverWebhookPayload> {
+        let AppContext { db, .. } = ctx.data::<AppContext>()?;
+        let job_queue = ctx.data::<JobQueue>()?;
+
+        let delivery = webhook_deliveries::Entity::find_by_id(input.id)
+            .one(db.get())
+            .await?
+            .ok_or(Error::new("Webhook delivery not found"))?;
+
+        let mut delivery_am: webhook_deliveries::ActiveModel = delivery.into();
+
+        delivery_am.status = Set(WebhookDeliveryStatus::Pending);
+        delivery_am.attempts = Set(0);
+        delivery_am.next_attempt_at = Set(Some(queued_until(Utc::now()).into()));
+        delivery_am.updated_at = Set(Utc::now().into());
+
+        let delivery = delivery_am.update(db.get()).await?;
+
+        job_queue
+            .enqueue(WebhookDeliveryTask::new(delivery.id))
+            .await?;
+
+        Ok(RedeliverWebhookPayload { delivery })
+    }
+}
+
+/// Subscribes an endpoint to each of the events once.
+async fn subscribe(
+    conn: &impl ConnectionTrait,
+    endpoint_id: Uuid,
+    events: Vec<WebhookEventType>,
+) -> Result<()> {
+    let subscriptions = events
+        .into_iter()
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .map(|event_type| webhook_subscriptions::ActiveModel {
+            endpoint_id: Set(endpoint_id),
+            event_type: Set(event_type),
+            ..Default::default()
+        });
+
+    webhook_subscriptions::Entity::insert_many(subscriptions)
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
+
+/// Input object for creating a webhook endpoint.
+#[derive(Debug, Clone, InputObject)]
+pub struct CreateWebhookEndpointInput {
+    /// The ID of the project the endpoint receives events for.
+    pub project: Uuid,
+    /// The `https` URL webhooks are posted to.
+    pub url: String,
+    /// The events sent to the endpoint.
+    pub events: Vec<WebhookEventType>,
+    pub description: Option<String>,
+}
+
+/// Represents the result of a successful create webhook endpoint mutation.
+#[derive(Debug, Clone, SimpleObject)]
+pub struct CreateWebhookEndpointPayload {
+    endpoint: webhook_endpoints::Model,
+    /// The secret webhooks sent to the endpoint are signed with. Verify the `X-Hub-Webhook-Signature` header by computing the HMAC-SHA256 of `{X-Hub-Webhook-Timestamp}.{body}` with it.
+    secret: String,
+}
+
+/// Input object for updating a webhook endpoint.
+#[derive(Debug, Clone, InputObject)]
+pub struct UpdateWebhookEndpointInput {
+    pub id: Uuid,
+    pub url: Option<String>,
+    /// Replaces the events sent to the endpoint.
+    pub events: Option<Vec<WebhookEventType>>,
+    pub description: Option<String>,
+    /// Pauses or resumes sending webhooks to the endpoint.
+    pub active: Option<bool>,
+}
+
+/// Represents the result of a successful update webhook endpoint mutation.
+#[derive(Debug, Clone, SimpleObject)]
+pub struct UpdateWebhookEndpointPayload {
+    endpoint: webhook_endpoints::Model,
+}
+
+/// Input object for deleting a webhook endpoint.
+#[derive(Debug, Clone, InputObject)]
+pub struct DeleteWebhookEndpointInput {
+    pub id: Uuid,
+}
+
+/// Represents the result of a successful delete webhook endpoint mutation.
+#[derive(Debug, Clone, SimpleObject)]
+pub struct DeleteWebhookEndpointPayload {
+    /// The ID of the deleted endpoint.
+    id: Uuid,
+}
+
+/// Input object for redelivering a webhook.
+#[derive(Debug, Clone, InputObject)]
+pub struct RedeliverWebhookInput {
+    /// The ID of the webhook delivery.
+    pub id: Uuid,
+}
+
+/// Represents the result of a successful redeliver webhook mutation.
+#[derive(Debug, Clone, SimpleObject)]
+pub struct RedeliverWebhookPayload {
+    /// The queued delivery.
+    delivery: webhook_deliveries::Model,
+}
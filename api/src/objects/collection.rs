@@ -1,8 +1,12 @@
 use async_graphql::{Context, Error, Object, Result};
 use sea_orm::entity::prelude::*;
 
-use super::{metadata_json::MetadataJson, CollectionMint, Drop, Holder};
+use super::{
+    metadata_json::MetadataJson, AnalyticsGranularity, AnalyticsRange, CollectionAnalytics,
+    CollectionMint, CollectionTrait, Drop, Holder, HolderSort,
+};
 use crate::{
+    dataloaders::{AnalyticsKey, HoldersKey},
     entities::{
         collection_creators,
         collections::Model,
@@ -12,6 +16,11 @@ use crate::{
     AppContext,
 };
 
+/// The number of holders returned when `limit` is not provided.
+const DEFAULT_HOLDERS_LIMIT: u64 = 25;
+/// The maximum number of holders returned by a single page.
+const MAX_HOLDERS_LIMIT: u64 = 100;
+
 /// An NFT collection that has either a fixed supply or unlimited mints. NFT collections are deployed to a desired blockchain.
 /// On Solana, when the collection is associated to a drop it is a `master_edition`. When the collection is not associated to a drop it is a sized Metaplex certified collection.
 /// On EVM chains, the collection is a ERC-1155 token.
@@ -138,11 +147,26 @@ impl Collection {
         creators_loader.load_one(self.id).await
     }
 
-    /// The list of current holders of NFTs from the collection.
-    async fn holders(&self, ctx: &Context<'_>) -> Result<Option<Vec<Holder>>> {
+    /// The list of current holders of NFTs from the collection, sorted by the number of NFTs they own. Use `limit` and `offset` to page through the holders. `limit` defaults to 25 and is capped at 100.
+    async fn holders(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default)] sort: HolderSort,
+        limit: Option<u64>,
+        #[graphql(default)] offset: u64,
+    ) -> Result<Option<Vec<Holder>>> {
         let AppContext { holders_loader, .. } = ctx.data::<AppContext>()?;
 
-        holders_loader.load_one(self.id).await
+        holders_loader
+            .load_one(HoldersKey {
+                collection_id: self.id,
+                sort,
+                limit: limit
+                    .unwrap_or(DEFAULT_HOLDERS_LIMIT)
+                    .min(MAX_HOLDERS_LIMIT),
+                offset,
+            })
+            .await
     }
 
     #[graphql(deprecation = "Use `mint_histories` instead")]
@@ -192,6 +216,48 @@ impl Collection {
 
         Ok(total_mints)
     }
+
+    /// Mint, transfer and holder activity of the collection within a time range, grouped into buckets of the given granularity.
+    /// Results are cached for a few minutes.
+    async fn analytics(
+        &self,
+        ctx: &Context<'_>,
+        range: AnalyticsRange,
+        granularity: AnalyticsGranularity,
+    ) -> Result<CollectionAnalytics> {
+        let AppContext {
+            collection_analytics_loader,
+            ..
+        } = ctx.data::<AppContext>()?;
+
+        if range.from >= range.to {
+            return Err(Error::new("Analytics range must start before it ends"));
+        }
+
+        let analytics = collection_analytics_loader
+            .load_one(AnalyticsKey {
+                collection_id: self.id,
+                range,
+                granularity,
+            })
+            .await?
+            .unwrap_or_default();
+
+        Ok(analytics)
+    }
+
+    /// The trait types of the NFTs in the collection with the number of NFTs having each value. Failed NFTs and NFTs removed from the collection are not counted.
+    async fn traits(&self, ctx: &Context<'_>) -> Result<Vec<CollectionTrait>> {
+        let AppContext {
+            collection_traits_loader,
+            ..
+        } = ctx.data::<AppContext>()?;
+
+        Ok(collection_traits_loader
+            .load_one(self.id)
+            .await?
+            .unwrap_or_default())
+    }
 }
 
 impl From<Model> for Collection {
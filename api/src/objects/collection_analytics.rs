@@ -0,0 +1,67 @@
+use async_graphql::{Enum, InputObject, SimpleObject};
+use hub_core::chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// The size of the time buckets collection analytics are grouped by.
+#[derive(Enum, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AnalyticsGranularity {
+    Hour,
+    Day,
+    Week,
+    Month,
+}
+
+impl AnalyticsGranularity {
+    /// The `date_trunc` field name of the granularity.
+    #[must_use]
+    pub fn as_date_trunc_field(self) -> &'static str {
+        match self {
+            Self::Hour => "hour",
+            Self::Day => "day",
+            Self::Week => "week",
+            Self::Month => "month",
+        }
+    }
+}
+
+/// The time range to compute collection analytics for. `from` is inclusive and `to` is exclusive.
+#[derive(InputObject, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AnalyticsRange {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+}
+
+/// Activity of a collection within a time range.
+#[derive(SimpleObject, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CollectionAnalytics {
+    /// The activity of the collection grouped by time bucket. Buckets without any activity are omitted.
+    pub buckets: Vec<AnalyticsBucket>,
+    /// The number of wallets currently holding NFTs from the collection.
+    pub unique_holders: i64,
+    /// The wallets currently holding the most NFTs from the collection.
+    pub top_holders: Vec<TopHolder>,
+}
+
+/// The activity of a collection within a single time bucket.
+#[derive(SimpleObject, Debug, Clone, Serialize, Deserialize)]
+pub struct AnalyticsBucket {
+    /// The start of the bucket.
+    pub start: DateTime<Utc>,
+    /// The number of NFTs minted.
+    pub mints: i64,
+    /// The number of mints that failed.
+    pub failed_mints: i64,
+    /// The number of transfers.
+    pub transfers: i64,
+    /// The number of distinct wallets that received an NFT through a mint or a transfer.
+    pub unique_holders: i64,
+}
+
+/// A wallet and the number of NFTs from the collection it holds.
+#[derive(SimpleObject, Debug, Clone, Serialize, Deserialize)]
+pub struct TopHolder {
+    /// The wallet address of the holder.
+    pub address: String,
+    /// The number of NFTs the holder owns in the collection.
+    pub owns: i64,
+}
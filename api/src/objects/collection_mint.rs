@@ -3,10 +3,11 @@ use sea_orm::entity::prelude::*;
 
 use crate::{
     entities::{
+        collection_mint_rarities,
         collection_mints::Model,
-        mint_creators, mint_histories, nft_transfers,
+        metadata_json_versions, mint_creators, mint_histories, nft_transfers,
         sea_orm_active_enums::{Blockchain, CreationStatus},
-        switch_collection_histories, update_histories,
+        switch_collection_histories, transfers, update_histories,
     },
     objects::{Collection, MetadataJson},
     AppContext,
@@ -42,6 +43,8 @@ pub struct CollectionMint {
     pub credits_deduction_id: Option<Uuid>,
     /// Indicates if the NFT is compressed. Compression is only supported on Solana.
     pub compressed: Option<bool>,
+    /// The date and time the NFT was found to no longer belong to the collection during a re-import.
+    pub removed_at: Option<DateTimeWithTimeZone>,
 }
 
 #[ComplexObject]
@@ -119,6 +122,35 @@ impl CollectionMint {
         collection_mint_transfers_loader.load_one(self.id).await
     }
 
+    /// The revisions of the metadata json of the mint, from the latest version.
+    async fn metadata_versions(
+        &self,
+        ctx: &Context<'_>,
+    ) -> Result<Vec<metadata_json_versions::Model>> {
+        let AppContext {
+            metadata_json_versions_loader,
+            ..
+        } = ctx.data::<AppContext>()?;
+
+        Ok(metadata_json_versions_loader
+            .load_one(self.id)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// The transfers of the mint that have not yet been submitted to the blockchain.
+    async fn pending_transfers(&self, ctx: &Context<'_>) -> Result<Vec<transfers::Model>> {
+        let AppContext {
+            pending_transfers_loader,
+            ..
+        } = ctx.data::<AppContext>()?;
+
+        Ok(pending_transfers_loader
+            .load_one(self.id)
+            .await?
+            .unwrap_or_default())
+    }
+
     /// The history of switched collections for the mint.
     async fn switch_collection_histories(
         &self,
@@ -131,6 +163,16 @@ impl CollectionMint {
 
         switch_collection_history_loader.load_one(self.id).await
     }
+
+    /// The rarity of the NFT within its collection. Recomputed in the background whenever NFTs are added to the collection or updated, so it can briefly be stale or missing.
+    async fn rarity(&self, ctx: &Context<'_>) -> Result<Option<collection_mint_rarities::Model>> {
+        let AppContext {
+            collection_mint_rarity_loader,
+            ..
+        } = ctx.data::<AppContext>()?;
+
+        collection_mint_rarity_loader.load_one(self.id).await
+    }
 }
 
 impl From<Model> for CollectionMint {
@@ -148,6 +190,8 @@ impl From<Model> for CollectionMint {
             seller_fee_basis_points,
             credits_deduction_id,
             compressed,
+            removed_at,
+            ..
         }: Model,
     ) -> Self {
         Self {
@@ -163,6 +207,7 @@ impl From<Model> for CollectionMint {
             seller_fee_basis_points,
             credits_deduction_id,
             compressed,
+            removed_at,
         }
     }
 }
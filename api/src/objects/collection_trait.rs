@@ -0,0 +1,21 @@
+use async_graphql::SimpleObject;
+
+/// A trait type used by the NFTs of a collection and how often each of its values occurs.
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct CollectionTrait {
+    /// The name of the trait.
+    pub trait_type: String,
+    /// The number of NFTs with the trait.
+    pub count: i64,
+    /// The values of the trait, from the most to the least common.
+    pub values: Vec<TraitValue>,
+}
+
+/// A value of a trait and the number of NFTs of the collection having it.
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct TraitValue {
+    /// The value of the trait.
+    pub value: String,
+    /// The number of NFTs with the value.
+    pub count: i64,
+}
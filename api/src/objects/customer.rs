@@ -1,8 +1,8 @@
 use async_graphql::{ComplexObject, Context, Result, SimpleObject};
 use hub_core::uuid::Uuid;
 
-use super::CollectionMint;
-use crate::{entities::mint_histories, AppContext};
+use super::{CollectionMint, OwnedCollection, OwnedMintsFilter};
+use crate::{dataloaders::OwnedMintsKey, entities::mint_histories, AppContext};
 
 /// A project customer.
 #[derive(SimpleObject, Debug, Clone)]
@@ -17,26 +17,51 @@ pub struct Customer {
 
 #[ComplexObject]
 impl Customer {
-    /// The NFTs owned by any of the customers' wallets.
+    /// The NFTs owned by any of the customers' wallets, from the most recently created.
+    /// Use `limit` and `offset` to page through the NFTs. `limit` defaults to 25 and is capped at 100.
     #[graphql(requires = "addresses")]
-    async fn mints(&self, ctx: &Context<'_>) -> Result<Option<Vec<CollectionMint>>> {
+    async fn mints(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default)] filter: OwnedMintsFilter,
+        limit: Option<u64>,
+        #[graphql(default)] offset: u64,
+    ) -> Result<Option<Vec<CollectionMint>>> {
+        let AppContext {
+            owned_mints_loader, ..
+        } = ctx.data::<AppContext>()?;
+
+        let Some(addresses) = self.addresses.clone() else {
+            return Ok(None);
+        };
+
+        owned_mints_loader
+            .load_one(OwnedMintsKey::new(addresses, filter, limit, offset))
+            .await
+    }
+
+    /// The collections the customers' wallets own NFTs of with the number of NFTs owned, from the collection with the most NFTs owned.
+    /// Use `limit` and `offset` to page through the collections. `limit` defaults to 25 and is capped at 100.
+    #[graphql(requires = "addresses")]
+    async fn collections(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default)] filter: OwnedMintsFilter,
+        limit: Option<u64>,
+        #[graphql(default)] offset: u64,
+    ) -> Result<Option<Vec<OwnedCollection>>> {
         let AppContext {
-            collection_mints_owner_loader,
+            owned_collections_loader,
             ..
         } = ctx.data::<AppContext>()?;
 
-        if let Some(addresses) = self.addresses.clone() {
-            Ok(Some(
-                collection_mints_owner_loader
-                    .load_many(addresses)
-                    .await?
-                    .into_values()
-                    .flatten()
-                    .collect(),
-            ))
-        } else {
-            Ok(None)
-        }
+        let Some(addresses) = self.addresses.clone() else {
+            return Ok(None);
+        };
+
+        owned_collections_loader
+            .load_one(OwnedMintsKey::new(addresses, filter, limit, offset))
+            .await
     }
 
     /// The NFTs minted by the customer.
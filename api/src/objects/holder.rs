@@ -1,4 +1,4 @@
-use async_graphql::{ComplexObject, Context, Result, SimpleObject};
+use async_graphql::{ComplexObject, Context, Enum, Result, SimpleObject};
 use hub_core::uuid::Uuid;
 use sea_orm::FromQueryResult;
 
@@ -21,21 +21,23 @@ impl Holder {
     /// The specific mints from the collection that the holder owns.
     async fn mints(&self, ctx: &Context<'_>) -> Result<Vec<Uuid>> {
         let AppContext {
-            collection_mints_loader,
+            holder_mints_loader,
             ..
         } = ctx.data::<AppContext>()?;
 
-        let mints = collection_mints_loader
-            .load_one(self.collection_id)
+        Ok(holder_mints_loader
+            .load_one((self.collection_id, self.address.clone()))
             .await?
-            .unwrap_or_default();
-
-        Ok(mints.into_iter().fold(Vec::new(), |mut acc, mint| {
-            if mint.owner == Some(self.address.clone()) {
-                acc.push(mint.id);
-            }
-
-            acc
-        }))
+            .unwrap_or_default())
     }
 }
+
+/// The order holders of a collection are returned in. Holders owning the same number of NFTs are ordered by address.
+#[derive(Enum, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum HolderSort {
+    /// Holders owning the most NFTs first.
+    #[default]
+    OwnsDesc,
+    /// Holders owning the fewest NFTs first.
+    OwnsAsc,
+}
@@ -0,0 +1,241 @@
+use std::collections::{BTreeMap, HashMap};
+
+use async_graphql::{Enum, Error, Result, SimpleObject};
+use hub_core::{
+    chrono::{DateTime, Utc},
+    uuid::Uuid,
+};
+use sea_orm::{prelude::*, QueryOrder};
+use serde::Serialize;
+
+use crate::entities::{
+    collection_mints, mint_histories, nft_transfers, sea_orm_active_enums::CreationStatus,
+};
+
+/// A wallet holding NFTs of a collection at a point in time.
+#[derive(SimpleObject, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotHolder {
+    /// The wallet address of the holder.
+    pub address: String,
+    /// The number of NFTs of the collection the holder owned.
+    pub owns: i64,
+    /// The IDs of the NFTs the holder owned.
+    pub mints: Vec<Uuid>,
+}
+
+impl SnapshotHolder {
+    /// Groups `(mint, owner)` pairs by owner. Holders are ordered by the number of NFTs owned and then by address.
+    #[must_use]
+    pub fn group(owners: impl IntoIterator<Item = (Uuid, String)>) -> Vec<Self> {
+        let grouped = owners.into_iter().fold(
+            BTreeMap::<String, Vec<Uuid>>::new(),
+            |mut acc, (mint, owner)| {
+                acc.entry(owner).or_default().push(mint);
+
+                acc
+            },
+        );
+
+        let mut holders = grouped
+            .into_iter()
+            .map(|(address, mints)| Self {
+                address,
+                owns: mints.len().try_into().unwrap_or(i64::MAX),
+                mints,
+            })
+            .collect::<Vec<_>>();
+
+        holders.sort_by(|a, b| b.owns.cmp(&a.owns).then_with(|| a.address.cmp(&b.address)));
+
+        holders
+    }
+}
+
+/// Computes the owner of every NFT of a collection at a point in time.
+/// The owner is the recipient of the last transfer at or before `at`. When the NFT was not transferred before `at` the owner is the sender of its first later transfer, or the wallet it was minted to.
+/// NFTs minted after `at` or removed from the collection before `at` are skipped.
+///
+/// # Errors
+/// Returns an error if the records of the collection cannot be loaded.
+pub async fn owners_at(
+    conn: &DatabaseConnection,
+    collection_id: Uuid,
+    at: DateTime<Utc>,
+) -> Result<Vec<(Uuid, String)>> {
+    let at: DateTimeWithTimeZone = at.into();
+
+    let mints = collection_mints::Entity::find()
+        .filter(
+            collection_mints::Column::CollectionId
+                .eq(collection_id)
+                .and(collection_mints::Column::CreationStatus.eq(CreationStatus::Created)),
+        )
+        .all(conn)
+        .await?;
+
+    let mint_histories = mint_histories::Entity::find()
+        .inner_join(collection_mints::Entity)
+        .filter(
+            collection_mints::Column::CollectionId
+                .eq(collection_id)
+                .and(mint_histories::Column::Status.eq(CreationStatus::Created)),
+        )
+        .all(conn)
+        .await?
+        .into_iter()
+        .map(|history| (history.mint_id, history))
+        .collect::<HashMap<_, _>>();
+
+    let transfers = nft_transfers::Entity::find()
+        .inner_join(collection_mints::Entity)
+        .filter(collection_mints::Column::CollectionId.eq(collection_id))
+        .order_by_asc(nft_transfers::Column::CreatedAt)
+        .all(conn)
+        .await?
+        .into_iter()
+        .fold(HashMap::new(), |mut acc, transfer| {
+            acc.entry(transfer.collection_mint_id)
+                .or_insert_with(Vec::new)
+                .push(transfer);
+
+            acc
+        });
+
+    Ok(mints
+        .into_iter()
+        .filter_map(|mint| {
+            let history = mint_histories.get(&mint.id);
+            let minted_at = history.map_or(mint.created_at, |history| history.created_at);
+
+            if minted_at > at || mint.removed_at.map_or(false, |removed_at| removed_at <= at) {
+                return None;
+            }
+
+            let transfers = transfers
+                .get(&mint.id)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+
+            let owner = match transfers.iter().rev().find(|t| t.created_at <= at) {
+                Some(transfer) => Some(transfer.recipient.clone()),
+                None => transfers
+                    .first()
+                    .map(|transfer| transfer.sender.clone())
+                    .or_else(|| history.map(|history| history.wallet.clone()))
+                    .or(mint.owner),
+            };
+
+            owner.map(|owner| (mint.id, owner))
+        })
+        .collect())
+}
+
+/// The formats a holder snapshot can be exported as.
+#[derive(Enum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotExportFormat {
+    /// A header row followed by one `address,owns,mints` row per holder. Mint IDs are separated by `;`.
+    Csv,
+    /// An array of holder objects.
+    Json,
+}
+
+impl SnapshotExportFormat {
+    /// Serializes the holders in the format.
+    ///
+    /// # Errors
+    /// Returns an error if the holders cannot be serialized to JSON.
+    pub fn serialize(self, holders: &[SnapshotHolder]) -> Result<String> {
+        match self {
+            Self::Json => serde_json::to_string(holders).map_err(|e| Error::new(e.to_string())),
+            Self::Csv => {
+                Ok(holders
+                    .iter()
+                    .fold(String::from("address,owns,mints\n"), |mut csv, holder| {
+                        let mints = holder
+                            .mints
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join(";");
+
+                        csv.push_str(&format!("{},{},{mints}\n", holder.address, holder.owns));
+
+                        csv
+                    }))
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group_orders_holders_by_owns_then_address() {
+        let [a, b, c, d] = [1, 2, 3, 4].map(Uuid::from_u128);
+
+        let holders = SnapshotHolder::group([
+            (a, "wallet-b".to_string()),
+            (b, "wallet-c".to_string()),
+            (c, "wallet-c".to_string()),
+            (d, "wallet-a".to_string()),
+        ]);
+
+        assert_eq!(holders, vec![
+            SnapshotHolder {
+                address: "wallet-c".to_string(),
+                owns: 2,
+                mints: vec![b, c],
+            },
+            SnapshotHolder {
+                address: "wallet-a".to_string(),
+                owns: 1,
+                mints: vec![d],
+            },
+            SnapshotHolder {
+                address: "wallet-b".to_string(),
+                owns: 1,
+                mints: vec![a],
+            },
+        ]);
+    }
+
+    #[test]
+    fn group_of_no_owners_is_empty() {
+        assert!(SnapshotHolder::group(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn serialize_csv_joins_mints() {
+        let [a, b] = [1, 2].map(Uuid::from_u128);
+
+        let csv = SnapshotExportFormat::Csv
+            .serialize(&[SnapshotHolder {
+                address: "wallet".to_string(),
+                owns: 2,
+                mints: vec![a, b],
+            }])
+            .unwrap();
+
+        assert_eq!(csv, format!("address,owns,mints\nwallet,2,{a};{b}\n"));
+    }
+
+    #[test]
+    fn serialize_json_lists_holders() {
+        let a = Uuid::from_u128(1);
+
+        let json = SnapshotExportFormat::Json
+            .serialize(&[SnapshotHolder {
+                address: "wallet".to_string(),
+                owns: 1,
+                mints: vec![a],
+            }])
+            .unwrap();
+
+        assert_eq!(
+            json,
+            format!(r#"[{{"address":"wallet","owns":1,"mints":["{a}"]}}]"#)
+        );
+    }
+}
@@ -1,14 +1,47 @@
 use async_graphql::{ComplexObject, Context, InputObject, Result, SimpleObject};
-use hub_core::{assets::AssetProxy, uuid::Uuid};
+use hub_core::{
+    assets::AssetProxy,
+    chrono::{DateTime, TimeZone, Utc},
+    uuid::Uuid,
+};
 use reqwest::Url;
 use sea_orm::{prelude::*, sea_query::OnConflict, DatabaseTransaction, Set};
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use sha2::{Digest, Sha256};
 
 use crate::{
-    entities::{metadata_json_attributes, metadata_json_files, metadata_jsons},
+    entities::{
+        metadata_json_attributes, metadata_json_files, metadata_jsons,
+        sea_orm_active_enums::{AttributeValueType, Blockchain},
+    },
     AppContext,
 };
 
+/// The `display_type`s of ERC-721 and ERC-1155 attributes. Their values are numbers, or dates for `date`.
+pub const NUMERIC_DISPLAY_TYPES: [&str; 4] = ["number", "boost_number", "boost_percentage", "date"];
+
+/// The metadata JSON standards uploaded metadata jsons are rendered in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetadataStandard {
+    /// [Metaplex v1.1.0 Standard](https://docs.metaplex.com/programs/token-metadata/token-standard), used on Solana.
+    Metaplex,
+    /// [ERC-721 Metadata JSON Schema](https://eips.ethereum.org/EIPS/eip-721) with the OpenSea extensions, used on Ethereum.
+    Erc721,
+    /// [ERC-1155 Metadata URI JSON Schema](https://eips.ethereum.org/EIPS/eip-1155) with the OpenSea extensions, used for Polygon editions.
+    Erc1155,
+}
+
+impl From<Blockchain> for MetadataStandard {
+    fn from(blockchain: Blockchain) -> Self {
+        match blockchain {
+            Blockchain::Solana => Self::Metaplex,
+            Blockchain::Ethereum => Self::Erc721,
+            Blockchain::Polygon => Self::Erc1155,
+        }
+    }
+}
+
 /// The collection's associated metadata JSON.
 /// ## References
 /// [Metaplex v1.1.0 Standard](https://docs.metaplex.com/programs/token-metadata/token-standard)
@@ -33,6 +66,12 @@ pub struct MetadataJson {
     pub animation_url: Option<String>,
     /// An optional URL where viewers can find more information on the NFT, such as the collection's homepage or Twitter page.
     pub external_url: Option<String>,
+    /// The reason the image, animation or files of the metadata json could not be re-hosted, if ingestion failed.
+    pub ingestion_error: Option<String>,
+    /// The background color of the NFT as a six-character hexadecimal without a pre-pended `#`. ERC-721 and ERC-1155 only.
+    pub background_color: Option<String>,
+    /// A URL to a YouTube video of the NFT. ERC-721 and ERC-1155 only.
+    pub youtube_url: Option<String>,
 }
 
 #[ComplexObject]
@@ -76,6 +115,12 @@ pub struct MetadataJsonInput {
     pub external_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<Property>,
+    /// The background color of the NFT as a six-character hexadecimal without a pre-pended `#`. ERC-721 and ERC-1155 only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background_color: Option<String>,
+    /// A URL to a YouTube video of the NFT. ERC-721 and ERC-1155 only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub youtube_url: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, InputObject)]
@@ -98,6 +143,173 @@ pub struct Property {
 pub struct Attribute {
     pub trait_type: String,
     pub value: String,
+    /// The type of the value. Inferred from `display_type` when not set, otherwise `STRING`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value_type: Option<AttributeValueType>,
+    /// How marketplaces display the attribute, e.g. `number`, `boost_percentage` or `date`. ERC-721 and ERC-1155 only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_type: Option<String>,
+    /// The maximum of a `NUMBER` attribute. ERC-721 and ERC-1155 only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_value: Option<f64>,
+}
+
+/// The value of an attribute parsed as its type.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttributeValue {
+    String(String),
+    Number(f64),
+    Boolean(bool),
+    Date(DateTime<Utc>),
+}
+
+impl AttributeValue {
+    /// Returns the value as emitted in metadata json documents. Dates are emitted as unix timestamps.
+    #[must_use]
+    pub fn to_json(&self) -> JsonValue {
+        match self {
+            Self::String(value) => value.clone().into(),
+            Self::Number(value) => number_to_json(*value),
+            Self::Boolean(value) => (*value).into(),
+            Self::Date(value) => value.timestamp().into(),
+        }
+    }
+}
+
+impl Attribute {
+    /// Returns an attribute read from a document that does not say its type. Numeric values are `NUMBER`s and anything else a `STRING`.
+    #[must_use]
+    pub fn inferred(trait_type: String, value: String) -> Self {
+        let is_number = value
+            .trim()
+            .parse::<f64>()
+            .is_ok_and(|number| number.is_finite());
+
+        Self {
+            trait_type,
+            value,
+            value_type: is_number.then_some(AttributeValueType::Number),
+            display_type: None,
+            max_value: None,
+        }
+    }
+
+    /// Returns the type of the value. A `date` display type implies a date and the other display types a number.
+    #[must_use]
+    pub fn value_type(&self) -> AttributeValueType {
+        match (self.value_type, self.display_type.as_deref()) {
+            (Some(value_type), _) => value_type,
+            (None, Some("date")) => AttributeValueType::Date,
+            (None, Some(_)) => AttributeValueType::Number,
+            (None, None) => AttributeValueType::String,
+        }
+    }
+
+    /// Parses the value as its type, or returns `None` if it is not a valid value of the type.
+    #[must_use]
+    pub fn typed_value(&self) -> Option<AttributeValue> {
+        let value = self.value.trim();
+
+        match self.value_type() {
+            AttributeValueType::String => Some(AttributeValue::String(self.value.clone())),
+            AttributeValueType::Number => value
+                .parse::<f64>()
+                .ok()
+                .filter(|number| number.is_finite())
+                .map(AttributeValue::Number),
+            AttributeValueType::Boolean => match value.to_lowercase().as_str() {
+                "true" => Some(AttributeValue::Boolean(true)),
+                "false" => Some(AttributeValue::Boolean(false)),
+                _ => None,
+            },
+            AttributeValueType::Date => value
+                .parse::<i64>()
+                .ok()
+                .and_then(|timestamp| Utc.timestamp_opt(timestamp, 0).single())
+                .or_else(|| {
+                    DateTime::parse_from_rfc3339(value)
+                        .ok()
+                        .map(|date| date.with_timezone(&Utc))
+                })
+                .map(AttributeValue::Date),
+        }
+    }
+
+    /// Returns the attribute row of a metadata json with the value stored in the column of its type.
+    #[must_use]
+    pub fn active_model(&self, metadata_json_id: Uuid) -> metadata_json_attributes::ActiveModel {
+        let value = self.typed_value();
+
+        metadata_json_attributes::ActiveModel {
+            metadata_json_id: Set(metadata_json_id),
+            trait_type: Set(self.trait_type.clone()),
+            value: Set(self.value.clone()),
+            value_type: Set(self.value_type()),
+            number_value: Set(match &value {
+                Some(AttributeValue::Number(number)) => Some(*number),
+                _ => None,
+            }),
+            boolean_value: Set(match &value {
+                Some(AttributeValue::Boolean(boolean)) => Some(*boolean),
+                _ => None,
+            }),
+            date_value: Set(match &value {
+                Some(AttributeValue::Date(date)) => Some((*date).into()),
+                _ => None,
+            }),
+            display_type: Set(self.display_type.clone()),
+            max_value: Set(self.max_value),
+            ..Default::default()
+        }
+    }
+
+    /// Renders the attribute as a Metaplex attribute.
+    fn render_metaplex(&self) -> JsonValue {
+        let mut attribute = serde_json::Map::new();
+
+        attribute.insert("trait_type".to_string(), self.trait_type.clone().into());
+        attribute.insert("value".to_string(), self.json_value());
+
+        JsonValue::Object(attribute)
+    }
+
+    /// Renders the attribute as an ERC-721 and ERC-1155 trait. Dates are displayed as dates unless another display type is set.
+    fn render_erc(&self) -> JsonValue {
+        let mut trait_json = serde_json::Map::new();
+
+        trait_json.insert("trait_type".to_string(), self.trait_type.clone().into());
+        trait_json.insert("value".to_string(), self.json_value());
+
+        let display_type = self.display_type.clone().or_else(|| {
+            (self.value_type() == AttributeValueType::Date).then(|| "date".to_string())
+        });
+
+        if let Some(display_type) = display_type {
+            trait_json.insert("display_type".to_string(), display_type.into());
+        }
+
+        if let Some(max_value) = self.max_value {
+            trait_json.insert("max_value".to_string(), number_to_json(max_value));
+        }
+
+        JsonValue::Object(trait_json)
+    }
+
+    /// Returns the typed value as JSON, falling back to the raw string for values that do not parse.
+    fn json_value(&self) -> JsonValue {
+        self.typed_value()
+            .map_or_else(|| self.value.clone().into(), |value| value.to_json())
+    }
+}
+
+/// Converts a number to JSON, emitting integers without a fraction.
+#[allow(clippy::cast_possible_truncation)]
+fn number_to_json(value: f64) -> JsonValue {
+    if value.fract() == 0.0 && value.abs() < 9_007_199_254_740_992.0 {
+        (value as i64).into()
+    } else {
+        serde_json::Number::from_f64(value).map_or(JsonValue::Null, JsonValue::Number)
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, InputObject)]
@@ -108,6 +320,137 @@ pub struct Collection {
 }
 
 impl MetadataJsonInput {
+    /// Returns the hex encoded SHA-256 of the metadata json serialized as canonical JSON.
+    /// Object keys are sorted so metadata jsons with the same content always have the same hash.
+    /// # Errors
+    /// Returns Err if the metadata json cannot be serialized
+    pub fn content_hash(&self) -> serde_json::Result<String> {
+        serde_json::to_value(self).map(|value| canonical_hash(&value))
+    }
+
+    /// Renders the metadata json document uploaded for NFTs following the standard.
+    /// Fields the standard does not define are left out.
+    /// # Errors
+    /// Returns Err if the metadata json cannot be serialized
+    pub fn render(&self, standard: MetadataStandard) -> serde_json::Result<JsonValue> {
+        match standard {
+            MetadataStandard::Metaplex => {
+                let mut metadata_json = self.clone();
+
+                metadata_json.background_color = None;
+                metadata_json.youtube_url = None;
+
+                for attribute in &mut metadata_json.attributes {
+                    attribute.display_type = None;
+                }
+
+                let mut document = serde_json::to_value(&metadata_json)?;
+
+                document["attributes"] = metadata_json
+                    .attributes
+                    .iter()
+                    .map(Attribute::render_metaplex)
+                    .collect();
+
+                Ok(document)
+            },
+            MetadataStandard::Erc721 | MetadataStandard::Erc1155 => {
+                let mut document = serde_json::Map::new();
+
+                document.insert("name".to_string(), self.name.clone().into());
+                document.insert("description".to_string(), self.description.clone().into());
+                document.insert("image".to_string(), self.image.clone().into());
+
+                let optional = [
+                    ("external_url", &self.external_url),
+                    ("animation_url", &self.animation_url),
+                    ("background_color", &self.background_color),
+                    ("youtube_url", &self.youtube_url),
+                ];
+
+                for (key, value) in optional
+                    .into_iter()
+                    .filter_map(|(key, value)| value.as_ref().map(|value| (key, value)))
+                {
+                    document.insert(key.to_string(), value.clone().into());
+                }
+
+                document.insert(
+                    "attributes".to_string(),
+                    self.attributes.iter().map(Attribute::render_erc).collect(),
+                );
+
+                if standard == MetadataStandard::Erc1155 {
+                    document.insert("decimals".to_string(), 0.into());
+                }
+
+                Ok(JsonValue::Object(document))
+            },
+        }
+    }
+
+    /// Loads a saved metadata json as an input.
+    /// Metadata jsons without a saved document are rebuilt from their columns, which leaves the collection and property category unset.
+    /// # Errors
+    /// Returns Err if the metadata json cannot be read from the database
+    pub async fn load(id: Uuid, conn: &impl ConnectionTrait) -> Result<Option<Self>> {
+        let Some(metadata_json) = metadata_jsons::Entity::find_by_id(id).one(conn).await? else {
+            return Ok(None);
+        };
+
+        if let Some(document) = metadata_json.document {
+            return Ok(Some(serde_json::from_value(document)?));
+        }
+
+        let attributes = metadata_json_attributes::Entity::find()
+            .filter(metadata_json_attributes::Column::MetadataJsonId.eq(id))
+            .all(conn)
+            .await?;
+        let files = metadata_json_files::Entity::find()
+            .filter(metadata_json_files::Column::MetadataJsonId.eq(id))
+            .all(conn)
+            .await?;
+
+        let attributes = attributes
+            .into_iter()
+            .map(|attribute| Attribute {
+                trait_type: attribute.trait_type,
+                value: attribute.value,
+                value_type: (attribute.value_type != AttributeValueType::String)
+                    .then_some(attribute.value_type),
+                display_type: attribute.display_type,
+                max_value: attribute.max_value,
+            })
+            .collect();
+
+        let properties = (!files.is_empty()).then(|| Property {
+            files: Some(
+                files
+                    .into_iter()
+                    .map(|file| File {
+                        uri: file.uri,
+                        file_type: file.file_type,
+                    })
+                    .collect(),
+            ),
+            category: None,
+        });
+
+        Ok(Some(Self {
+            name: metadata_json.name,
+            symbol: metadata_json.symbol,
+            description: metadata_json.description,
+            image: metadata_json.image,
+            animation_url: metadata_json.animation_url,
+            collection: None,
+            attributes,
+            external_url: metadata_json.external_url,
+            properties,
+            background_color: metadata_json.background_color,
+            youtube_url: metadata_json.youtube_url,
+        }))
+    }
+
     /// Saves the metadata json to the database. If the metadata json already exists, it will update the existing record.
     /// # Arguments
     /// * `id` - The id of the metadata json
@@ -128,6 +471,11 @@ impl MetadataJsonInput {
             image: Set(metadata_json.image),
             animation_url: Set(metadata_json.animation_url),
             external_url: Set(metadata_json.external_url),
+            content_hash: Set(Some(self.content_hash()?)),
+            ingestion_error: Set(None),
+            background_color: Set(metadata_json.background_color),
+            youtube_url: Set(metadata_json.youtube_url),
+            document: Set(Some(serde_json::to_value(self)?)),
         };
 
         let metadata_json_model = metadata_jsons::Entity::insert(metadata_json_active_model)
@@ -142,6 +490,11 @@ impl MetadataJsonInput {
                         metadata_jsons::Column::Image,
                         metadata_jsons::Column::AnimationUrl,
                         metadata_jsons::Column::ExternalUrl,
+                        metadata_jsons::Column::ContentHash,
+                        metadata_jsons::Column::IngestionError,
+                        metadata_jsons::Column::BackgroundColor,
+                        metadata_jsons::Column::YoutubeUrl,
+                        metadata_jsons::Column::Document,
                     ])
                     .clone(),
             )
@@ -154,12 +507,7 @@ impl MetadataJsonInput {
             .await?;
 
         for attribute in metadata_json.attributes {
-            let am = metadata_json_attributes::ActiveModel {
-                metadata_json_id: Set(metadata_json_model.id),
-                trait_type: Set(attribute.trait_type),
-                value: Set(attribute.value),
-                ..Default::default()
-            };
+            let am = attribute.active_model(metadata_json_model.id);
 
             am.insert(tx).await?;
         }
@@ -186,6 +534,54 @@ impl MetadataJsonInput {
     }
 }
 
+/// Returns the hex encoded SHA-256 of a JSON value serialized as canonical JSON.
+#[must_use]
+pub fn canonical_hash(value: &JsonValue) -> String {
+    let mut canonical = String::new();
+
+    write_canonical_json(value, &mut canonical);
+
+    hex::encode(Sha256::digest(canonical.as_bytes()))
+}
+
+/// Serializes a JSON value without whitespace and with object keys sorted.
+fn write_canonical_json(value: &JsonValue, out: &mut String) {
+    match value {
+        JsonValue::Array(items) => {
+            out.push('[');
+
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+
+                write_canonical_json(item, out);
+            }
+
+            out.push(']');
+        },
+        JsonValue::Object(map) => {
+            let mut entries = map.iter().collect::<Vec<_>>();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            out.push('{');
+
+            for (index, (key, value)) in entries.into_iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+
+                out.push_str(&JsonValue::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical_json(value, out);
+            }
+
+            out.push('}');
+        },
+        _ => out.push_str(&value.to_string()),
+    }
+}
+
 impl From<metadata_jsons::Model> for MetadataJson {
     fn from(
         metadata_jsons::Model {
@@ -198,6 +594,10 @@ impl From<metadata_jsons::Model> for MetadataJson {
             image,
             animation_url,
             external_url,
+            ingestion_error,
+            background_color,
+            youtube_url,
+            ..
         }: metadata_jsons::Model,
     ) -> Self {
         Self {
@@ -210,6 +610,200 @@ impl From<metadata_jsons::Model> for MetadataJson {
             image_original: image,
             animation_url,
             external_url,
+            ingestion_error,
+            background_color,
+            youtube_url,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn metadata_json() -> MetadataJsonInput {
+        MetadataJsonInput {
+            name: "Name".to_string(),
+            symbol: "SYM".to_string(),
+            description: "Description".to_string(),
+            image: "https://example.com/image.png".to_string(),
+            animation_url: None,
+            collection: None,
+            attributes: vec![
+                Attribute {
+                    trait_type: "speed".to_string(),
+                    value: "5".to_string(),
+                    value_type: None,
+                    display_type: Some("number".to_string()),
+                    max_value: Some(10.0),
+                },
+                Attribute {
+                    trait_type: "color".to_string(),
+                    value: "red".to_string(),
+                    value_type: None,
+                    display_type: None,
+                    max_value: None,
+                },
+            ],
+            external_url: Some("https://example.com".to_string()),
+            properties: None,
+            background_color: Some("ffffff".to_string()),
+            youtube_url: Some("https://youtube.com/watch?v=1".to_string()),
+        }
+    }
+
+    #[test]
+    fn render_metaplex_leaves_out_erc_fields() {
+        let document = metadata_json().render(MetadataStandard::Metaplex).unwrap();
+
+        assert_eq!(
+            document,
+            json!({
+                "name": "Name",
+                "symbol": "SYM",
+                "description": "Description",
+                "image": "https://example.com/image.png",
+                "attributes": [
+                    { "trait_type": "speed", "value": 5 },
+                    { "trait_type": "color", "value": "red" },
+                ],
+                "external_url": "https://example.com",
+            })
+        );
+    }
+
+    #[test]
+    fn render_erc721_includes_display_types_and_max_values() {
+        let document = metadata_json().render(MetadataStandard::Erc721).unwrap();
+
+        assert_eq!(
+            document,
+            json!({
+                "name": "Name",
+                "description": "Description",
+                "image": "https://example.com/image.png",
+                "external_url": "https://example.com",
+                "background_color": "ffffff",
+                "youtube_url": "https://youtube.com/watch?v=1",
+                "attributes": [
+                    { "trait_type": "speed", "value": 5, "display_type": "number", "max_value": 10 },
+                    { "trait_type": "color", "value": "red" },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn render_erc1155_sets_decimals() {
+        let document = metadata_json().render(MetadataStandard::Erc1155).unwrap();
+
+        assert_eq!(document["decimals"], json!(0));
+        assert!(document.get("symbol").is_none());
+    }
+
+    fn attribute(value: &str, value_type: Option<AttributeValueType>) -> Attribute {
+        Attribute {
+            trait_type: "trait".to_string(),
+            value: value.to_string(),
+            value_type,
+            display_type: None,
+            max_value: None,
+        }
+    }
+
+    #[test]
+    fn number_to_json_emits_integers_without_a_fraction() {
+        assert_eq!(number_to_json(5.0), json!(5));
+        assert_eq!(number_to_json(-3.0), json!(-3));
+        assert_eq!(number_to_json(2.5), json!(2.5));
+        assert_eq!(number_to_json(1e20), json!(1e20));
+        assert_eq!(number_to_json(f64::NAN), JsonValue::Null);
+    }
+
+    #[test]
+    fn inferred_types_numbers_and_strings() {
+        let number = Attribute::inferred("trait".to_string(), " 42 ".to_string());
+        let string = Attribute::inferred("trait".to_string(), "red".to_string());
+        let infinite = Attribute::inferred("trait".to_string(), "inf".to_string());
+
+        assert_eq!(number.value_type(), AttributeValueType::Number);
+        assert_eq!(string.value_type(), AttributeValueType::String);
+        assert_eq!(infinite.value_type(), AttributeValueType::String);
+    }
+
+    #[test]
+    fn value_type_is_inferred_from_the_display_type() {
+        let mut date = attribute("0", None);
+        date.display_type = Some("date".to_string());
+        let mut boost = attribute("0", None);
+        boost.display_type = Some("boost_number".to_string());
+        let mut explicit = attribute("0", Some(AttributeValueType::String));
+        explicit.display_type = Some("number".to_string());
+
+        assert_eq!(date.value_type(), AttributeValueType::Date);
+        assert_eq!(boost.value_type(), AttributeValueType::Number);
+        assert_eq!(explicit.value_type(), AttributeValueType::String);
+        assert_eq!(
+            attribute("0", None).value_type(),
+            AttributeValueType::String
+        );
+    }
+
+    #[test]
+    fn typed_value_parses_the_value_as_its_type() {
+        let date = AttributeValue::Date(Utc.timestamp_opt(1_700_000_000, 0).unwrap());
+
+        assert_eq!(
+            attribute(" 1.5 ", Some(AttributeValueType::Number)).typed_value(),
+            Some(AttributeValue::Number(1.5))
+        );
+        assert_eq!(
+            attribute("TRUE", Some(AttributeValueType::Boolean)).typed_value(),
+            Some(AttributeValue::Boolean(true))
+        );
+        assert_eq!(
+            attribute("1700000000", Some(AttributeValueType::Date)).typed_value(),
+            Some(date.clone())
+        );
+        assert_eq!(
+            attribute("2023-11-14T22:13:20Z", Some(AttributeValueType::Date)).typed_value(),
+            Some(date)
+        );
+        assert_eq!(
+            attribute(" red ", None).typed_value(),
+            Some(AttributeValue::String(" red ".to_string()))
+        );
+    }
+
+    #[test]
+    fn typed_value_rejects_invalid_values() {
+        assert!(attribute("fast", Some(AttributeValueType::Number))
+            .typed_value()
+            .is_none());
+        assert!(attribute("NaN", Some(AttributeValueType::Number))
+            .typed_value()
+            .is_none());
+        assert!(attribute("yes", Some(AttributeValueType::Boolean))
+            .typed_value()
+            .is_none());
+        assert!(attribute("tomorrow", Some(AttributeValueType::Date))
+            .typed_value()
+            .is_none());
+    }
+
+    #[test]
+    fn render_erc_displays_dates_as_dates() {
+        let date = attribute("2023-11-14T22:13:20Z", Some(AttributeValueType::Date));
+
+        assert_eq!(
+            date.render_erc(),
+            json!({ "trait_type": "trait", "value": 1_700_000_000, "display_type": "date" })
+        );
+        assert_eq!(
+            date.render_metaplex(),
+            json!({ "trait_type": "trait", "value": 1_700_000_000 })
+        );
+    }
+}
@@ -0,0 +1,294 @@
+use async_graphql::{Enum, InputObject};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value as JsonValue};
+
+use super::metadata_json::{Attribute, MetadataJsonInput};
+use crate::entities::sea_orm_active_enums::AttributeValueType;
+
+/// A change applied to the metadata json of every mint of a collection.
+/// The `merge` document is applied first, then the attribute operations in order.
+#[derive(Clone, Debug, Serialize, Deserialize, InputObject)]
+pub struct MetadataJsonPatchInput {
+    /// A [JSON merge patch](https://www.rfc-editor.org/rfc/rfc7396) applied to the metadata json. Keys set to `null` are removed and arrays are replaced as a whole.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge: Option<JsonValue>,
+    /// Attributes to add, remove or replace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<Vec<AttributePatchInput>>,
+}
+
+/// How an attribute patch changes the attributes of a metadata json.
+#[derive(Enum, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum AttributePatchOp {
+    /// Adds the attribute unless the metadata json already has an attribute with the trait type.
+    Add,
+    /// Removes the attributes with the trait type. Only attributes with `value` are removed when it is set.
+    Remove,
+    /// Replaces the value of the attribute with the trait type. Metadata jsons without the trait type are left unchanged.
+    Replace,
+}
+
+/// An attribute operation of a metadata json patch.
+#[derive(Clone, Debug, Serialize, Deserialize, InputObject)]
+pub struct AttributePatchInput {
+    pub op: AttributePatchOp,
+    pub trait_type: String,
+    /// The value of the attribute. Required for `ADD` and `REPLACE`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value_type: Option<AttributeValueType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_value: Option<f64>,
+}
+
+impl MetadataJsonPatchInput {
+    /// Checks the patch can be applied before it is queued.
+    /// # Errors
+    /// if the patch is empty, `merge` is not an object or an attribute operation is missing its value
+    pub fn validate(&self) -> Result<(), String> {
+        let attributes = self.attributes.as_deref().unwrap_or_default();
+
+        if self.merge.is_none() && attributes.is_empty() {
+            return Err("Patch must set merge or attributes".to_string());
+        }
+
+        if self.merge.as_ref().is_some_and(|merge| !merge.is_object()) {
+            return Err("Merge patch must be a json object".to_string());
+        }
+
+        for attribute in attributes {
+            if attribute.op != AttributePatchOp::Remove && attribute.value.is_none() {
+                return Err(format!(
+                    "Attribute patch for {} must set a value",
+                    attribute.trait_type
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the metadata json with the patch applied.
+    /// # Errors
+    /// if the merge patch leaves an invalid metadata json
+    pub fn apply(&self, metadata_json: MetadataJsonInput) -> Result<MetadataJsonInput, String> {
+        let mut metadata_json = match &self.merge {
+            Some(merge) => {
+                let mut document = serde_json::to_value(&metadata_json)
+                    .map_err(|e| format!("Invalid metadata json: {e}"))?;
+
+                merge_patch(&mut document, merge);
+
+                serde_json::from_value(document)
+                    .map_err(|e| format!("Invalid metadata json after merge patch: {e}"))?
+            },
+            None => metadata_json,
+        };
+
+        for attribute in self.attributes.iter().flatten() {
+            attribute.apply(&mut metadata_json.attributes);
+        }
+
+        Ok(metadata_json)
+    }
+}
+
+impl AttributePatchInput {
+    fn apply(&self, attributes: &mut Vec<Attribute>) {
+        match (self.op, &self.value) {
+            (AttributePatchOp::Add, Some(value)) => {
+                if attributes.iter().any(|a| a.trait_type == self.trait_type) {
+                    return;
+                }
+
+                attributes.push(Attribute {
+                    trait_type: self.trait_type.clone(),
+                    value: value.clone(),
+                    value_type: self.value_type,
+                    display_type: self.display_type.clone(),
+                    max_value: self.max_value,
+                });
+            },
+            (AttributePatchOp::Replace, Some(value)) => {
+                for attribute in attributes
+                    .iter_mut()
+                    .filter(|a| a.trait_type == self.trait_type)
+                {
+                    attribute.value = value.clone();
+                    attribute.value_type = self.value_type;
+                    attribute.display_type = self.display_type.clone();
+                    attribute.max_value = self.max_value;
+                }
+            },
+            (AttributePatchOp::Remove, value) => attributes.retain(|a| {
+                a.trait_type != self.trait_type || value.as_ref().is_some_and(|v| *v != a.value)
+            }),
+            (AttributePatchOp::Add | AttributePatchOp::Replace, None) => {},
+        }
+    }
+}
+
+/// Applies a JSON merge patch to the document following RFC 7396.
+fn merge_patch(document: &mut JsonValue, patch: &JsonValue) {
+    let JsonValue::Object(patch) = patch else {
+        *document = patch.clone();
+        return;
+    };
+
+    if !document.is_object() {
+        *document = JsonValue::Object(Map::new());
+    }
+
+    let Some(document) = document.as_object_mut() else {
+        return;
+    };
+
+    for (key, value) in patch {
+        if value.is_null() {
+            document.remove(key);
+        } else {
+            merge_patch(
+                document.entry(key.clone()).or_insert(JsonValue::Null),
+                value,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn metadata_json() -> MetadataJsonInput {
+        MetadataJsonInput {
+            name: "Name".to_string(),
+            symbol: "SYM".to_string(),
+            description: "Description".to_string(),
+            image: "https://example.com/image.png".to_string(),
+            animation_url: None,
+            collection: None,
+            attributes: vec![
+                Attribute::inferred("color".to_string(), "red".to_string()),
+                Attribute::inferred("size".to_string(), "2".to_string()),
+            ],
+            external_url: Some("https://example.com".to_string()),
+            properties: None,
+            background_color: None,
+            youtube_url: None,
+        }
+    }
+
+    fn attribute_patch(
+        op: AttributePatchOp,
+        trait_type: &str,
+        value: Option<&str>,
+    ) -> AttributePatchInput {
+        AttributePatchInput {
+            op,
+            trait_type: trait_type.to_string(),
+            value: value.map(ToString::to_string),
+            value_type: None,
+            display_type: None,
+            max_value: None,
+        }
+    }
+
+    fn patch(attributes: Vec<AttributePatchInput>) -> MetadataJsonPatchInput {
+        MetadataJsonPatchInput {
+            merge: None,
+            attributes: Some(attributes),
+        }
+    }
+
+    fn values(metadata_json: &MetadataJsonInput) -> Vec<(&str, &str)> {
+        metadata_json
+            .attributes
+            .iter()
+            .map(|a| (a.trait_type.as_str(), a.value.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn validate_rejects_empty_and_incomplete_patches() {
+        let array_merge = MetadataJsonPatchInput {
+            merge: Some(json!([])),
+            attributes: None,
+        };
+        let add_without_value = attribute_patch(AttributePatchOp::Add, "color", None);
+        let remove = attribute_patch(AttributePatchOp::Remove, "color", None);
+
+        assert!(patch(Vec::new()).validate().is_err());
+        assert!(array_merge.validate().is_err());
+        assert!(patch(vec![add_without_value]).validate().is_err());
+        assert!(patch(vec![remove]).validate().is_ok());
+    }
+
+    #[test]
+    fn apply_merges_fields_and_removes_null_keys() {
+        let merged = MetadataJsonPatchInput {
+            merge: Some(json!({ "name": "New name", "external_url": null })),
+            attributes: None,
+        }
+        .apply(metadata_json())
+        .unwrap();
+
+        assert_eq!(merged.name, "New name");
+        assert_eq!(merged.external_url, None);
+        assert_eq!(merged.symbol, "SYM");
+    }
+
+    #[test]
+    fn apply_rejects_merges_leaving_an_invalid_metadata_json() {
+        let merged = MetadataJsonPatchInput {
+            merge: Some(json!({ "name": null })),
+            attributes: None,
+        }
+        .apply(metadata_json());
+
+        assert!(merged.is_err());
+    }
+
+    #[test]
+    fn apply_adds_attributes_with_new_trait_types_only() {
+        let patched = patch(vec![
+            attribute_patch(AttributePatchOp::Add, "color", Some("blue")),
+            attribute_patch(AttributePatchOp::Add, "shape", Some("round")),
+        ])
+        .apply(metadata_json())
+        .unwrap();
+
+        assert_eq!(
+            values(&patched),
+            vec![("color", "red"), ("size", "2"), ("shape", "round")]
+        );
+    }
+
+    #[test]
+    fn apply_replaces_existing_attributes_only() {
+        let patched = patch(vec![
+            attribute_patch(AttributePatchOp::Replace, "color", Some("blue")),
+            attribute_patch(AttributePatchOp::Replace, "shape", Some("round")),
+        ])
+        .apply(metadata_json())
+        .unwrap();
+
+        assert_eq!(values(&patched), vec![("color", "blue"), ("size", "2")]);
+    }
+
+    #[test]
+    fn apply_removes_attributes_matching_the_value() {
+        let patched = patch(vec![
+            attribute_patch(AttributePatchOp::Remove, "color", Some("blue")),
+            attribute_patch(AttributePatchOp::Remove, "size", None),
+        ])
+        .apply(metadata_json())
+        .unwrap();
+
+        assert_eq!(values(&patched), vec![("color", "red")]);
+    }
+}
@@ -1,21 +1,40 @@
 #![allow(clippy::unused_async)]
 
 mod collection;
+mod collection_analytics;
 mod collection_mint;
+mod collection_trait;
 mod creator;
 mod customer;
 mod drop;
 mod holder;
+pub mod holder_snapshot;
 mod metadata_json;
+mod metadata_json_patch;
+mod owned_mints;
 mod project;
+mod project_usage;
+mod transfer_batch;
 mod wallet;
 
 pub use collection::Collection;
+pub use collection_analytics::{
+    AnalyticsBucket, AnalyticsGranularity, AnalyticsRange, CollectionAnalytics, TopHolder,
+};
 pub use collection_mint::CollectionMint;
+pub use collection_trait::{CollectionTrait, TraitValue};
 pub use creator::Creator;
 pub use customer::Customer;
 pub use drop::Drop;
-pub use holder::Holder;
-pub use metadata_json::{MetadataJson, MetadataJsonInput};
+pub use holder::{Holder, HolderSort};
+pub use holder_snapshot::{SnapshotExportFormat, SnapshotHolder};
+pub use metadata_json::{
+    canonical_hash, Attribute, AttributeValue, MetadataJson, MetadataJsonInput, MetadataStandard,
+    NUMERIC_DISPLAY_TYPES,
+};
+pub use metadata_json_patch::{AttributePatchInput, AttributePatchOp, MetadataJsonPatchInput};
+pub use owned_mints::{OwnedCollection, OwnedMintsFilter};
 pub use project::Project;
+pub use project_usage::ProjectUsage;
+pub use transfer_batch::TransferBatch;
 pub use wallet::Wallet;
@@ -0,0 +1,46 @@
+use async_graphql::{ComplexObject, Context, InputObject, Result, SimpleObject};
+use hub_core::uuid::Uuid;
+use sea_orm::FromQueryResult;
+
+use super::Collection;
+use crate::{
+    entities::sea_orm_active_enums::{Blockchain, CreationStatus},
+    AppContext,
+};
+
+/// Narrows down the NFTs owned by a wallet or a customer. Only NFTs matching every provided field are returned.
+#[derive(InputObject, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct OwnedMintsFilter {
+    /// The project the collection of the NFT belongs to.
+    pub project: Option<Uuid>,
+    /// The collection of the NFT.
+    pub collection: Option<Uuid>,
+    /// The blockchain of the NFT.
+    pub blockchain: Option<Blockchain>,
+    /// Whether the NFT is compressed.
+    pub compressed: Option<bool>,
+    /// The creation status of the NFT.
+    pub status: Option<CreationStatus>,
+}
+
+/// A collection and the number of its NFTs owned by a customer.
+#[derive(SimpleObject, Debug, Clone, FromQueryResult)]
+#[graphql(complex)]
+pub struct OwnedCollection {
+    /// The ID of the collection.
+    pub collection_id: Uuid,
+    /// The number of NFTs of the collection owned by the customer's wallets.
+    pub owns: i64,
+}
+
+#[ComplexObject]
+impl OwnedCollection {
+    /// The collection.
+    async fn collection(&self, ctx: &Context<'_>) -> Result<Option<Collection>> {
+        let AppContext {
+            collection_loader, ..
+        } = ctx.data::<AppContext>()?;
+
+        collection_loader.load_one(self.collection_id).await
+    }
+}
@@ -1,8 +1,12 @@
 use async_graphql::{ComplexObject, Context, Error, Result, SimpleObject};
-use hub_core::uuid::Uuid;
+use hub_core::{
+    chrono::{DateTime, Utc},
+    uuid::Uuid,
+};
 
 use crate::{
-    objects::{Collection, Drop},
+    dataloaders::UsageKey,
+    objects::{Collection, Drop, ProjectUsage},
     AppContext,
 };
 
@@ -78,4 +82,29 @@ impl Project {
 
         Ok(None)
     }
+
+    /// Counts of the billable operations of the project within a time range, for reconciliation against credit deductions. `from` is inclusive and `to` is exclusive.
+    async fn usage(
+        &self,
+        ctx: &Context<'_>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Option<ProjectUsage>> {
+        let AppContext {
+            project_usage_loader,
+            ..
+        } = ctx.data::<AppContext>()?;
+
+        if from >= to {
+            return Err(Error::new("Usage range must start before it ends"));
+        }
+
+        project_usage_loader
+            .load_one(UsageKey {
+                project_id: self.id,
+                from,
+                to,
+            })
+            .await
+    }
 }
@@ -0,0 +1,42 @@
+use async_graphql::{ComplexObject, SimpleObject};
+use hub_core::chrono::{DateTime, Utc};
+
+/// Billable activity of a project within a time range. `from` is inclusive and `to` is exclusive.
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct ProjectUsage {
+    /// The start of the range.
+    pub from: DateTime<Utc>,
+    /// The end of the range.
+    pub to: DateTime<Utc>,
+    /// The number of uncompressed NFTs minted, including edition mints from drops.
+    pub mints: i64,
+    /// The number of compressed NFTs minted.
+    pub compressed_mints: i64,
+    /// The number of completed transfers.
+    pub transfers: i64,
+    /// The number of completed metadata updates.
+    pub updates: i64,
+    /// The number of completed collection switches.
+    pub collection_switches: i64,
+    /// The number of charged retries of collections, drops and mints.
+    pub retries: i64,
+}
+
+#[ComplexObject]
+impl ProjectUsage {
+    /// The usage as CSV. A header row is followed by a single row of counts.
+    async fn csv(&self) -> String {
+        format!(
+            "from,to,mints,compressed_mints,transfers,updates,collection_switches,retries\n{},{},{},{},{},{},{},{}\n",
+            self.from.to_rfc3339(),
+            self.to.to_rfc3339(),
+            self.mints,
+            self.compressed_mints,
+            self.transfers,
+            self.updates,
+            self.collection_switches,
+            self.retries
+        )
+    }
+}
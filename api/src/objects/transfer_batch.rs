@@ -0,0 +1,19 @@
+use async_graphql::SimpleObject;
+use hub_core::uuid::Uuid;
+
+/// The progress of a batch of transfers submitted with `transferAssetsBatched`.
+#[derive(SimpleObject, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransferBatch {
+    /// The ID of the batch.
+    pub id: Uuid,
+    /// The number of transfers in the batch.
+    pub total: i64,
+    /// The number of transfers waiting to be submitted to the blockchain.
+    pub pending: i64,
+    /// The number of transfers submitted to the blockchain.
+    pub completed: i64,
+    /// The number of transfers that failed.
+    pub failed: i64,
+    /// The number of transfers for which the change of owner has been recorded in the transfer history of the mint.
+    pub transferred: i64,
+}
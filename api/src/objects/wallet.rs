@@ -1,7 +1,7 @@
 use async_graphql::{ComplexObject, Context, Result, SimpleObject};
 
-use super::CollectionMint;
-use crate::AppContext;
+use super::{CollectionMint, OwnedMintsFilter};
+use crate::{dataloaders::OwnedMintsKey, AppContext};
 
 /// A blockchain wallet is a digital wallet that allows users to securely store, manage, and transfer their cryptocurrencies or other digital assets on a blockchain network.
 #[derive(SimpleObject, Debug, Clone)]
@@ -14,15 +14,25 @@ pub struct Wallet {
 
 #[ComplexObject]
 impl Wallet {
-    /// The NFTs that were minted from Holaplex and are owned by the wallet's address.
-    async fn mints(&self, ctx: &Context<'_>) -> Result<Option<Vec<CollectionMint>>> {
+    /// The NFTs that were minted from Holaplex and are owned by the wallet's address, from the most recently created.
+    /// Use `limit` and `offset` to page through the NFTs. `limit` defaults to 25 and is capped at 100.
+    async fn mints(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default)] filter: OwnedMintsFilter,
+        limit: Option<u64>,
+        #[graphql(default)] offset: u64,
+    ) -> Result<Option<Vec<CollectionMint>>> {
         let AppContext {
-            collection_mints_owner_loader,
-            ..
+            owned_mints_loader, ..
         } = ctx.data::<AppContext>()?;
 
-        collection_mints_owner_loader
-            .load_one(self.address.clone().unwrap_or(String::new()))
+        let Some(address) = self.address.clone() else {
+            return Ok(None);
+        };
+
+        owned_mints_loader
+            .load_one(OwnedMintsKey::new(vec![address], filter, limit, offset))
             .await
     }
 }
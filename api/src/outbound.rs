@@ -0,0 +1,231 @@
+//! HTTP requests to urls supplied by users.
+//!
+//! Clients built here resolve hosts with a resolver that drops loopback, private, link-local and other non-public addresses,
+//! so a name is checked every time a connection is made rather than only when its url is accepted.
+//! Urls with an IP address as their host skip resolution and are checked with `check_url` before sending and when following redirects.
+
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    sync::Arc,
+};
+
+use hub_core::{anyhow::Result, prelude::*, thiserror, tokio, url::Host};
+use reqwest::{
+    dns::{Addrs, Name, Resolve, Resolving},
+    redirect, ClientBuilder, Response,
+};
+
+/// The number of redirects followed before a request fails
+const MAX_REDIRECTS: usize = 5;
+
+/// A request was refused because its host does not resolve to a public address
+#[derive(Debug, thiserror::Error)]
+#[error("{0} does not resolve to a public address")]
+pub struct NotPublic(String);
+
+/// Returns a client builder whose requests can only reach public addresses
+#[must_use]
+pub fn client_builder() -> ClientBuilder {
+    reqwest::Client::builder()
+        .dns_resolver(Arc::new(PublicResolver))
+        .redirect(redirect::Policy::custom(|attempt| {
+            if attempt.previous().len() >= MAX_REDIRECTS {
+                attempt.error("too many redirects")
+            } else if let Err(e) = check_url(attempt.url()) {
+                match e.downcast::<NotPublic>() {
+                    Ok(e) => attempt.error(e),
+                    Err(e) => attempt.error(e.to_string()),
+                }
+            } else {
+                attempt.follow()
+            }
+        }))
+}
+
+/// Checks a url has a host and that the host is not an IP address outside of the public internet
+///
+/// # Errors
+/// if the url has no host or its host is a non-public IP address
+pub fn check_url(url: &Url) -> Result<()> {
+    let ip = match url.host() {
+        Some(Host::Ipv4(ip)) => IpAddr::V4(ip),
+        Some(Host::Ipv6(ip)) => IpAddr::V6(ip),
+        Some(Host::Domain(_)) => return Ok(()),
+        None => bail!("url {url} has no host"),
+    };
+
+    if !is_public(ip) {
+        return Err(NotPublic(url.to_string()).into());
+    }
+
+    Ok(())
+}
+
+/// Returns true if a request failed because its url or a redirect pointed to a non-public address
+#[must_use]
+pub fn is_not_public(error: &(dyn std::error::Error + 'static)) -> bool {
+    let mut source = Some(error);
+
+    while let Some(error) = source {
+        if error.is::<NotPublic>() {
+            return true;
+        }
+
+        source = error.source();
+    }
+
+    false
+}
+
+/// Reads the body of a response, failing as soon as it grows past `max_bytes`
+///
+/// # Errors
+/// if the body cannot be read or is larger than `max_bytes`
+pub async fn read_limited(mut response: Response, max_bytes: u64) -> Result<Vec<u8>> {
+    if response
+        .content_length()
+        .is_some_and(|length| length > max_bytes)
+    {
+        bail!("response is larger than {max_bytes} bytes");
+    }
+
+    let mut contents = Vec::new();
+
+    while let Some(chunk) = response.chunk().await? {
+        contents.extend_from_slice(&chunk);
+
+        if u64::try_from(contents.len()).unwrap_or(u64::MAX) > max_bytes {
+            bail!("response is larger than {max_bytes} bytes");
+        }
+    }
+
+    Ok(contents)
+}
+
+/// Returns true if the address is routable on the public internet
+#[must_use]
+pub fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_v4(ip),
+        IpAddr::V6(ip) => ip
+            .to_ipv4_mapped()
+            .map_or_else(|| is_public_v6(ip), is_public_v4),
+    }
+}
+
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, c, _] = ip.octets();
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        // 0.0.0.0/8
+        || a == 0
+        // shared address space 100.64.0.0/10
+        || (a == 100 && (b & 0xc0) == 64)
+        // IETF protocol assignments 192.0.0.0/24
+        || (a == 192 && b == 0 && c == 0)
+        // benchmarking 198.18.0.0/15
+        || (a == 198 && (b & 0xfe) == 18)
+        // reserved 240.0.0.0/4
+        || a >= 240)
+}
+
+fn is_public_v6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        // unique local fc00::/7
+        || (first & 0xfe00) == 0xfc00
+        // link-local fe80::/10
+        || (first & 0xffc0) == 0xfe80
+        // documentation 2001:db8::/32
+        || (first == 0x2001 && ip.segments()[1] == 0x0db8))
+}
+
+/// Resolves hosts with the system resolver and drops every address that is not public
+#[derive(Debug, Clone, Copy)]
+struct PublicResolver;
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(resolve_public(name.as_str().to_string()))
+    }
+}
+
+async fn resolve_public(host: String) -> Result<Addrs, Box<dyn std::error::Error + Send + Sync>> {
+    let addrs = tokio::net::lookup_host((host.as_str(), 0))
+        .await?
+        .filter(|addr| is_public(addr.ip()))
+        .collect::<Vec<SocketAddr>>();
+
+    if addrs.is_empty() {
+        return Err(NotPublic(host).into());
+    }
+
+    Ok(Box::new(addrs.into_iter()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(ip: &str) -> IpAddr {
+        ip.parse().unwrap()
+    }
+
+    #[test]
+    fn is_public_accepts_public_addresses() {
+        for address in [
+            "1.1.1.1",
+            "8.8.8.8",
+            "2606:4700:4700::1111",
+            "::ffff:8.8.8.8",
+        ] {
+            assert!(is_public(ip(address)), "{address} is public");
+        }
+    }
+
+    #[test]
+    fn is_public_rejects_non_public_addresses() {
+        for address in [
+            "0.0.0.0",
+            "127.0.0.1",
+            "10.0.0.1",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "192.0.0.1",
+            "198.18.0.1",
+            "240.0.0.1",
+            "255.255.255.255",
+            "::",
+            "::1",
+            "fc00::1",
+            "fe80::1",
+            "2001:db8::1",
+            "ff02::1",
+            "::ffff:127.0.0.1",
+        ] {
+            assert!(!is_public(ip(address)), "{address} is not public");
+        }
+    }
+
+    #[test]
+    fn check_url_rejects_non_public_ip_hosts() {
+        let private = Url::parse("https://10.0.0.1/hook").unwrap();
+        let public = Url::parse("https://1.1.1.1/hook").unwrap();
+        let domain = Url::parse("https://example.com/hook").unwrap();
+
+        assert!(check_url(&private).is_err());
+        assert!(check_url(&public).is_ok());
+        assert!(check_url(&domain).is_ok());
+    }
+}
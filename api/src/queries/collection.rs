@@ -1,7 +1,7 @@
 use async_graphql::{Context, Object, Result};
 use hub_core::uuid::Uuid;
 
-use crate::{objects::Collection, AppContext};
+use crate::{entities::collection_mint_updates, objects::Collection, AppContext};
 
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Query;
@@ -17,4 +17,18 @@ impl Query {
 
         project_collection_loader.load_one(id).await
     }
+
+    /// Look up the progress of a collection mints update by its ID.
+    async fn collection_mints_update(
+        &self,
+        ctx: &Context<'_>,
+        id: Uuid,
+    ) -> Result<Option<collection_mint_updates::Model>> {
+        let AppContext {
+            collection_mint_update_loader,
+            ..
+        } = ctx.data::<AppContext>()?;
+
+        collection_mint_update_loader.load_one(id).await
+    }
 }
@@ -1,7 +1,7 @@
 use async_graphql::{Context, Object, Result};
 use hub_core::uuid::Uuid;
 
-use crate::{objects::Drop, AppContext};
+use crate::{entities::drop_manifests, objects::Drop, AppContext};
 
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Query;
@@ -14,4 +14,18 @@ impl Query {
 
         drop_loader.load_one(id).await
     }
+
+    /// Look up the progress of a drop manifest by its ID.
+    async fn drop_manifest(
+        &self,
+        ctx: &Context<'_>,
+        id: Uuid,
+    ) -> Result<Option<drop_manifests::Model>> {
+        let AppContext {
+            drop_manifest_loader,
+            ..
+        } = ctx.data::<AppContext>()?;
+
+        drop_manifest_loader.load_one(id).await
+    }
 }
@@ -0,0 +1,24 @@
+use async_graphql::{Context, Object, Result};
+use hub_core::uuid::Uuid;
+
+use crate::{entities::collection_exports, AppContext};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Query;
+
+#[Object(name = "ExportQuery")]
+impl Query {
+    /// Look up a collection export by its ID.
+    async fn collection_export(
+        &self,
+        ctx: &Context<'_>,
+        id: Uuid,
+    ) -> Result<Option<collection_exports::Model>> {
+        let AppContext {
+            collection_export_loader,
+            ..
+        } = ctx.data::<AppContext>()?;
+
+        collection_export_loader.load_one(id).await
+    }
+}
@@ -0,0 +1,45 @@
+use async_graphql::{Context, Object, Result};
+use hub_core::{
+    chrono::{DateTime, Utc},
+    uuid::Uuid,
+};
+
+use crate::{
+    entities::holder_snapshots,
+    objects::{holder_snapshot::owners_at, SnapshotHolder},
+    AppContext,
+};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Query;
+
+#[Object(name = "HolderSnapshotQuery")]
+impl Query {
+    /// Computes the holders of a collection at a point in time from the mint and transfer histories of its NFTs.
+    async fn holder_snapshot(
+        &self,
+        ctx: &Context<'_>,
+        collection_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<Vec<SnapshotHolder>> {
+        let AppContext { db, .. } = ctx.data::<AppContext>()?;
+
+        let owners = owners_at(db.get(), collection_id, at).await?;
+
+        Ok(SnapshotHolder::group(owners))
+    }
+
+    /// Look up a holder snapshot saved with `createHolderSnapshot` by its ID.
+    async fn saved_holder_snapshot(
+        &self,
+        ctx: &Context<'_>,
+        id: Uuid,
+    ) -> Result<Option<holder_snapshots::Model>> {
+        let AppContext {
+            holder_snapshot_loader,
+            ..
+        } = ctx.data::<AppContext>()?;
+
+        holder_snapshot_loader.load_one(id).await
+    }
+}
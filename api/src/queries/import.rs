@@ -0,0 +1,17 @@
+use async_graphql::{Context, Object, Result};
+use hub_core::uuid::Uuid;
+
+use crate::{entities::imports, AppContext};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Query;
+
+#[Object(name = "ImportQuery")]
+impl Query {
+    /// Look up the progress of a collection import by its ID.
+    async fn import_status(&self, ctx: &Context<'_>, id: Uuid) -> Result<Option<imports::Model>> {
+        let AppContext { import_loader, .. } = ctx.data::<AppContext>()?;
+
+        import_loader.load_one(id).await
+    }
+}
@@ -1,7 +1,36 @@
-use async_graphql::{Context, Object, Result};
+use async_graphql::{
+    connection::{self, Connection, Edge, OpaqueCursor},
+    Context, Error, InputObject, Object, Result,
+};
 use hub_core::uuid::Uuid;
+use sea_orm::{prelude::*, sea_query::Expr, JoinType, QueryOrder, QuerySelect, Value};
+use serde::{Deserialize, Serialize};
 
-use crate::{objects::CollectionMint, AppContext};
+use crate::{
+    entities::{collection_mints, collections, sea_orm_active_enums::CreationStatus},
+    objects::CollectionMint,
+    AppContext,
+};
+
+/// The number of mints returned by `searchMints` when `first` is not provided.
+const DEFAULT_SEARCH_LIMIT: usize = 25;
+/// The maximum number of mints returned by a single `searchMints` page.
+const MAX_SEARCH_LIMIT: usize = 100;
+
+/// Matches mints whose metadata json name or description matches a full-text query.
+/// Polygon mints share the metadata json of their collection.
+/// The `to_tsvector` expression must match the one of the `metadata_jsons_search_idx` index.
+const FULL_TEXT_FILTER: &str = "EXISTS (SELECT 1 FROM metadata_jsons
+    WHERE metadata_jsons.id = CASE WHEN collections.blockchain = 'polygon'
+            THEN collection_mints.collection_id ELSE collection_mints.id END
+        AND to_tsvector('english', metadata_jsons.name || ' ' || metadata_jsons.description)
+            @@ plainto_tsquery('english', ?))";
+
+/// Matches mints with an attribute of a trait type. The conditions of `AttributeFilter` are appended before the closing parenthesis.
+const ATTRIBUTE_FILTER: &str = "EXISTS (SELECT 1 FROM metadata_json_attributes
+    WHERE metadata_json_attributes.metadata_json_id = CASE WHEN collections.blockchain = 'polygon'
+            THEN collection_mints.collection_id ELSE collection_mints.id END
+        AND metadata_json_attributes.trait_type = ?";
 
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Query;
@@ -17,4 +46,164 @@ impl Query {
 
         single_collection_mint_loader.load_one(id).await
     }
+
+    /// Search the NFTs of a project. `query` is matched against the name and description of the metadata json using Postgres full-text search.
+    /// Every attribute filter must match an attribute of the NFT. Queued mints are only returned when filtering by the `QUEUED` status.
+    /// Results are ordered from the most recently created NFT and paginated with the `after` cursor of the last edge.
+    #[allow(clippy::too_many_arguments)]
+    async fn search_mints(
+        &self,
+        ctx: &Context<'_>,
+        project: Uuid,
+        query: Option<String>,
+        attributes: Option<Vec<AttributeFilter>>,
+        collection: Option<Uuid>,
+        owner: Option<String>,
+        status: Option<CreationStatus>,
+        after: Option<String>,
+        first: Option<i32>,
+    ) -> Result<Connection<OpaqueCursor<MintCursor>, CollectionMint>> {
+        let AppContext { db, .. } = ctx.data::<AppContext>()?;
+        let conn = db.get();
+
+        connection::query(
+            after,
+            None,
+            first,
+            None,
+            |after: Option<OpaqueCursor<MintCursor>>, _, first, _| async move {
+                let limit = first.unwrap_or(DEFAULT_SEARCH_LIMIT).min(MAX_SEARCH_LIMIT);
+
+                let mut select = collection_mints::Entity::find()
+                    .join(
+                        JoinType::InnerJoin,
+                        collection_mints::Relation::Collections.def(),
+                    )
+                    .filter(collections::Column::ProjectId.eq(project));
+
+                select = match status {
+                    Some(status) => {
+                        select.filter(collection_mints::Column::CreationStatus.eq(status))
+                    },
+                    None => select.filter(
+                        collection_mints::Column::CreationStatus.ne(CreationStatus::Queued),
+                    ),
+                };
+
+                if let Some(collection) = collection {
+                    select = select.filter(collection_mints::Column::CollectionId.eq(collection));
+                }
+
+                if let Some(owner) = owner {
+                    let owner = if owner.starts_with("0x") {
+                        owner.to_lowercase()
+                    } else {
+                        owner
+                    };
+
+                    select = select.filter(collection_mints::Column::Owner.eq(owner));
+                }
+
+                if let Some(search) = query.filter(|search| !search.trim().is_empty()) {
+                    select = select.filter(Expr::cust_with_values(FULL_TEXT_FILTER, [search]));
+                }
+
+                for filter in attributes.unwrap_or_default() {
+                    let (sql, values) = filter.to_sql();
+
+                    select = select.filter(Expr::cust_with_values(sql, values));
+                }
+
+                if let Some(OpaqueCursor(MintCursor { created_at, id })) = &after {
+                    select = select.filter(Expr::cust_with_values(
+                        "(collection_mints.created_at, collection_mints.id) < (?, ?)",
+                        [Value::from(*created_at), Value::from(*id)],
+                    ));
+                }
+
+                let mut mints = select
+                    .order_by_desc(collection_mints::Column::CreatedAt)
+                    .order_by_desc(collection_mints::Column::Id)
+                    .limit(u64::try_from(limit + 1)?)
+                    .all(conn)
+                    .await?;
+
+                let has_next_page = mints.len() > limit;
+                mints.truncate(limit);
+
+                let mut page = Connection::new(after.is_some(), has_next_page);
+
+                page.edges.extend(mints.into_iter().map(|mint| {
+                    Edge::new(
+                        OpaqueCursor(MintCursor {
+                            created_at: mint.created_at,
+                            id: mint.id,
+                        }),
+                        mint.into(),
+                    )
+                }));
+
+                Ok::<_, Error>(page)
+            },
+        )
+        .await
+    }
+}
+
+/// Position of a mint in `searchMints` results.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct MintCursor {
+    created_at: DateTimeWithTimeZone,
+    id: Uuid,
+}
+
+/// Matches NFTs with an attribute of the trait type. Every condition that is set must hold.
+/// Range conditions only match attributes whose value is of the `NUMBER` or `DATE` type.
+#[derive(Debug, Clone, InputObject)]
+pub struct AttributeFilter {
+    /// The name of the attribute.
+    pub trait_type: String,
+    /// The value of the attribute.
+    pub value: Option<String>,
+    /// The smallest value of a `NUMBER` attribute.
+    pub min: Option<f64>,
+    /// The largest value of a `NUMBER` attribute.
+    pub max: Option<f64>,
+    /// The earliest value of a `DATE` attribute.
+    pub from: Option<DateTimeWithTimeZone>,
+    /// The latest value of a `DATE` attribute.
+    pub to: Option<DateTimeWithTimeZone>,
+}
+
+impl AttributeFilter {
+    /// Returns the `ATTRIBUTE_FILTER` condition with the conditions of the filter and its values.
+    fn to_sql(&self) -> (String, Vec<Value>) {
+        let mut sql = String::from(ATTRIBUTE_FILTER);
+        let mut values = vec![Value::from(self.trait_type.clone())];
+
+        if let Some(value) = &self.value {
+            sql.push_str(" AND metadata_json_attributes.value = ?");
+            values.push(value.clone().into());
+        }
+
+        let ranges = [
+            ("number_value >= ?", self.min.map(Value::from)),
+            ("number_value <= ?", self.max.map(Value::from)),
+            ("date_value >= ?", self.from.map(Value::from)),
+            ("date_value <= ?", self.to.map(Value::from)),
+        ];
+
+        for (condition, value) in ranges
+            .into_iter()
+            .filter_map(|(condition, value)| value.map(|value| (condition, value)))
+        {
+            sql.push_str(" AND metadata_json_attributes.");
+            sql.push_str(condition);
+            values.push(value);
+        }
+
+        sql.push(')');
+
+        (sql, values)
+    }
 }
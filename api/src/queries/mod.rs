@@ -3,9 +3,14 @@
 mod collection;
 mod customer;
 mod drop;
+mod export;
+mod holder_snapshot;
+mod import;
 mod mint;
 mod project;
+mod transfer;
 mod wallet;
+mod webhook;
 
 // // Add your other ones here to create a unified Query object
 #[derive(async_graphql::MergedObject, Default)]
@@ -16,4 +21,9 @@ pub struct Query(
     mint::Query,
     collection::Query,
     drop::Query,
+    import::Query,
+    transfer::Query,
+    holder_snapshot::Query,
+    export::Query,
+    webhook::Query,
 );
@@ -0,0 +1,29 @@
+use async_graphql::{Context, Object, Result};
+use hub_core::uuid::Uuid;
+
+use crate::{entities::transfers, objects::TransferBatch, AppContext};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Query;
+
+#[Object(name = "TransferQuery")]
+impl Query {
+    /// Look up a transfer by its ID.
+    async fn transfer(&self, ctx: &Context<'_>, id: Uuid) -> Result<Option<transfers::Model>> {
+        let AppContext {
+            transfer_loader, ..
+        } = ctx.data::<AppContext>()?;
+
+        transfer_loader.load_one(id).await
+    }
+
+    /// Look up the progress of a batch of transfers by the batch ID returned from `transferAssetsBatched`.
+    async fn transfer_batch(&self, ctx: &Context<'_>, id: Uuid) -> Result<Option<TransferBatch>> {
+        let AppContext {
+            transfer_batch_loader,
+            ..
+        } = ctx.data::<AppContext>()?;
+
+        transfer_batch_loader.load_one(id).await
+    }
+}
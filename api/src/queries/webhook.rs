@@ -0,0 +1,79 @@
+use async_graphql::{Context, Object, Result};
+use hub_core::uuid::Uuid;
+use sea_orm::{prelude::*, QueryOrder, QuerySelect};
+
+use crate::{
+    entities::{
+        sea_orm_active_enums::WebhookDeliveryStatus, webhook_deliveries, webhook_endpoints,
+    },
+    AppContext,
+};
+
+/// The number of deliveries returned by `webhookDeliveries` when `limit` is not provided.
+const DEFAULT_DELIVERIES_LIMIT: u64 = 50;
+/// The maximum number of deliveries returned by `webhookDeliveries`.
+const MAX_DELIVERIES_LIMIT: u64 = 500;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Query;
+
+#[Object(name = "WebhookQuery")]
+impl Query {
+    /// The webhook endpoints of a project.
+    async fn webhook_endpoints(
+        &self,
+        ctx: &Context<'_>,
+        project: Uuid,
+    ) -> Result<Vec<webhook_endpoints::Model>> {
+        let AppContext { db, .. } = ctx.data::<AppContext>()?;
+
+        webhook_endpoints::Entity::find()
+            .filter(webhook_endpoints::Column::ProjectId.eq(project))
+            .order_by_asc(webhook_endpoints::Column::CreatedAt)
+            .all(db.get())
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Look up a webhook endpoint by its ID.
+    async fn webhook_endpoint(
+        &self,
+        ctx: &Context<'_>,
+        id: Uuid,
+    ) -> Result<Option<webhook_endpoints::Model>> {
+        let AppContext { db, .. } = ctx.data::<AppContext>()?;
+
+        webhook_endpoints::Entity::find_by_id(id)
+            .one(db.get())
+            .await
+            .map_err(Into::into)
+    }
+
+    /// The webhooks sent to an endpoint, most recent first. Use `status` to only return pending, delivered or failed deliveries and `limit` and `offset` to page through them.
+    async fn webhook_deliveries(
+        &self,
+        ctx: &Context<'_>,
+        endpoint: Uuid,
+        status: Option<WebhookDeliveryStatus>,
+        limit: Option<u64>,
+        #[graphql(default)] offset: u64,
+    ) -> Result<Vec<webhook_deliveries::Model>> {
+        let AppContext { db, .. } = ctx.data::<AppContext>()?;
+
+        let mut query = webhook_deliveries::Entity::find()
+            .filter(webhook_deliveries::Column::EndpointId.eq(endpoint))
+            .order_by_desc(webhook_deliveries::Column::CreatedAt)
+            .limit(
+                limit
+                    .unwrap_or(DEFAULT_DELIVERIES_LIMIT)
+                    .min(MAX_DELIVERIES_LIMIT),
+            )
+            .offset(offset);
+
+        if let Some(status) = status {
+            query = query.filter(webhook_deliveries::Column::Status.eq(status));
+        }
+
+        query.all(db.get()).await.map_err(Into::into)
+    }
+}
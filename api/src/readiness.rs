@@ -0,0 +1,187 @@
+//! Readiness checks of the dependencies hub-nfts needs to serve traffic.
+//!
+//! `/health` only reports that the process is alive. `/ready` pings Postgres, Redis, the Kafka brokers
+//! the producer publishes to and, when enabled, the storage backend, and reports the status and latency of each.
+//! Kafka is pinged by building a producer from the producer configuration of the service, so it connects with the same brokers and credentials.
+
+use std::time::Instant;
+
+use hub_core::{anyhow::Result, clap, prelude::*, producer, tokio};
+use redis::Client as Redis;
+use serde::Serialize;
+
+use crate::{db::Connection, hub_uploads::HubUploadClient, proto::NftEvents};
+
+/// Arguments for checking the readiness of the service
+#[derive(Debug, clap::Args)]
+pub struct ReadinessArgs {
+    /// Ping the storage backend of metadata jsons in the readiness check
+    #[arg(long, env, default_value_t = false)]
+    pub ready_check_hub_uploads: bool,
+    /// The time allowed for each dependency to respond, in milliseconds
+    #[arg(long, env, default_value_t = 2000)]
+    pub ready_timeout_ms: u64,
+}
+
+/// Pings the dependencies of the service
+#[derive(Clone)]
+pub struct Readiness {
+    connection: Connection,
+    redis: Redis,
+    kafka: producer::Config,
+    hub_uploads: Option<HubUploadClient>,
+    timeout: Duration,
+}
+
+impl std::fmt::Debug for Readiness {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Readiness")
+            .field("timeout", &self.timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The status of a single dependency
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Up,
+    Down,
+}
+
+/// The outcome of pinging a dependency
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Check {
+    pub status: CheckStatus,
+    pub latency_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// The outcome of pinging every dependency
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadinessReport {
+    pub status: CheckStatus,
+    pub postgres: Check,
+    pub redis: Check,
+    pub kafka: Check,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hub_uploads: Option<Check>,
+}
+
+impl Readiness {
+    /// Returns the readiness checks of the service
+    #[must_use]
+    pub fn new(
+        args: ReadinessArgs,
+        connection: Connection,
+        redis: Redis,
+        kafka: producer::Config,
+        hub_uploads: HubUploadClient,
+    ) -> Self {
+        let ReadinessArgs {
+            ready_check_hub_uploads,
+            ready_timeout_ms,
+        } = args;
+
+        Self {
+            connection,
+            redis,
+            kafka,
+            hub_uploads: ready_check_hub_uploads.then_some(hub_uploads),
+            timeout: Duration::from_millis(ready_timeout_ms),
+        }
+    }
+
+    /// Pings every dependency concurrently
+    pub async fn check(&self) -> ReadinessReport {
+        let (postgres, redis, kafka, hub_uploads) = tokio::join!(
+            self.run(self.ping_postgres()),
+            self.run(self.ping_redis()),
+            self.run(self.ping_kafka()),
+            async {
+                match &self.hub_uploads {
+                    Some(hub_uploads) => Some(self.run(hub_uploads.ping()).await),
+                    None => None,
+                }
+            },
+        );
+
+        let up = [&postgres, &redis, &kafka]
+            .into_iter()
+            .chain(hub_uploads.as_ref())
+            .all(|check| check.status == CheckStatus::Up);
+
+        ReadinessReport {
+            status: if up {
+                CheckStatus::Up
+            } else {
+                CheckStatus::Down
+            },
+            postgres,
+            redis,
+            kafka,
+            hub_uploads,
+        }
+    }
+
+    /// Times a ping, failing it if it does not complete within the timeout
+    async fn run(&self, ping: impl std::future::Future<Output = Result<()>>) -> Check {
+        let started_at = Instant::now();
+
+        let result = tokio::time::timeout(self.timeout, ping)
+            .await
+            .unwrap_or_else(|_| Err(anyhow!("timed out after {}ms", self.timeout.as_millis())));
+
+        let latency_ms = u64::try_from(started_at.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+        match result {
+            Ok(()) => Check {
+                status: CheckStatus::Up,
+                latency_ms,
+                error: None,
+            },
+            Err(e) => Check {
+                status: CheckStatus::Down,
+                latency_ms,
+                error: Some(format!("{e:#}")),
+            },
+        }
+    }
+
+    async fn ping_postgres(&self) -> Result<()> {
+        self.connection
+            .get()
+            .ping()
+            .await
+            .context("failed to ping database")
+    }
+
+    async fn ping_redis(&self) -> Result<()> {
+        let mut conn = self
+            .redis
+            .get_async_connection()
+            .await
+            .context("failed to connect to redis")?;
+
+        let _: String = redis::cmd("PING")
+            .query_async(&mut conn)
+            .await
+            .context("failed to ping redis")?;
+
+        Ok(())
+    }
+
+    /// Builds a producer with the configuration of the event producer, which fails when the brokers cannot be reached
+    async fn ping_kafka(&self) -> Result<()> {
+        self.kafka
+            .clone()
+            .build::<NftEvents>()
+            .await
+            .context("failed to connect to kafka")?;
+
+        Ok(())
+    }
+}
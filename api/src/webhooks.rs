@@ -0,0 +1,509 @@
+//! Signed webhooks sent by hub-nfts to the endpoints of a project.
+//!
+//! Each webhook is a `POST` of a JSON envelope `{ id, type, projectId, createdAt, data }`.
+//! The body is signed with HMAC-SHA256 using the secret of the endpoint over `{timestamp}.{body}`,
+//! where the timestamp is the `X-Hub-Webhook-Timestamp` header, and the hex digest is sent as `X-Hub-Webhook-Signature: v1={digest}`.
+//!
+//! A pending delivery is sent when its `next_attempt_at` is due. Queueing a delivery pushes `next_attempt_at` past the time its job is given to run,
+//! so a delivery whose job is lost is queued again by `Webhooks::poll` rather than waiting in a worker. Deliveries are sent at least once.
+
+use hmac::{Hmac, Mac};
+use hub_core::{
+    anyhow::Result,
+    chrono::{DateTime, Duration as ChronoDuration, Utc},
+    clap,
+    prelude::*,
+    tokio,
+    uuid::Uuid,
+};
+use reqwest::{header::CONTENT_TYPE, StatusCode};
+use sea_orm::{prelude::*, sea_query::Expr, JoinType, QuerySelect, Set};
+use serde_json::{json, Value as Json};
+use sha2::Sha256;
+
+use crate::{
+    background_worker::{job_queue::JobQueue, tasks::WebhookDeliveryTask},
+    db::Connection,
+    entities::{
+        sea_orm_active_enums::{WebhookDeliveryStatus, WebhookEventType},
+        webhook_deliveries, webhook_endpoints, webhook_subscriptions,
+    },
+    outbound,
+};
+
+pub const ID_HEADER: &str = "X-Hub-Webhook-Id";
+pub const EVENT_HEADER: &str = "X-Hub-Webhook-Event";
+pub const TIMESTAMP_HEADER: &str = "X-Hub-Webhook-Timestamp";
+pub const SIGNATURE_HEADER: &str = "X-Hub-Webhook-Signature";
+
+/// The number of bytes of a response body kept on the delivery
+const RESPONSE_BODY_LIMIT: usize = 1024;
+
+/// The largest response body read from an endpoint. Larger bodies are not kept.
+const MAX_RESPONSE_BODY_BYTES: u64 = 64 * 1024;
+
+/// The longest wait between two attempts of a delivery
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60 * 60);
+
+/// The minutes a queued delivery is given to be sent before it is queued again
+const QUEUED_DELIVERY_TIMEOUT_MINS: i64 = 10;
+
+/// The wait between two checks for deliveries due to be sent
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Arguments for sending webhooks
+#[derive(Debug, clap::Args)]
+pub struct WebhookArgs {
+    /// The time allowed for an endpoint to respond, in seconds
+    #[arg(long, env, default_value_t = 10)]
+    pub webhook_timeout_secs: u64,
+    /// The number of attempts made before a delivery is marked as failed
+    #[arg(long, env, default_value_t = 8)]
+    pub webhook_max_attempts: i32,
+    /// The wait after the first failed attempt, in seconds. The wait doubles with every further attempt.
+    #[arg(long, env, default_value_t = 30)]
+    pub webhook_retry_base_secs: u64,
+    /// Accept `http://` endpoint urls and endpoints on loopback or private addresses, e.g. to send webhooks to a local HTTP stub.
+    /// For local development only.
+    #[arg(long, env, default_value_t = false)]
+    pub webhook_allow_insecure_urls: bool,
+}
+
+/// Records webhook deliveries for emitted events and sends them to the subscribed endpoints
+#[derive(Debug, Clone)]
+pub struct Webhooks {
+    http: reqwest::Client,
+    job_queue: JobQueue,
+    max_attempts: i32,
+    retry_base: Duration,
+    allow_insecure_urls: bool,
+}
+
+impl Webhooks {
+    /// Returns the webhook sender
+    ///
+    /// # Errors
+    /// if the http client fails to build
+    pub fn new(args: WebhookArgs, job_queue: JobQueue) -> Result<Self> {
+        let WebhookArgs {
+            webhook_timeout_secs,
+            webhook_max_attempts,
+            webhook_retry_base_secs,
+            webhook_allow_insecure_urls,
+        } = args;
+
+        let http = if webhook_allow_insecure_urls {
+            reqwest::Client::builder()
+        } else {
+            outbound::client_builder()
+        };
+
+        let http = http
+            .timeout(Duration::from_secs(webhook_timeout_secs))
+            .build()
+            .context("failed to build webhook http client")?;
+
+        Ok(Self {
+            http,
+            job_queue,
+            max_attempts: webhook_max_attempts.max(1),
+            retry_base: Duration::from_secs(webhook_retry_base_secs),
+            allow_insecure_urls: webhook_allow_insecure_urls,
+        })
+    }
+
+    /// The number of attempts made before a delivery is marked as failed
+    #[must_use]
+    pub fn max_attempts(&self) -> i32 {
+        self.max_attempts
+    }
+
+    /// Returns the wait before the next attempt of a delivery that has failed `attempts` times
+    #[must_use]
+    pub fn retry_delay(&self, attempts: i32) -> Duration {
+        backoff(self.retry_base, attempts)
+    }
+
+    /// Checks an endpoint url can be sent webhooks
+    ///
+    /// # Errors
+    /// if the url is invalid, is not `https` or does not resolve to a public address while insecure urls are not accepted
+    pub async fn validate_url(&self, url: &str) -> Result<Url> {
+        let url = Url::parse(url).context("invalid webhook url")?;
+
+        match url.scheme() {
+            "https" => (),
+            "http" if self.allow_insecure_urls => (),
+            scheme => bail!("webhook url scheme {scheme} is not supported"),
+        }
+
+        if self.allow_insecure_urls {
+            return Ok(url);
+        }
+
+        outbound::check_url(&url)?;
+
+        let host = url.host_str().context("webhook url has no host")?;
+        let port = url.port_or_known_default().unwrap_or_default();
+        let mut addrs = tokio::net::lookup_host((host, port))
+            .await
+            .with_context(|| format!("failed to resolve webhook url host {host}"))?;
+
+        if !addrs.all(|addr| outbound::is_public(addr.ip())) {
+            bail!("webhook url {url} does not resolve to a public address");
+        }
+
+        Ok(url)
+    }
+
+    /// Queues the pending deliveries that are due to be sent and returns the number queued
+    ///
+    /// # Errors
+    /// if the deliveries cannot be read or queued
+    pub async fn queue_due(&self, conn: &DatabaseConnection) -> Result<usize> {
+        let now = Utc::now();
+
+        let deliveries = webhook_deliveries::Entity::update_many()
+            .col_expr(
+                webhook_deliveries::Column::NextAttemptAt,
+                Expr::value(queued_until(now)),
+            )
+            .filter(webhook_deliveries::Column::Status.eq(WebhookDeliveryStatus::Pending))
+            .filter(webhook_deliveries::Column::NextAttemptAt.lte(now))
+            .exec_with_returning(conn)
+            .await?;
+
+        for delivery in &deliveries {
+            self.job_queue
+                .enqueue(WebhookDeliveryTask::new(delivery.id))
+                .await?;
+        }
+
+        Ok(deliveries.len())
+    }
+
+    /// Queues the deliveries that are due to be sent every few seconds, until the process exits
+    pub async fn poll(&self, db: &Connection) {
+        let mut interval = tokio::time::interval(POLL_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            if let Err(e) = self.queue_due(db.get()).await {
+                error!("failed to queue due webhook deliveries: {e:?}");
+            }
+        }
+    }
+
+    /// Records a delivery of the event for every active endpoint of the project subscribed to it and queues them to be sent
+    ///
+    /// # Errors
+    /// if the deliveries cannot be saved or queued
+    pub async fn emit(
+        &self,
+        conn: &DatabaseConnection,
+        project_id: Uuid,
+        event_type: WebhookEventType,
+        data: Json,
+    ) -> Result<()> {
+        let endpoints = webhook_endpoints::Entity::find()
+            .join(
+                JoinType::InnerJoin,
+                webhook_endpoints::Relation::WebhookSubscriptions.def(),
+            )
+            .filter(webhook_endpoints::Column::ProjectId.eq(project_id))
+            .filter(webhook_endpoints::Column::Active.eq(true))
+            .filter(webhook_subscriptions::Column::EventType.eq(event_type))
+            .all(conn)
+            .await?;
+
+        let created_at = Utc::now();
+
+        for endpoint in endpoints {
+            let id = Uuid::new_v4();
+
+            let delivery_am = webhook_deliveries::ActiveModel {
+                id: Set(id),
+                endpoint_id: Set(endpoint.id),
+                event_type: Set(event_type),
+                payload: Set(envelope(
+                    id,
+                    project_id,
+                    event_type,
+                    created_at,
+                    data.clone(),
+                )),
+                status: Set(WebhookDeliveryStatus::Pending),
+                next_attempt_at: Set(Some(queued_until(created_at).into())),
+                created_at: Set(created_at.into()),
+                updated_at: Set(created_at.into()),
+                ..Default::default()
+            };
+
+            delivery_am.insert(conn).await?;
+
+            self.job_queue.enqueue(WebhookDeliveryTask::new(id)).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Posts the payload of a delivery to the endpoint and returns the status and the start of the body of the response
+    ///
+    /// # Errors
+    /// if the endpoint url is invalid or is not public while insecure urls are not accepted, or the endpoint cannot be reached
+    pub async fn send(
+        &self,
+        endpoint: &webhook_endpoints::Model,
+        delivery: &webhook_deliveries::Model,
+    ) -> Result<(StatusCode, String)> {
+        let url = Url::parse(&endpoint.url).context("invalid webhook url")?;
+
+        if !self.allow_insecure_urls {
+            outbound::check_url(&url)?;
+        }
+
+        post(&self.http, url, &endpoint.secret, delivery).await
+    }
+}
+
+/// Returns the wait before the next attempt of a delivery that has failed `attempts` times, doubling `retry_base` with every attempt after the first
+fn backoff(retry_base: Duration, attempts: i32) -> Duration {
+    let exponent = u32::try_from(attempts.saturating_sub(1)).unwrap_or_default();
+
+    2u32.checked_pow(exponent)
+        .and_then(|factor| retry_base.checked_mul(factor))
+        .map_or(MAX_RETRY_DELAY, |delay| delay.min(MAX_RETRY_DELAY))
+}
+
+/// Posts the signed payload of a delivery to a url and returns the status and the start of the body of the response
+async fn post(
+    http: &reqwest::Client,
+    url: Url,
+    secret: &str,
+    delivery: &webhook_deliveries::Model,
+) -> Result<(StatusCode, String)> {
+    let body = serde_json::to_vec(&delivery.payload)?;
+    let timestamp = Utc::now().timestamp();
+    let signature = sign(secret, timestamp, &body)?;
+
+    let response = http
+        .post(url.clone())
+        .header(CONTENT_TYPE, "application/json")
+        .header(ID_HEADER, delivery.id.to_string())
+        .header(EVENT_HEADER, delivery.event_type.to_value())
+        .header(TIMESTAMP_HEADER, timestamp.to_string())
+        .header(SIGNATURE_HEADER, format!("v1={signature}"))
+        .body(body)
+        .send()
+        .await
+        .with_context(|| format!("failed to send webhook to {url}"))?;
+
+    let status = response.status();
+    let body = outbound::read_limited(response, MAX_RESPONSE_BODY_BYTES)
+        .await
+        .map(|body| String::from_utf8_lossy(&body).into_owned())
+        .unwrap_or_default();
+
+    Ok((status, truncate(body)))
+}
+
+/// Returns the hex encoded HMAC-SHA256 of `{timestamp}.{body}` keyed with the secret of an endpoint
+///
+/// # Errors
+/// if the secret cannot be used as a key
+pub fn sign(secret: &str, timestamp: i64, body: &[u8]) -> Result<String> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .map_err(|_| anyhow!("invalid webhook secret"))?;
+
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+
+    Ok(hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Returns when a delivery queued at `now` is queued again if it has not been sent
+#[must_use]
+pub fn queued_until(now: DateTime<Utc>) -> DateTime<Utc> {
+    now + ChronoDuration::minutes(QUEUED_DELIVERY_TIMEOUT_MINS)
+}
+
+/// Returns a new secret for signing the webhooks of an endpoint
+#[must_use]
+pub fn generate_secret() -> String {
+    format!(
+        "whsec_{}{}",
+        Uuid::new_v4().simple(),
+        Uuid::new_v4().simple()
+    )
+}
+
+fn envelope(
+    id: Uuid,
+    project_id: Uuid,
+    event_type: WebhookEventType,
+    created_at: DateTime<Utc>,
+    data: Json,
+) -> Json {
+    json!({
+        "id": id,
+        "type": event_type.to_value(),
+        "projectId": project_id,
+        "createdAt": created_at.to_rfc3339(),
+        "data": data,
+    })
+}
+
+fn truncate(mut body: String) -> String {
+    if body.len() > RESPONSE_BODY_LIMIT {
+        let mut end = RESPONSE_BODY_LIMIT;
+
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        body.truncate(end);
+    }
+
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use hub_core::tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    #[test]
+    fn sign_is_hmac_sha256_of_timestamp_and_body() {
+        let signature = sign("whsec_test", 1_700_000_000, br#"{"id":1}"#).unwrap();
+
+        assert_eq!(
+            signature,
+            "2f441ba4b3b2d50d28a9ab9d9fd8880376ecd1eb5d0435401553f5d8d0a5dcf8"
+        );
+        assert_ne!(
+            sign("whsec_other", 1_700_000_000, br#"{"id":1}"#).unwrap(),
+            signature
+        );
+        assert_ne!(
+            sign("whsec_test", 1_700_000_001, br#"{"id":1}"#).unwrap(),
+            signature
+        );
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_max_delay() {
+        let base = Duration::from_secs(30);
+
+        assert_eq!(backoff(base, 0), base);
+        assert_eq!(backoff(base, 1), base);
+        assert_eq!(backoff(base, 2), Duration::from_secs(60));
+        assert_eq!(backoff(base, 4), Duration::from_secs(240));
+        assert_eq!(backoff(base, 8), MAX_RETRY_DELAY);
+        assert_eq!(backoff(base, i32::MAX), MAX_RETRY_DELAY);
+    }
+
+    #[test]
+    fn truncate_keeps_whole_characters() {
+        let body = "é".repeat(RESPONSE_BODY_LIMIT);
+
+        let truncated = truncate(body);
+
+        assert_eq!(truncated.len(), RESPONSE_BODY_LIMIT);
+        assert!(truncated.chars().all(|c| c == 'é'));
+    }
+
+    /// Accepts one request, returns it and responds with `201 Created` and a body of `ok`
+    async fn stub_endpoint(listener: TcpListener) -> String {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+
+        loop {
+            let read = stream.read(&mut buf).await.unwrap();
+
+            if read == 0 {
+                break;
+            }
+
+            request.extend_from_slice(&buf[..read]);
+
+            let text = String::from_utf8_lossy(&request);
+
+            if let Some(end) = text.find("\r\n\r\n") {
+                let content_length = text[..end]
+                    .lines()
+                    .find_map(|line| {
+                        let (name, value) = line.split_once(':')?;
+                        name.eq_ignore_ascii_case("content-length")
+                            .then(|| value.trim().parse::<usize>().ok())
+                            .flatten()
+                    })
+                    .unwrap_or_default();
+
+                if request.len() >= end + 4 + content_length {
+                    break;
+                }
+            }
+        }
+
+        stream
+            .write_all(b"HTTP/1.1 201 Created\r\ncontent-length: 2\r\nconnection: close\r\n\r\nok")
+            .await
+            .unwrap();
+
+        String::from_utf8(request).unwrap()
+    }
+
+    #[tokio::test]
+    async fn post_sends_a_signed_delivery() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!("http://{}/hook", listener.local_addr().unwrap())).unwrap();
+        let endpoint = tokio::spawn(stub_endpoint(listener));
+
+        let now = Utc::now().into();
+        let delivery = webhook_deliveries::Model {
+            id: Uuid::from_u128(1),
+            endpoint_id: Uuid::from_u128(2),
+            event_type: WebhookEventType::CollectionCreated,
+            payload: json!({ "id": 1 }),
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 0,
+            response_status: None,
+            response_body: None,
+            last_error: None,
+            next_attempt_at: None,
+            created_at: now,
+            updated_at: now,
+            delivered_at: None,
+        };
+
+        let (status, body) = post(&reqwest::Client::new(), url, "whsec_test", &delivery)
+            .await
+            .unwrap();
+        let request = endpoint.await.unwrap();
+
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body, "ok");
+
+        let headers = request.to_lowercase();
+        let timestamp = headers
+            .lines()
+            .find_map(|line| line.strip_prefix("x-hub-webhook-timestamp: "))
+            .unwrap()
+            .parse::<i64>()
+            .unwrap();
+        let signature = sign("whsec_test", timestamp, br#"{"id":1}"#).unwrap();
+
+        assert!(request.starts_with("POST /hook HTTP/1.1"));
+        assert!(headers.contains("x-hub-webhook-id: 00000000-0000-0000-0000-000000000001"));
+        assert!(headers.contains("x-hub-webhook-event: collection_created"));
+        assert!(headers.contains(&format!("x-hub-webhook-signature: v1={signature}")));
+        assert!(request.ends_with(r#"{"id":1}"#));
+    }
+}
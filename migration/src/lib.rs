@@ -61,6 +61,34 @@ mod m20230914_154759_add_job_trackings_table;
 mod m20230915_111128_create_mints_creation_status_idx;
 mod m20230922_150621_nullable_metadata_jsons_identifier_and_uri;
 mod m20231011_202917_create_queued_mints_idx;
+mod m20231016_093512_create_imports_table;
+mod m20231016_101247_add_import_columns_to_collection_mints;
+mod m20231017_090412_add_batch_columns_to_transfer_charges;
+mod m20231017_143120_create_transfers_table;
+mod m20231018_101522_create_holder_snapshots_tables;
+mod m20231019_094215_create_retry_histories_table;
+mod m20231019_151030_create_mint_search_indexes;
+mod m20231020_103344_create_collection_mint_rarities_table;
+mod m20231020_161805_create_collection_mints_collection_id_owner_idx;
+mod m20231021_093014_create_collection_exports_table;
+mod m20231022_101204_create_drop_manifests_tables;
+mod m20231023_141522_create_metadata_json_uploads_table;
+mod m20231024_112041_create_media_assets_table;
+mod m20231025_094417_add_typed_values_to_metadata_json_attributes;
+mod m20231026_143208_create_metadata_json_versions_table;
+mod m20231027_101532_create_collection_mint_updates_tables;
+mod m20231030_090512_create_webhook_tables;
+mod m20231031_093204_create_import_mint_failures_table;
+mod m20231101_104512_add_storage_to_metadata_json_uploads;
+mod m20231101_141036_add_ingestion_error_to_metadata_jsons;
+mod m20231101_160218_add_erc_columns_to_metadata_jsons;
+mod m20231101_173645_backfill_typed_values_of_metadata_json_attributes;
+mod m20231102_093021_add_document_to_metadata_jsons;
+mod m20231102_141207_add_collection_mint_update_id_to_update_histories;
+mod m20231102_163204_schedule_pending_webhook_deliveries;
+mod m20231103_090214_allow_one_pending_import_per_collection;
+mod m20231103_101530_move_transfer_batch_id_to_transfers;
+mod m20231103_104211_drop_status_from_transfer_charges;
 
 pub struct Migrator;
 
@@ -129,6 +157,34 @@ impl MigratorTrait for Migrator {
             Box::new(m20230914_154759_add_job_trackings_table::Migration),
             Box::new(m20230922_150621_nullable_metadata_jsons_identifier_and_uri::Migration),
             Box::new(m20231011_202917_create_queued_mints_idx::Migration),
+            Box::new(m20231016_093512_create_imports_table::Migration),
+            Box::new(m20231016_101247_add_import_columns_to_collection_mints::Migration),
+            Box::new(m20231017_090412_add_batch_columns_to_transfer_charges::Migration),
+            Box::new(m20231017_143120_create_transfers_table::Migration),
+            Box::new(m20231018_101522_create_holder_snapshots_tables::Migration),
+            Box::new(m20231019_094215_create_retry_histories_table::Migration),
+            Box::new(m20231019_151030_create_mint_search_indexes::Migration),
+            Box::new(m20231020_103344_create_collection_mint_rarities_table::Migration),
+            Box::new(m20231020_161805_create_collection_mints_collection_id_owner_idx::Migration),
+            Box::new(m20231021_093014_create_collection_exports_table::Migration),
+            Box::new(m20231022_101204_create_drop_manifests_tables::Migration),
+            Box::new(m20231023_141522_create_metadata_json_uploads_table::Migration),
+            Box::new(m20231024_112041_create_media_assets_table::Migration),
+            Box::new(m20231025_094417_add_typed_values_to_metadata_json_attributes::Migration),
+            Box::new(m20231026_143208_create_metadata_json_versions_table::Migration),
+            Box::new(m20231027_101532_create_collection_mint_updates_tables::Migration),
+            Box::new(m20231030_090512_create_webhook_tables::Migration),
+            Box::new(m20231031_093204_create_import_mint_failures_table::Migration),
+            Box::new(m20231101_104512_add_storage_to_metadata_json_uploads::Migration),
+            Box::new(m20231101_141036_add_ingestion_error_to_metadata_jsons::Migration),
+            Box::new(m20231101_160218_add_erc_columns_to_metadata_jsons::Migration),
+            Box::new(m20231101_173645_backfill_typed_values_of_metadata_json_attributes::Migration),
+            Box::new(m20231102_093021_add_document_to_metadata_jsons::Migration),
+            Box::new(m20231102_141207_add_collection_mint_update_id_to_update_histories::Migration),
+            Box::new(m20231102_163204_schedule_pending_webhook_deliveries::Migration),
+            Box::new(m20231103_090214_allow_one_pending_import_per_collection::Migration),
+            Box::new(m20231103_101530_move_transfer_batch_id_to_transfers::Migration),
+            Box::new(m20231103_104211_drop_status_from_transfer_charges::Migration),
         ]
     }
 }
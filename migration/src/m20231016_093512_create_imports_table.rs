@@ -0,0 +1,109 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20230214_212301_create_collections_table::CreationStatus;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Imports::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Imports::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("default gen_random_uuid()".to_string()),
+                    )
+                    .col(ColumnDef::new(Imports::ProjectId).uuid().not_null())
+                    .col(ColumnDef::new(Imports::CollectionId).uuid().not_null())
+                    .col(ColumnDef::new(Imports::CollectionAddress).text().not_null())
+                    .col(
+                        ColumnDef::new(Imports::Status)
+                            .custom(CreationStatus::Type)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Imports::MintsExpected).big_integer().null())
+                    .col(
+                        ColumnDef::new(Imports::MintsIndexed)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(Imports::MintsFailed)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(Imports::MintsRemoved)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(Imports::LastError).text().null())
+                    .col(ColumnDef::new(Imports::CreatedBy).uuid().not_null())
+                    .col(
+                        ColumnDef::new(Imports::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("default now()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(Imports::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("default now()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(Imports::CompletedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("imports_collection_id_idx")
+                    .table(Imports::Table)
+                    .col(Imports::CollectionId)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Imports::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum Imports {
+    Table,
+    Id,
+    ProjectId,
+    CollectionId,
+    CollectionAddress,
+    Status,
+    MintsExpected,
+    MintsIndexed,
+    MintsFailed,
+    MintsRemoved,
+    LastError,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+    CompletedAt,
+}
@@ -0,0 +1,68 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CollectionMints::Table)
+                    .add_column_if_not_exists(
+                        ColumnDef::new(CollectionMints::ImportId).uuid().null(),
+                    )
+                    .add_column_if_not_exists(
+                        ColumnDef::new(CollectionMints::RemovedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("collection_mints_collection_id_address_idx")
+                    .table(CollectionMints::Table)
+                    .col(CollectionMints::CollectionId)
+                    .col(CollectionMints::Address)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("collection_mints_collection_id_address_idx")
+                    .table(CollectionMints::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CollectionMints::Table)
+                    .drop_column(CollectionMints::ImportId)
+                    .drop_column(CollectionMints::RemovedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+/// Learn more at https://docs.rs/sea-query#iden
+#[derive(Iden)]
+enum CollectionMints {
+    Table,
+    CollectionId,
+    Address,
+    ImportId,
+    RemovedAt,
+}
@@ -0,0 +1,84 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20230214_212301_create_collections_table::CreationStatus;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // charges created before the status was tracked are considered processed
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TransferCharges::Table)
+                    .add_column_if_not_exists(
+                        ColumnDef::new(TransferCharges::BatchId).uuid().null(),
+                    )
+                    .add_column_if_not_exists(
+                        ColumnDef::new(TransferCharges::CollectionMintId)
+                            .uuid()
+                            .null(),
+                    )
+                    .add_column_if_not_exists(
+                        ColumnDef::new(TransferCharges::Status)
+                            .custom(CreationStatus::Type)
+                            .not_null()
+                            .extra("default 'created'".to_string()),
+                    )
+                    .add_column_if_not_exists(
+                        ColumnDef::new(TransferCharges::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("default now()".to_string()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("transfer_charges_batch_id_idx")
+                    .table(TransferCharges::Table)
+                    .col(TransferCharges::BatchId)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("transfer_charges_batch_id_idx")
+                    .table(TransferCharges::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TransferCharges::Table)
+                    .drop_column(TransferCharges::BatchId)
+                    .drop_column(TransferCharges::CollectionMintId)
+                    .drop_column(TransferCharges::Status)
+                    .drop_column(TransferCharges::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+/// Learn more at https://docs.rs/sea-query#iden
+#[derive(Iden)]
+enum TransferCharges {
+    Table,
+    BatchId,
+    CollectionMintId,
+    Status,
+    CreatedAt,
+}
@@ -0,0 +1,103 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20230214_212301_create_collections_table::CreationStatus,
+    m20230220_223223_create_collection_mints_table::CollectionMints,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Transfers::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Transfers::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("default gen_random_uuid()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(Transfers::CollectionMintId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Transfers::Sender).text().not_null())
+                    .col(ColumnDef::new(Transfers::Recipient).text().not_null())
+                    .col(
+                        ColumnDef::new(Transfers::Status)
+                            .custom(CreationStatus::Type)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Transfers::Signature).text().null())
+                    .col(
+                        ColumnDef::new(Transfers::CreditsDeductionId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Transfers::CreatedBy).uuid().not_null())
+                    .col(
+                        ColumnDef::new(Transfers::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("default now()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(Transfers::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("default now()".to_string()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-transfers_collection_mint_id")
+                            .from(Transfers::Table, Transfers::CollectionMintId)
+                            .to(CollectionMints::Table, CollectionMints::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("transfers_collection_mint_id_status_idx")
+                    .table(Transfers::Table)
+                    .col(Transfers::CollectionMintId)
+                    .col(Transfers::Status)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Transfers::Table).to_owned())
+            .await
+    }
+}
+
+/// Learn more at https://docs.rs/sea-query#iden
+#[derive(Iden)]
+enum Transfers {
+    Table,
+    Id,
+    CollectionMintId,
+    Sender,
+    Recipient,
+    Status,
+    Signature,
+    CreditsDeductionId,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,155 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20230214_212301_create_collections_table::Collections,
+    m20230220_223223_create_collection_mints_table::CollectionMints,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(HolderSnapshots::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(HolderSnapshots::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("default gen_random_uuid()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(HolderSnapshots::CollectionId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(HolderSnapshots::Name).text().not_null())
+                    .col(
+                        ColumnDef::new(HolderSnapshots::TakenAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(HolderSnapshots::CreatedBy).uuid().not_null())
+                    .col(
+                        ColumnDef::new(HolderSnapshots::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("default now()".to_string()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-holder_snapshots_collection_id")
+                            .from(HolderSnapshots::Table, HolderSnapshots::CollectionId)
+                            .to(Collections::Table, Collections::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("holder_snapshots_collection_id_idx")
+                    .table(HolderSnapshots::Table)
+                    .col(HolderSnapshots::CollectionId)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(HolderSnapshotMints::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(HolderSnapshotMints::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("default gen_random_uuid()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(HolderSnapshotMints::SnapshotId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(HolderSnapshotMints::CollectionMintId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(HolderSnapshotMints::Owner).text().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-holder_snapshot_mints_snapshot_id")
+                            .from(HolderSnapshotMints::Table, HolderSnapshotMints::SnapshotId)
+                            .to(HolderSnapshots::Table, HolderSnapshots::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-holder_snapshot_mints_collection_mint_id")
+                            .from(
+                                HolderSnapshotMints::Table,
+                                HolderSnapshotMints::CollectionMintId,
+                            )
+                            .to(CollectionMints::Table, CollectionMints::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("holder_snapshot_mints_snapshot_id_idx")
+                    .table(HolderSnapshotMints::Table)
+                    .col(HolderSnapshotMints::SnapshotId)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(HolderSnapshotMints::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(HolderSnapshots::Table).to_owned())
+            .await
+    }
+}
+
+/// Learn more at https://docs.rs/sea-query#iden
+#[derive(Iden)]
+enum HolderSnapshots {
+    Table,
+    Id,
+    CollectionId,
+    Name,
+    TakenAt,
+    CreatedBy,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum HolderSnapshotMints {
+    Table,
+    Id,
+    SnapshotId,
+    CollectionMintId,
+    Owner,
+}
@@ -0,0 +1,98 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20230214_212301_create_collections_table::Collections,
+    m20230220_223223_create_collection_mints_table::CollectionMints,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RetryHistories::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(RetryHistories::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("default gen_random_uuid()".to_string()),
+                    )
+                    .col(ColumnDef::new(RetryHistories::ProjectId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(RetryHistories::CollectionId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(RetryHistories::CollectionMintId).uuid())
+                    .col(ColumnDef::new(RetryHistories::Action).text().not_null())
+                    .col(
+                        ColumnDef::new(RetryHistories::CreditsDeductionId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(RetryHistories::CreatedBy).uuid().not_null())
+                    .col(
+                        ColumnDef::new(RetryHistories::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("default now()".to_string()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-retry_histories_collection_id")
+                            .from(RetryHistories::Table, RetryHistories::CollectionId)
+                            .to(Collections::Table, Collections::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-retry_histories_collection_mint_id")
+                            .from(RetryHistories::Table, RetryHistories::CollectionMintId)
+                            .to(CollectionMints::Table, CollectionMints::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("retry_histories_project_id_created_at_idx")
+                    .table(RetryHistories::Table)
+                    .col(RetryHistories::ProjectId)
+                    .col(RetryHistories::CreatedAt)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RetryHistories::Table).to_owned())
+            .await
+    }
+}
+
+/// Learn more at https://docs.rs/sea-query#iden
+#[derive(Iden)]
+enum RetryHistories {
+    Table,
+    Id,
+    ProjectId,
+    CollectionId,
+    CollectionMintId,
+    Action,
+    CreditsDeductionId,
+    CreatedBy,
+    CreatedAt,
+}
@@ -0,0 +1,91 @@
+use sea_orm_migration::{
+    prelude::*,
+    sea_orm::{ConnectionTrait, Statement},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // The expression must match the one used by the `searchMints` query for the index to be used.
+        let stmt = Statement::from_string(
+            manager.get_database_backend(),
+            r#"CREATE INDEX IF NOT EXISTS metadata_jsons_search_idx
+            ON metadata_jsons
+            USING GIN (to_tsvector('english', name || ' ' || description));"#
+                .to_string(),
+        );
+
+        db.execute(stmt).await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("metadata_json_attributes_metadata_json_id_idx")
+                    .table(MetadataJsonAttributes::Table)
+                    .col(MetadataJsonAttributes::MetadataJsonId)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("metadata_json_attributes_trait_type_value_idx")
+                    .table(MetadataJsonAttributes::Table)
+                    .col(MetadataJsonAttributes::TraitType)
+                    .col(MetadataJsonAttributes::Value)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("metadata_json_attributes_trait_type_value_idx")
+                    .table(MetadataJsonAttributes::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("metadata_json_attributes_metadata_json_id_idx")
+                    .table(MetadataJsonAttributes::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("metadata_jsons_search_idx")
+                    .table(MetadataJsons::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+/// Learn more at https://docs.rs/sea-query#iden
+#[derive(Iden)]
+enum MetadataJsons {
+    Table,
+}
+
+#[derive(Iden)]
+enum MetadataJsonAttributes {
+    Table,
+    MetadataJsonId,
+    TraitType,
+    Value,
+}
@@ -0,0 +1,104 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20230214_212301_create_collections_table::Collections,
+    m20230220_223223_create_collection_mints_table::CollectionMints,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CollectionMintRarities::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CollectionMintRarities::CollectionMintId)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionMintRarities::CollectionId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionMintRarities::Score)
+                            .double()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionMintRarities::Rank)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionMintRarities::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("default now()".to_string()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-collection_mint_rarities_collection_mint_id")
+                            .from(
+                                CollectionMintRarities::Table,
+                                CollectionMintRarities::CollectionMintId,
+                            )
+                            .to(CollectionMints::Table, CollectionMints::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-collection_mint_rarities_collection_id")
+                            .from(
+                                CollectionMintRarities::Table,
+                                CollectionMintRarities::CollectionId,
+                            )
+                            .to(Collections::Table, Collections::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("collection_mint_rarities_collection_id_idx")
+                    .table(CollectionMintRarities::Table)
+                    .col(CollectionMintRarities::CollectionId)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(CollectionMintRarities::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+/// Learn more at https://docs.rs/sea-query#iden
+#[derive(Iden)]
+enum CollectionMintRarities {
+    Table,
+    CollectionMintId,
+    CollectionId,
+    Score,
+    Rank,
+    UpdatedAt,
+}
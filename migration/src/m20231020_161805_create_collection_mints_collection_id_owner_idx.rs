@@ -0,0 +1,34 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20230220_223223_create_collection_mints_table::CollectionMints;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("collection_mints_collection_id_owner_idx")
+                    .table(CollectionMints::Table)
+                    .col(CollectionMints::CollectionId)
+                    .col(CollectionMints::Owner)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("collection_mints_collection_id_owner_idx")
+                    .table(CollectionMints::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,151 @@
+use sea_orm_migration::{prelude::*, sea_query::extension::postgres::Type};
+
+use crate::{
+    m20230214_212301_create_collections_table::{Collections, CreationStatus},
+    m20230215_194724_create_drops_table::Drops,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(ExportFormat::Type)
+                    .values([ExportFormat::Csv, ExportFormat::Json])
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(CollectionExports::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CollectionExports::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("default gen_random_uuid()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionExports::CollectionId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(CollectionExports::DropId).uuid().null())
+                    .col(
+                        ColumnDef::new(CollectionExports::Format)
+                            .custom(ExportFormat::Type)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionExports::Status)
+                            .custom(CreationStatus::Type)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(CollectionExports::Uri).text().null())
+                    .col(ColumnDef::new(CollectionExports::Cid).text().null())
+                    .col(
+                        ColumnDef::new(CollectionExports::Rows)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(CollectionExports::LastError).text().null())
+                    .col(
+                        ColumnDef::new(CollectionExports::CreatedBy)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionExports::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("default now()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionExports::CompletedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-collection_exports_collection_id")
+                            .from(CollectionExports::Table, CollectionExports::CollectionId)
+                            .to(Collections::Table, Collections::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-collection_exports_drop_id")
+                            .from(CollectionExports::Table, CollectionExports::DropId)
+                            .to(Drops::Table, Drops::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("collection_exports_collection_id_idx")
+                    .table(CollectionExports::Table)
+                    .col(CollectionExports::CollectionId)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CollectionExports::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_type(Type::drop().if_exists().name(ExportFormat::Type).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum CollectionExports {
+    Table,
+    Id,
+    CollectionId,
+    DropId,
+    Format,
+    Status,
+    Uri,
+    Cid,
+    Rows,
+    LastError,
+    CreatedBy,
+    CreatedAt,
+    CompletedAt,
+}
+
+pub enum ExportFormat {
+    Type,
+    Csv,
+    Json,
+}
+
+impl Iden for ExportFormat {
+    fn unquoted(&self, s: &mut dyn std::fmt::Write) {
+        write!(s, "{}", match self {
+            Self::Type => "export_format",
+            Self::Csv => "csv",
+            Self::Json => "json",
+        })
+        .unwrap();
+    }
+}
@@ -0,0 +1,187 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20230214_212301_create_collections_table::CreationStatus,
+    m20230215_194724_create_drops_table::Drops,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(DropManifests::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(DropManifests::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("default gen_random_uuid()".to_string()),
+                    )
+                    .col(ColumnDef::new(DropManifests::DropId).uuid().not_null())
+                    .col(ColumnDef::new(DropManifests::SourceUrl).text().null())
+                    .col(ColumnDef::new(DropManifests::Entries).json_binary().null())
+                    .col(
+                        ColumnDef::new(DropManifests::Status)
+                            .custom(CreationStatus::Type)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DropManifests::RowsTotal)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(DropManifests::RowsProcessed)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(DropManifests::RowsQueued)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(DropManifests::RowsFailed)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(DropManifests::LastError).text().null())
+                    .col(ColumnDef::new(DropManifests::CreatedBy).uuid().not_null())
+                    .col(
+                        ColumnDef::new(DropManifests::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("default now()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(DropManifests::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("default now()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(DropManifests::CompletedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-drop_manifests_drop_id")
+                            .from(DropManifests::Table, DropManifests::DropId)
+                            .to(Drops::Table, Drops::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("drop_manifests_drop_id_idx")
+                    .table(DropManifests::Table)
+                    .col(DropManifests::DropId)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(DropManifestErrors::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(DropManifestErrors::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("default gen_random_uuid()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(DropManifestErrors::ManifestId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DropManifestErrors::Row)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DropManifestErrors::Message)
+                            .text()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-drop_manifest_errors_manifest_id")
+                            .from(DropManifestErrors::Table, DropManifestErrors::ManifestId)
+                            .to(DropManifests::Table, DropManifests::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("drop_manifest_errors_manifest_id_idx")
+                    .table(DropManifestErrors::Table)
+                    .col(DropManifestErrors::ManifestId)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(DropManifestErrors::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(DropManifests::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum DropManifests {
+    Table,
+    Id,
+    DropId,
+    SourceUrl,
+    Entries,
+    Status,
+    RowsTotal,
+    RowsProcessed,
+    RowsQueued,
+    RowsFailed,
+    LastError,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+    CompletedAt,
+}
+
+#[derive(Iden)]
+enum DropManifestErrors {
+    Table,
+    Id,
+    ManifestId,
+    Row,
+    Message,
+}
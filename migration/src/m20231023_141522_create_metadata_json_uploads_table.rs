@@ -0,0 +1,84 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(MetadataJsons::Table)
+                    .add_column_if_not_exists(
+                        ColumnDef::new(MetadataJsons::ContentHash).text().null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("metadata_jsons_content_hash_idx")
+                    .table(MetadataJsons::Table)
+                    .col(MetadataJsons::ContentHash)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(MetadataJsonUploads::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(MetadataJsonUploads::ContentHash)
+                            .text()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(MetadataJsonUploads::Uri).text().not_null())
+                    .col(ColumnDef::new(MetadataJsonUploads::Cid).text().not_null())
+                    .col(
+                        ColumnDef::new(MetadataJsonUploads::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("default now()".to_string()),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(MetadataJsonUploads::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(MetadataJsons::Table)
+                    .drop_column(MetadataJsons::ContentHash)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum MetadataJsons {
+    Table,
+    ContentHash,
+}
+
+#[derive(Iden)]
+enum MetadataJsonUploads {
+    Table,
+    ContentHash,
+    Uri,
+    Cid,
+    CreatedAt,
+}
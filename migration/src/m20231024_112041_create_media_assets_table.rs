@@ -0,0 +1,66 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(MediaAssets::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(MediaAssets::ContentHash)
+                            .text()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(MediaAssets::Uri).text().not_null())
+                    .col(ColumnDef::new(MediaAssets::Cid).text().not_null())
+                    .col(ColumnDef::new(MediaAssets::ContentType).text().not_null())
+                    .col(ColumnDef::new(MediaAssets::Size).big_integer().not_null())
+                    .col(ColumnDef::new(MediaAssets::Width).integer().null())
+                    .col(ColumnDef::new(MediaAssets::Height).integer().null())
+                    .col(
+                        ColumnDef::new(MediaAssets::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("default now()".to_string()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("media_assets_uri_idx")
+                    .table(MediaAssets::Table)
+                    .col(MediaAssets::Uri)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(MediaAssets::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum MediaAssets {
+    Table,
+    ContentHash,
+    Uri,
+    Cid,
+    ContentType,
+    Size,
+    Width,
+    Height,
+    CreatedAt,
+}
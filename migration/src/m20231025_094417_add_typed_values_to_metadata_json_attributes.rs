@@ -0,0 +1,144 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(AttributeValueType::Type)
+                    .values([
+                        AttributeValueType::String,
+                        AttributeValueType::Number,
+                        AttributeValueType::Boolean,
+                        AttributeValueType::Date,
+                    ])
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(MetadataJsonAttributes::Table)
+                    .add_column_if_not_exists(
+                        ColumnDef::new(MetadataJsonAttributes::ValueType)
+                            .custom(AttributeValueType::Type)
+                            .not_null()
+                            .extra("default 'string'".to_string()),
+                    )
+                    .add_column_if_not_exists(
+                        ColumnDef::new(MetadataJsonAttributes::NumberValue)
+                            .double()
+                            .null(),
+                    )
+                    .add_column_if_not_exists(
+                        ColumnDef::new(MetadataJsonAttributes::BooleanValue)
+                            .boolean()
+                            .null(),
+                    )
+                    .add_column_if_not_exists(
+                        ColumnDef::new(MetadataJsonAttributes::DateValue)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .add_column_if_not_exists(
+                        ColumnDef::new(MetadataJsonAttributes::DisplayType)
+                            .text()
+                            .null(),
+                    )
+                    .add_column_if_not_exists(
+                        ColumnDef::new(MetadataJsonAttributes::MaxValue)
+                            .double()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("metadata_json_attributes_trait_type_number_value_idx")
+                    .table(MetadataJsonAttributes::Table)
+                    .col(MetadataJsonAttributes::TraitType)
+                    .col(MetadataJsonAttributes::NumberValue)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("metadata_json_attributes_trait_type_date_value_idx")
+                    .table(MetadataJsonAttributes::Table)
+                    .col(MetadataJsonAttributes::TraitType)
+                    .col(MetadataJsonAttributes::DateValue)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(MetadataJsonAttributes::Table)
+                    .drop_column(MetadataJsonAttributes::ValueType)
+                    .drop_column(MetadataJsonAttributes::NumberValue)
+                    .drop_column(MetadataJsonAttributes::BooleanValue)
+                    .drop_column(MetadataJsonAttributes::DateValue)
+                    .drop_column(MetadataJsonAttributes::DisplayType)
+                    .drop_column(MetadataJsonAttributes::MaxValue)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_type(
+                Type::drop()
+                    .if_exists()
+                    .name(AttributeValueType::Type)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum MetadataJsonAttributes {
+    Table,
+    TraitType,
+    ValueType,
+    NumberValue,
+    BooleanValue,
+    DateValue,
+    DisplayType,
+    MaxValue,
+}
+
+pub enum AttributeValueType {
+    Type,
+    String,
+    Number,
+    Boolean,
+    Date,
+}
+
+impl Iden for AttributeValueType {
+    fn unquoted(&self, s: &mut dyn std::fmt::Write) {
+        write!(s, "{}", match self {
+            Self::Type => "attribute_value_type",
+            Self::String => "string",
+            Self::Number => "number",
+            Self::Boolean => "boolean",
+            Self::Date => "date",
+        })
+        .unwrap();
+    }
+}
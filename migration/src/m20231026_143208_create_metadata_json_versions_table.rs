@@ -0,0 +1,139 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20230220_223223_create_collection_mints_table::CollectionMints,
+    m20230807_090847_create_histories_table::UpdateHistories,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(MetadataJsonVersions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(MetadataJsonVersions::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("default gen_random_uuid()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(MetadataJsonVersions::CollectionMintId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(MetadataJsonVersions::Version)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(MetadataJsonVersions::UpdateHistoryId)
+                            .uuid()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(MetadataJsonVersions::MetadataJson)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(MetadataJsonVersions::ContentHash)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(MetadataJsonVersions::Uri).text().null())
+                    .col(
+                        ColumnDef::new(MetadataJsonVersions::Identifier)
+                            .text()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(MetadataJsonVersions::CreatedBy)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(MetadataJsonVersions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("default now()".to_string()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-metadata_json_versions_collection_mint_id")
+                            .from(
+                                MetadataJsonVersions::Table,
+                                MetadataJsonVersions::CollectionMintId,
+                            )
+                            .to(CollectionMints::Table, CollectionMints::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-metadata_json_versions_update_history_id")
+                            .from(
+                                MetadataJsonVersions::Table,
+                                MetadataJsonVersions::UpdateHistoryId,
+                            )
+                            .to(UpdateHistories::Table, UpdateHistories::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("metadata_json_versions_collection_mint_id_version_idx")
+                    .table(MetadataJsonVersions::Table)
+                    .col(MetadataJsonVersions::CollectionMintId)
+                    .col(MetadataJsonVersions::Version)
+                    .unique()
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("metadata_json_versions_update_history_id_idx")
+                    .table(MetadataJsonVersions::Table)
+                    .col(MetadataJsonVersions::UpdateHistoryId)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(MetadataJsonVersions::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum MetadataJsonVersions {
+    Table,
+    Id,
+    CollectionMintId,
+    Version,
+    UpdateHistoryId,
+    MetadataJson,
+    ContentHash,
+    Uri,
+    Identifier,
+    CreatedBy,
+    CreatedAt,
+}
@@ -0,0 +1,240 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20230214_212301_create_collections_table::{Collections, CreationStatus},
+    m20230220_223223_create_collection_mints_table::CollectionMints,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CollectionMintUpdates::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CollectionMintUpdates::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("default gen_random_uuid()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionMintUpdates::CollectionId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionMintUpdates::Patch)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionMintUpdates::Status)
+                            .custom(CreationStatus::Type)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionMintUpdates::RowsTotal)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionMintUpdates::RowsProcessed)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionMintUpdates::RowsUpdated)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionMintUpdates::RowsFailed)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionMintUpdates::LastMintId)
+                            .uuid()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionMintUpdates::LastError)
+                            .text()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionMintUpdates::OrganizationId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionMintUpdates::Balance)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionMintUpdates::CreatedBy)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionMintUpdates::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("default now()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionMintUpdates::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("default now()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionMintUpdates::CompletedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-collection_mint_updates_collection_id")
+                            .from(
+                                CollectionMintUpdates::Table,
+                                CollectionMintUpdates::CollectionId,
+                            )
+                            .to(Collections::Table, Collections::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("collection_mint_updates_collection_id_idx")
+                    .table(CollectionMintUpdates::Table)
+                    .col(CollectionMintUpdates::CollectionId)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(CollectionMintUpdateErrors::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CollectionMintUpdateErrors::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("default gen_random_uuid()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionMintUpdateErrors::UpdateId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionMintUpdateErrors::MintId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionMintUpdateErrors::Message)
+                            .text()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-collection_mint_update_errors_update_id")
+                            .from(
+                                CollectionMintUpdateErrors::Table,
+                                CollectionMintUpdateErrors::UpdateId,
+                            )
+                            .to(CollectionMintUpdates::Table, CollectionMintUpdates::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-collection_mint_update_errors_mint_id")
+                            .from(
+                                CollectionMintUpdateErrors::Table,
+                                CollectionMintUpdateErrors::MintId,
+                            )
+                            .to(CollectionMints::Table, CollectionMints::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("collection_mint_update_errors_update_id_idx")
+                    .table(CollectionMintUpdateErrors::Table)
+                    .col(CollectionMintUpdateErrors::UpdateId)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(CollectionMintUpdateErrors::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(CollectionMintUpdates::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum CollectionMintUpdates {
+    Table,
+    Id,
+    CollectionId,
+    Patch,
+    Status,
+    RowsTotal,
+    RowsProcessed,
+    RowsUpdated,
+    RowsFailed,
+    LastMintId,
+    LastError,
+    OrganizationId,
+    Balance,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+    CompletedAt,
+}
+
+#[derive(Iden)]
+enum CollectionMintUpdateErrors {
+    Table,
+    Id,
+    UpdateId,
+    MintId,
+    Message,
+}
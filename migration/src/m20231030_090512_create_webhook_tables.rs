@@ -0,0 +1,356 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(WebhookEventType::Type)
+                    .values([
+                        WebhookEventType::CollectionCreated,
+                        WebhookEventType::DropCreated,
+                        WebhookEventType::MintCreated,
+                        WebhookEventType::MintFailed,
+                        WebhookEventType::TransferCompleted,
+                        WebhookEventType::UpdateCompleted,
+                    ])
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(WebhookDeliveryStatus::Type)
+                    .values([
+                        WebhookDeliveryStatus::Pending,
+                        WebhookDeliveryStatus::Delivered,
+                        WebhookDeliveryStatus::Failed,
+                    ])
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebhookEndpoints::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(WebhookEndpoints::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("default gen_random_uuid()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookEndpoints::ProjectId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(WebhookEndpoints::Url).text().not_null())
+                    .col(ColumnDef::new(WebhookEndpoints::Secret).text().not_null())
+                    .col(ColumnDef::new(WebhookEndpoints::Description).text().null())
+                    .col(
+                        ColumnDef::new(WebhookEndpoints::Active)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookEndpoints::CreatedBy)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookEndpoints::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("default now()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookEndpoints::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("default now()".to_string()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("webhook_endpoints_project_id_idx")
+                    .table(WebhookEndpoints::Table)
+                    .col(WebhookEndpoints::ProjectId)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebhookSubscriptions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(WebhookSubscriptions::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("default gen_random_uuid()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookSubscriptions::EndpointId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookSubscriptions::EventType)
+                            .custom(WebhookEventType::Type)
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-webhook_subscriptions_endpoint_id")
+                            .from(
+                                WebhookSubscriptions::Table,
+                                WebhookSubscriptions::EndpointId,
+                            )
+                            .to(WebhookEndpoints::Table, WebhookEndpoints::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("webhook_subscriptions_endpoint_id_event_type_idx")
+                    .table(WebhookSubscriptions::Table)
+                    .col(WebhookSubscriptions::EndpointId)
+                    .col(WebhookSubscriptions::EventType)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebhookDeliveries::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("default gen_random_uuid()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::EndpointId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::EventType)
+                            .custom(WebhookEventType::Type)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::Payload)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::Status)
+                            .custom(WebhookDeliveryStatus::Type)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::ResponseStatus)
+                            .integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::ResponseBody)
+                            .text()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(WebhookDeliveries::LastError).text().null())
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::NextAttemptAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("default now()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("default now()".to_string()),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::DeliveredAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-webhook_deliveries_endpoint_id")
+                            .from(WebhookDeliveries::Table, WebhookDeliveries::EndpointId)
+                            .to(WebhookEndpoints::Table, WebhookEndpoints::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("webhook_deliveries_endpoint_id_created_at_idx")
+                    .table(WebhookDeliveries::Table)
+                    .col(WebhookDeliveries::EndpointId)
+                    .col(WebhookDeliveries::CreatedAt)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(WebhookDeliveries::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(WebhookSubscriptions::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(WebhookEndpoints::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_type(
+                Type::drop()
+                    .if_exists()
+                    .name(WebhookDeliveryStatus::Type)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_type(
+                Type::drop()
+                    .if_exists()
+                    .name(WebhookEventType::Type)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum WebhookEndpoints {
+    Table,
+    Id,
+    ProjectId,
+    Url,
+    Secret,
+    Description,
+    Active,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum WebhookSubscriptions {
+    Table,
+    Id,
+    EndpointId,
+    EventType,
+}
+
+#[derive(Iden)]
+enum WebhookDeliveries {
+    Table,
+    Id,
+    EndpointId,
+    EventType,
+    Payload,
+    Status,
+    Attempts,
+    ResponseStatus,
+    ResponseBody,
+    LastError,
+    NextAttemptAt,
+    CreatedAt,
+    UpdatedAt,
+    DeliveredAt,
+}
+
+pub enum WebhookEventType {
+    Type,
+    CollectionCreated,
+    DropCreated,
+    MintCreated,
+    MintFailed,
+    TransferCompleted,
+    UpdateCompleted,
+}
+
+impl Iden for WebhookEventType {
+    fn unquoted(&self, s: &mut dyn std::fmt::Write) {
+        write!(s, "{}", match self {
+            Self::Type => "webhook_event_type",
+            Self::CollectionCreated => "collection_created",
+            Self::DropCreated => "drop_created",
+            Self::MintCreated => "mint_created",
+            Self::MintFailed => "mint_failed",
+            Self::TransferCompleted => "transfer_completed",
+            Self::UpdateCompleted => "update_completed",
+        })
+        .unwrap();
+    }
+}
+
+pub enum WebhookDeliveryStatus {
+    Type,
+    Pending,
+    Delivered,
+    Failed,
+}
+
+impl Iden for WebhookDeliveryStatus {
+    fn unquoted(&self, s: &mut dyn std::fmt::Write) {
+        write!(s, "{}", match self {
+            Self::Type => "webhook_delivery_status",
+            Self::Pending => "pending",
+            Self::Delivered => "delivered",
+            Self::Failed => "failed",
+        })
+        .unwrap();
+    }
+}
@@ -0,0 +1,65 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20231016_093512_create_imports_table::Imports;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ImportMintFailures::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ImportMintFailures::ImportId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ImportMintFailures::MintAddress)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ImportMintFailures::Error).text().not_null())
+                    .col(
+                        ColumnDef::new(ImportMintFailures::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("default now()".to_string()),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(ImportMintFailures::ImportId)
+                            .col(ImportMintFailures::MintAddress),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-import_mint_failures_import_id")
+                            .from(ImportMintFailures::Table, ImportMintFailures::ImportId)
+                            .to(Imports::Table, Imports::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ImportMintFailures::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum ImportMintFailures {
+    Table,
+    ImportId,
+    MintAddress,
+    Error,
+    CreatedAt,
+}
@@ -0,0 +1,45 @@
+use sea_orm_migration::{
+    prelude::*,
+    sea_orm::{ConnectionTrait, Statement},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // The storage existing uploads were written to is unknown, so the cache is cleared and refilled by the next uploads
+        for sql in [
+            "DELETE FROM metadata_json_uploads;",
+            "ALTER TABLE metadata_json_uploads ADD COLUMN IF NOT EXISTS storage text NOT NULL;",
+            "ALTER TABLE metadata_json_uploads DROP CONSTRAINT IF EXISTS metadata_json_uploads_pkey;",
+            "ALTER TABLE metadata_json_uploads ADD PRIMARY KEY (storage, content_hash);",
+        ] {
+            let stmt = Statement::from_string(manager.get_database_backend(), sql.to_string());
+
+            db.execute(stmt).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        for sql in [
+            "DELETE FROM metadata_json_uploads;",
+            "ALTER TABLE metadata_json_uploads DROP CONSTRAINT IF EXISTS metadata_json_uploads_pkey;",
+            "ALTER TABLE metadata_json_uploads DROP COLUMN IF EXISTS storage;",
+            "ALTER TABLE metadata_json_uploads ADD PRIMARY KEY (content_hash);",
+        ] {
+            let stmt = Statement::from_string(manager.get_database_backend(), sql.to_string());
+
+            db.execute(stmt).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(MetadataJsons::Table)
+                    .add_column_if_not_exists(
+                        ColumnDef::new(MetadataJsons::BackgroundColor).text().null(),
+                    )
+                    .add_column_if_not_exists(
+                        ColumnDef::new(MetadataJsons::YoutubeUrl).text().null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(MetadataJsons::Table)
+                    .drop_column(MetadataJsons::BackgroundColor)
+                    .drop_column(MetadataJsons::YoutubeUrl)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum MetadataJsons {
+    Table,
+    BackgroundColor,
+    YoutubeUrl,
+}
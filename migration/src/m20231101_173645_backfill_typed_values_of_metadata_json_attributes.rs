@@ -0,0 +1,49 @@
+use sea_orm_migration::{
+    prelude::*,
+    sea_orm::{ConnectionTrait, Statement},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // Attributes saved before values were typed are all strings. Numeric ones become numbers so range filters match them,
+        // the same way imported attributes are typed. The patterns only accept values that cast without overflowing.
+        for sql in [
+            r#"UPDATE metadata_json_attributes
+            SET value_type = 'number', number_value = trim(value)::double precision
+            WHERE value_type = 'string'
+            AND number_value IS NULL
+            AND trim(value) ~ '^[-+]?([0-9]{1,30}(\.[0-9]{0,30})?|\.[0-9]{1,30})([eE][-+]?[0-9]{1,2})?$';"#,
+            r#"UPDATE metadata_json_attributes
+            SET number_value = trim(value)::double precision
+            WHERE value_type = 'number'
+            AND number_value IS NULL
+            AND trim(value) ~ '^[-+]?([0-9]{1,30}(\.[0-9]{0,30})?|\.[0-9]{1,30})([eE][-+]?[0-9]{1,2})?$';"#,
+            r#"UPDATE metadata_json_attributes
+            SET date_value = to_timestamp(trim(value)::bigint)
+            WHERE value_type = 'date'
+            AND date_value IS NULL
+            AND trim(value) ~ '^-?[0-9]{1,11}$';"#,
+            r#"UPDATE metadata_json_attributes
+            SET date_value = trim(value)::timestamptz
+            WHERE value_type = 'date'
+            AND date_value IS NULL
+            AND trim(value) ~ '^[0-9]{4}-[0-9]{2}-[0-9]{2}[Tt ][0-9]{2}:[0-9]{2}:[0-9]{2}(\.[0-9]+)?([Zz]|[-+][0-9]{2}:[0-9]{2})$';"#,
+        ] {
+            let stmt = Statement::from_string(manager.get_database_backend(), sql.to_string());
+
+            db.execute(stmt).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UpdateHistories::Table)
+                    .add_column_if_not_exists(
+                        ColumnDef::new(UpdateHistories::CollectionMintUpdateId)
+                            .uuid()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk-update_histories_collection_mint_update_id")
+                    .from(
+                        UpdateHistories::Table,
+                        UpdateHistories::CollectionMintUpdateId,
+                    )
+                    .to(CollectionMintUpdates::Table, CollectionMintUpdates::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("update_histories_collection_mint_update_id_idx")
+                    .table(UpdateHistories::Table)
+                    .col(UpdateHistories::CollectionMintUpdateId)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UpdateHistories::Table)
+                    .drop_column(UpdateHistories::CollectionMintUpdateId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum UpdateHistories {
+    Table,
+    CollectionMintUpdateId,
+}
+
+#[derive(Iden)]
+enum CollectionMintUpdates {
+    Table,
+    Id,
+}
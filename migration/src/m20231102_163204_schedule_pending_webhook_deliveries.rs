@@ -0,0 +1,42 @@
+use sea_orm_migration::{
+    prelude::*,
+    sea_orm::{ConnectionTrait, Statement},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // Pending deliveries are queued by polling their next attempt, so deliveries queued before it have to be scheduled.
+        for sql in [
+            r#"UPDATE webhook_deliveries
+            SET next_attempt_at = now()
+            WHERE status = 'pending'
+            AND next_attempt_at IS NULL;"#,
+            r#"CREATE INDEX IF NOT EXISTS webhook_deliveries_pending_next_attempt_at_idx
+            ON webhook_deliveries (next_attempt_at)
+            WHERE status = 'pending';"#,
+        ] {
+            let stmt = Statement::from_string(manager.get_database_backend(), sql.to_string());
+
+            db.execute(stmt).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("webhook_deliveries_pending_next_attempt_at_idx")
+                    .table(Alias::new("webhook_deliveries"))
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,47 @@
+use sea_orm_migration::{
+    prelude::*,
+    sea_orm::{ConnectionTrait, Statement},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // Imports started while another was pending never complete, since events only update the newest one.
+        for sql in [
+            r#"UPDATE imports
+            SET status = 'canceled', last_error = 'Superseded by a newer import', updated_at = now()
+            WHERE status = 'pending'
+            AND EXISTS (
+                SELECT 1 FROM imports newer
+                WHERE newer.collection_id = imports.collection_id
+                AND newer.status = 'pending'
+                AND newer.created_at > imports.created_at
+            );"#,
+            r#"CREATE UNIQUE INDEX IF NOT EXISTS imports_pending_collection_id_idx
+            ON imports (collection_id)
+            WHERE status = 'pending';"#,
+        ] {
+            let stmt = Statement::from_string(manager.get_database_backend(), sql.to_string());
+
+            db.execute(stmt).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("imports_pending_collection_id_idx")
+                    .table(Alias::new("imports"))
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,97 @@
+use sea_orm_migration::{
+    prelude::*,
+    sea_orm::{ConnectionTrait, Statement},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transfers::Table)
+                    .add_column_if_not_exists(ColumnDef::new(Transfers::BatchId).uuid().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                IndexCreateStatement::new()
+                    .name("transfers_batch_id_idx")
+                    .table(Transfers::Table)
+                    .col(Transfers::BatchId)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await?;
+
+        // transfers share the id of their charge
+        let stmt = Statement::from_string(
+            manager.get_database_backend(),
+            r#"UPDATE transfers
+            SET batch_id = transfer_charges.batch_id
+            FROM transfer_charges
+            WHERE transfer_charges.id = transfers.id
+            AND transfer_charges.batch_id IS NOT NULL;"#
+                .to_string(),
+        );
+
+        manager.get_connection().execute(stmt).await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TransferCharges::Table)
+                    .drop_column(TransferCharges::BatchId)
+                    .drop_column(TransferCharges::CollectionMintId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TransferCharges::Table)
+                    .add_column_if_not_exists(
+                        ColumnDef::new(TransferCharges::BatchId).uuid().null(),
+                    )
+                    .add_column_if_not_exists(
+                        ColumnDef::new(TransferCharges::CollectionMintId)
+                            .uuid()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transfers::Table)
+                    .drop_column(Transfers::BatchId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+/// Learn more at https://docs.rs/sea-query#iden
+#[derive(Iden)]
+enum Transfers {
+    Table,
+    BatchId,
+}
+
+/// Learn more at https://docs.rs/sea-query#iden
+#[derive(Iden)]
+enum TransferCharges {
+    Table,
+    BatchId,
+    CollectionMintId,
+}
@@ -0,0 +1,44 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20230214_212301_create_collections_table::CreationStatus;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // the status of a transfer is tracked on the transfers table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TransferCharges::Table)
+                    .drop_column(TransferCharges::Status)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TransferCharges::Table)
+                    .add_column_if_not_exists(
+                        ColumnDef::new(TransferCharges::Status)
+                            .custom(CreationStatus::Type)
+                            .not_null()
+                            .extra("default 'created'".to_string()),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+/// Learn more at https://docs.rs/sea-query#iden
+#[derive(Iden)]
+enum TransferCharges {
+    Table,
+    Status,
+}